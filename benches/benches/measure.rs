@@ -0,0 +1,110 @@
+//! This file includes benchmarks for trees in which many of the leaves have measure functions
+use std::cell::Cell;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use taffy::prelude::*;
+use taffy::style::Style;
+use taffy::{text_measure_function, TextMeasurer};
+use taffy_benchmarks::STANDARD_RNG_SEED;
+
+/// A paragraph of text which simulates wrapping: its height depends on the width it is given
+struct Paragraph {
+    /// The number of characters in the paragraph
+    char_count: usize,
+}
+
+impl Paragraph {
+    /// The width of every character
+    const CHAR_WIDTH: f32 = 8.0;
+    /// The height of every line
+    const LINE_HEIGHT: f32 = 16.0;
+    /// The number of characters in every word
+    const WORD_LENGTH: usize = 6;
+}
+
+impl TextMeasurer for Paragraph {
+    fn min_content_width(&self) -> f32 {
+        Self::WORD_LENGTH.min(self.char_count) as f32 * Self::CHAR_WIDTH
+    }
+
+    fn max_content_width(&self) -> f32 {
+        self.char_count as f32 * Self::CHAR_WIDTH
+    }
+
+    fn measure_height(&self, width: f32) -> f32 {
+        let line_count = (self.max_content_width() / width.max(self.min_content_width())).ceil();
+        line_count * Self::LINE_HEIGHT
+    }
+}
+
+/// Build a tree of flexbox containers of the given depth and width in which half of the leaves are paragraphs of text
+fn build_tree(taffy: &mut TaffyTree<Paragraph>, rng: &mut ChaCha8Rng, depth: usize, width: usize) -> NodeId {
+    if depth == 0 {
+        return if rng.random_bool(0.5) {
+            let paragraph = Paragraph { char_count: rng.random_range(10..200) };
+            taffy.new_leaf_with_context(Style::default(), paragraph).unwrap()
+        } else {
+            let size = Size::from_lengths(rng.random_range(10.0..100.0), rng.random_range(10.0..100.0));
+            taffy.new_leaf(Style { size, ..Default::default() }).unwrap()
+        };
+    }
+
+    let children: Vec<NodeId> = (0..width).map(|_| build_tree(taffy, rng, depth - 1, width)).collect();
+    let style = Style {
+        flex_direction: if rng.random_bool(0.5) { FlexDirection::Row } else { FlexDirection::Column },
+        flex_wrap: if rng.random_bool(0.5) { FlexWrap::Wrap } else { FlexWrap::NoWrap },
+        ..Default::default()
+    };
+    taffy.new_with_children(style, &children).unwrap()
+}
+
+/// Lay out the tree, counting the number of times paragraphs are measured
+fn compute_layout(taffy: &mut TaffyTree<Paragraph>, root: NodeId, measure_count: &Cell<usize>) {
+    let available_space = Size { width: AvailableSpace::Definite(800.0), height: AvailableSpace::MaxContent };
+    taffy
+        .compute_layout_with_measure(
+            root,
+            available_space,
+            |known_dimensions, available_space, node, context, style| {
+                if context.is_some() {
+                    measure_count.set(measure_count.get() + 1);
+                }
+                text_measure_function(known_dimensions, available_space, node, context, style)
+            },
+        )
+        .unwrap();
+}
+
+fn measure_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("measure-heavy tree");
+    for (depth, width) in [(2, 10), (3, 10), (4, 10usize)] {
+        let node_count = (0..=depth).map(|level| width.pow(level as u32)).sum::<usize>();
+
+        // Report how often the measure functions are called, so that regressions in caching are easy to spot
+        let mut rng = ChaCha8Rng::seed_from_u64(STANDARD_RNG_SEED);
+        let mut taffy = TaffyTree::new();
+        let root = build_tree(&mut taffy, &mut rng, depth, width);
+        let measure_count = Cell::new(0);
+        compute_layout(&mut taffy, root, &measure_count);
+        println!("{node_count} nodes: {} measure function calls", measure_count.get());
+
+        group.bench_with_input(BenchmarkId::new("layout", node_count), &(depth, width), |b, &(depth, width)| {
+            b.iter_batched(
+                || {
+                    let mut rng = ChaCha8Rng::seed_from_u64(STANDARD_RNG_SEED);
+                    let mut taffy = TaffyTree::new();
+                    let root = build_tree(&mut taffy, &mut rng, depth, width);
+                    (taffy, root)
+                },
+                |(mut taffy, root)| compute_layout(&mut taffy, root, &Cell::new(0)),
+                criterion::BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, measure_benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+//! This file reports the memory used per node by trees of various sizes
+use std::alloc::{GlobalAlloc, Layout as AllocLayout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use taffy::prelude::*;
+use taffy::style::Style;
+use taffy_benchmarks::STANDARD_RNG_SEED;
+
+/// A global allocator which keeps track of the number of bytes which are currently allocated
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: AllocLayout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: AllocLayout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// A tree with many children that have shallow depth
+fn build_flat_hierarchy(total_node_count: usize) -> (TaffyTree, NodeId) {
+    let mut taffy = TaffyTree::with_capacity(total_node_count);
+    let mut rng = ChaCha8Rng::seed_from_u64(STANDARD_RNG_SEED);
+    let mut children = Vec::new();
+    let mut node_count = 0;
+
+    while node_count < total_node_count {
+        let sub_children_count = rng.random_range(1..=4);
+        let sub_children: Vec<NodeId> =
+            (0..sub_children_count).map(|_| taffy.new_leaf(Style::DEFAULT).unwrap()).collect();
+        let node = taffy.new_with_children(Style::DEFAULT, &sub_children).unwrap();
+
+        children.push(node);
+        node_count += 1 + sub_children_count;
+    }
+
+    let root = taffy.new_with_children(Style::DEFAULT, children.as_slice()).unwrap();
+    (taffy, root)
+}
+
+fn main() {
+    println!("Node count | Bytes per node after creation | Bytes per node after layout");
+    for node_count in [1_000, 10_000, 100_000] {
+        let baseline = ALLOCATED.load(Ordering::Relaxed);
+        let (mut taffy, root) = build_flat_hierarchy(node_count);
+        let created = ALLOCATED.load(Ordering::Relaxed) - baseline;
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let laid_out = ALLOCATED.load(Ordering::Relaxed) - baseline;
+
+        let total_node_count = taffy.total_node_count();
+        println!("{total_node_count:>10} | {:>29} | {:>27}", created / total_node_count, laid_out / total_node_count);
+    }
+}
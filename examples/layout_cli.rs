@@ -0,0 +1,112 @@
+//! Computes the layout of a tree described in JSON and prints the result
+//!
+//! Usage: `cargo run --example layout_cli --features serde -- [--json] [FILE]`
+//!
+//! The tree is read from `FILE` (or from stdin if no file is given) and has the form:
+//!
+//! ```json
+//! {
+//!   "available_space": { "width": 800, "height": null },
+//!   "root": { "style": { ... }, "children": [ { "style": { ... } } ] }
+//! }
+//! ```
+//!
+//! Each `style` is a [`Style`] in the form produced by serializing it with serde (any field may be omitted).
+//! A `null` or missing available space is treated as max-content. Run with `--sample` to print an example input.
+//!
+//! By default the computed layout is printed using `print_tree`. With `--json` the layout of each node is
+//! printed as JSON instead, nested in the same way as the input.
+use serde_json::{json, Value};
+use std::io::Read;
+use taffy::prelude::*;
+
+type Error = Box<dyn std::error::Error>;
+
+/// Create the nodes described by `description` and return the root node
+fn build_tree(taffy: &mut TaffyTree, description: &Value) -> Result<NodeId, Error> {
+    let style: Style = match description.get("style") {
+        Some(style) => serde_json::from_value(style.clone())?,
+        None => Style::default(),
+    };
+    let children = match description.get("children") {
+        Some(Value::Array(children)) => {
+            children.iter().map(|child| build_tree(taffy, child)).collect::<Result<Vec<_>, _>>()?
+        }
+        Some(_) => return Err("`children` must be an array".into()),
+        None => Vec::new(),
+    };
+    Ok(taffy.new_with_children(style, &children)?)
+}
+
+/// Parse one axis of the available space: a number is a definite size, `null` or missing is max-content
+fn parse_available_space(value: Option<&Value>) -> Result<AvailableSpace, Error> {
+    match value {
+        None | Some(Value::Null) => Ok(AvailableSpace::MaxContent),
+        Some(value) => value
+            .as_f64()
+            .map(|v| AvailableSpace::Definite(v as f32))
+            .ok_or_else(|| format!("available space must be a number or null, found {value}").into()),
+    }
+}
+
+/// Serialize the computed layout of `node` and its descendants
+fn layout_to_json(taffy: &TaffyTree, node: NodeId) -> Result<Value, Error> {
+    let children =
+        taffy.children(node)?.into_iter().map(|child| layout_to_json(taffy, child)).collect::<Result<Vec<_>, _>>()?;
+    Ok(json!({ "layout": serde_json::to_value(taffy.layout(node)?)?, "children": children }))
+}
+
+/// An example input, so that the serialized form of styles can be seen
+fn sample() -> Result<Value, Error> {
+    let child: Style = Style { flex_grow: 1.0, ..Default::default() };
+    let root: Style = Style { size: Size::from_lengths(200.0, 100.0), padding: length(10.0), ..Default::default() };
+    Ok(json!({
+        "available_space": { "width": 800, "height": null },
+        "root": {
+            "style": serde_json::to_value(&root)?,
+            "children": [{ "style": serde_json::to_value(&child)? }, { "style": serde_json::to_value(&child)? }],
+        },
+    }))
+}
+
+fn main() -> Result<(), Error> {
+    let mut output_json = false;
+    let mut path = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => output_json = true,
+            "--sample" => {
+                println!("{}", serde_json::to_string_pretty(&sample()?)?);
+                return Ok(());
+            }
+            _ => path = Some(arg),
+        }
+    }
+
+    let input = match path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            input
+        }
+    };
+    let description: Value = serde_json::from_str(&input)?;
+
+    let mut taffy: TaffyTree = TaffyTree::new();
+    let root = build_tree(&mut taffy, description.get("root").ok_or("missing `root` node")?)?;
+    let available_space = description.get("available_space");
+    let available_space = Size {
+        width: parse_available_space(available_space.and_then(|space| space.get("width")))?,
+        height: parse_available_space(available_space.and_then(|space| space.get("height")))?,
+    };
+    taffy.compute_layout(root, available_space)?;
+
+    if output_json {
+        println!("{}", serde_json::to_string_pretty(&layout_to_json(&taffy, root)?)?);
+    } else {
+        taffy.print_tree(root);
+    }
+
+    Ok(())
+}
@@ -9,7 +9,9 @@ use crate::util::sys::f32_max;
 use crate::util::sys::Vec;
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
-use crate::{BlockContainerStyle, BlockItemStyle, BoxGenerationMode, BoxSizing, LayoutBlockContainer, TextAlign};
+use crate::{BlockContainerStyle, BlockItemStyle, BoxSizing, LayoutBlockContainer, TextAlign};
+
+use super::common::children::ChildKind;
 
 #[cfg(feature = "content_size")]
 use super::common::content_size::compute_content_size_contribution;
@@ -252,7 +254,7 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     let len = tree.child_count(node_id);
     for order in 0..len {
         let child = tree.get_child_id(node_id, order);
-        if tree.get_block_child_style(child).box_generation_mode() == BoxGenerationMode::None {
+        if ChildKind::of(&tree.get_block_child_style(child)) == ChildKind::Hidden {
             tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
             tree.perform_child_layout(
                 child,
@@ -305,12 +307,13 @@ fn generate_item_list(
 ) -> Vec<BlockItem> {
     tree.child_ids(node)
         .map(|child_node_id| (child_node_id, tree.get_block_child_style(child_node_id)))
-        .filter(|(_, style)| style.box_generation_mode() != BoxGenerationMode::None)
+        .filter(|(_, style)| ChildKind::of(style) != ChildKind::Hidden)
         .enumerate()
         .map(|(order, (child_node_id, child_style))| {
             let aspect_ratio = child_style.aspect_ratio();
-            let padding = child_style.padding().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
-            let border = child_style.border().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
+            let padding_basis = tree.percentage_padding_basis(node_inner_size);
+            let padding = child_style.padding().resolve_or_zero(padding_basis, |val, basis| tree.calc(val, basis));
+            let border = child_style.border().resolve_or_zero(padding_basis, |val, basis| tree.calc(val, basis));
             let pb_sum = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
@@ -367,7 +370,7 @@ fn determine_content_based_container_width(
         let width = known_dimensions.width.unwrap_or_else(|| {
             let item_x_margin_sum = item
                 .margin
-                .resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis))
+                .resolve_or_zero(available_space.width, |val, basis| tree.calc(val, basis))
                 .horizontal_axis_sum();
             let size_and_baselines = tree.perform_child_layout(
                 item.node_id,
@@ -593,8 +596,7 @@ fn perform_absolute_layout_on_absolute_children(
         let child_style = tree.get_block_child_style(item.node_id);
 
         // Skip items that are display:none or are not position:absolute
-        if child_style.box_generation_mode() == BoxGenerationMode::None || child_style.position() != Position::Absolute
-        {
+        if ChildKind::of(&child_style) != ChildKind::Absolute {
             continue;
         }
 
@@ -653,14 +655,29 @@ fn perform_absolute_layout_on_absolute_children(
             known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
         }
 
+        // Auto sizes shrink-to-fit the inset-modified containing block. An auto inset is zero, except that when both
+        // insets in an axis are auto the start inset is the static position
+        // The item's margins are subtracted from this available space when it is laid out
+        // See https://www.w3.org/TR/css-position-3/#abspos-auto-size
+        let static_position =
+            Point { x: item.static_position.x - area_offset.x, y: item.static_position.y - area_offset.y };
+        let available_space = Size {
+            width: area_width
+                - left.unwrap_or(if right.is_none() { static_position.x } else { 0.0 })
+                - right.unwrap_or(0.0),
+            height: area_height
+                - top.unwrap_or(if bottom.is_none() { static_position.y } else { 0.0 })
+                - bottom.unwrap_or(0.0),
+        }
+        .f32_max(Size::ZERO)
+        .maybe_clamp(min_size, max_size)
+        .map(AvailableSpace::Definite);
+
         let measured_size = tree.measure_child_size_both(
             item.node_id,
             known_dimensions,
             area_size.map(Some),
-            Size {
-                width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
-                height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
-            },
+            available_space,
             SizingMode::ContentSize,
             Line::FALSE,
         );
@@ -671,10 +688,7 @@ fn perform_absolute_layout_on_absolute_children(
             item.node_id,
             final_size.map(Some),
             area_size.map(Some),
-            Size {
-                width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
-                height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
-            },
+            available_space,
             SizingMode::ContentSize,
             Line::FALSE,
         );
@@ -683,7 +697,7 @@ fn perform_absolute_layout_on_absolute_children(
             left: if left.is_some() { margin.left.unwrap_or(0.0) } else { 0.0 },
             right: if right.is_some() { margin.right.unwrap_or(0.0) } else { 0.0 },
             top: if top.is_some() { margin.top.unwrap_or(0.0) } else { 0.0 },
-            bottom: if bottom.is_some() { margin.left.unwrap_or(0.0) } else { 0.0 },
+            bottom: if bottom.is_some() { margin.bottom.unwrap_or(0.0) } else { 0.0 },
         };
 
         // Expand auto margins to fill available space
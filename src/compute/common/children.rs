@@ -0,0 +1,31 @@
+//! Classification of the children of a container by how they take part in its layout
+use crate::style::{CoreStyle, Position};
+use crate::BoxGenerationMode;
+
+/// How a child takes part in the layout of its parent
+///
+/// All of the layout algorithms use this to decide which children to skip in each phase of layout,
+/// so that they agree on how hidden and absolutely positioned children are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChildKind {
+    /// The child is laid out as part of the normal flow of its parent
+    InFlow,
+    /// The child is taken out of flow and is positioned against its parent after in-flow layout
+    Absolute,
+    /// The child generates no box (`display: none`). Takes precedence over `position: absolute`.
+    Hidden,
+}
+
+impl ChildKind {
+    /// Classify a child using its style
+    #[inline(always)]
+    pub(crate) fn of(style: &impl CoreStyle) -> Self {
+        if style.box_generation_mode() == BoxGenerationMode::None {
+            ChildKind::Hidden
+        } else if style.position() == Position::Absolute {
+            ChildKind::Absolute
+        } else {
+            ChildKind::InFlow
+        }
+    }
+}
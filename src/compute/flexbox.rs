@@ -2,20 +2,24 @@
 use crate::compute::common::alignment::compute_alignment_offset;
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::style::{
-    AlignContent, AlignItems, AlignSelf, AvailableSpace, FlexWrap, JustifyContent, LengthPercentageAuto, Overflow,
-    Position,
+    AlignContent, AlignItems, AlignSelf, AvailableSpace, FlexGrowPrecision, FlexWrap, JustifyContent,
+    LengthPercentageAuto, Overflow,
 };
 use crate::style::{CoreStyle, FlexDirection, FlexboxContainerStyle, FlexboxItemStyle};
 use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
-use crate::tree::{Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
+use crate::tree::{Layout, LayoutInput, LayoutOutput, LayoutWarning, RunMode, SizingMode};
 use crate::tree::{LayoutFlexboxContainer, LayoutPartialTreeExt, NodeId};
+use crate::util::arena::{collect_scratch_vec, scratch_vec_with_capacity, with_scratch, Scratch, ScratchVec};
 use crate::util::debug::debug_log;
-use crate::util::sys::{f32_max, new_vec_with_capacity, Vec};
-use crate::util::MaybeMath;
+#[cfg(feature = "detailed_layout_info")]
+use crate::util::sys::Vec;
+use crate::util::sys::{f32_max, f32_min};
+use crate::util::{sanitize_f32, MaybeMath};
 use crate::util::{MaybeResolve, ResolveOrZero};
-use crate::{BoxGenerationMode, BoxSizing};
+use crate::BoxSizing;
 
 use super::common::alignment::apply_alignment_fallback;
+use super::common::children::ChildKind;
 #[cfg(feature = "content_size")]
 use super::common::content_size::compute_content_size_contribution;
 
@@ -35,6 +39,8 @@ struct FlexItem {
     max_size: Size<Option<f32>>,
     /// The cross-alignment of this item
     align_self: AlignSelf,
+    /// The cross-alignment to use instead of baseline alignment if the item has no baseline
+    align_self_fallback: Option<AlignSelf>,
 
     /// The overflow style of the item
     overflow: Point<Overflow>,
@@ -44,6 +50,14 @@ struct FlexItem {
     flex_shrink: f32,
     /// The flex grow style of the item
     flex_grow: f32,
+    /// The most that the item can grow beyond its flex basis
+    flex_grow_max: Option<f32>,
+    /// The most that the item can shrink below its flex basis
+    flex_shrink_min: Option<f32>,
+    /// Whether an `auto` min size resolves to the content-based automatic minimum size (or else to zero)
+    auto_min_size: bool,
+    /// Whether the item always starts a new line
+    break_before: bool,
 
     /// The minimum size of the item. This differs from min_size above because it also
     /// takes into account content based automatic minimum sizes
@@ -126,6 +140,12 @@ struct AlgoConstants {
     is_wrap: bool,
     /// Is the wrap direction inverted
     is_wrap_reverse: bool,
+    /// How precisely free space is distributed between growing items
+    flex_grow_precision: FlexGrowPrecision,
+    /// The flex grow factor of each gap between items in the main axis
+    gap_grow: f32,
+    /// The most items that a line can hold
+    max_items_per_line: Option<u16>,
 
     /// The item's min_size style
     min_size: Size<Option<f32>>,
@@ -140,6 +160,8 @@ struct AlgoConstants {
     content_box_inset: Rect<f32>,
     /// The size reserved for scrollbar gutters in each axis
     scrollbar_gutter: Point<f32>,
+    /// Whether the node is a scroll container in each axis, in which case content alignment is "safe"
+    is_scroll_container: Point<bool>,
     /// The gap of this section
     gap: Size<f32>,
     /// The align_items property of this node
@@ -219,11 +241,17 @@ pub fn compute_flexbox_layout(
     debug_log!("FLEX:", dbg:style.flex_direction());
     drop(style);
 
-    compute_preliminary(tree, node, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
+    let inputs = LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs };
+    with_scratch(|scratch| compute_preliminary(tree, node, inputs, scratch))
 }
 
 /// Compute a preliminary size for an item
-fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+fn compute_preliminary(
+    tree: &mut impl LayoutFlexboxContainer,
+    node: NodeId,
+    inputs: LayoutInput,
+    scratch: Scratch,
+) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
 
     // Define some general constants we will need for the remainder of the algorithm.
@@ -235,7 +263,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
 
     // 1. Generate anonymous flex items as described in §4 Flex Items.
     debug_log!("generate_anonymous_flex_items");
-    let mut flex_items = generate_anonymous_flex_items(tree, node, &constants);
+    let mut flex_items = generate_anonymous_flex_items(tree, node, &constants, scratch);
 
     // 9.2. Line Length Determination
 
@@ -263,7 +291,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
 
     // 5. Collect flex items into flex lines.
     debug_log!("collect_flex_lines");
-    let mut flex_lines = collect_flex_lines(&constants, available_space, &mut flex_items);
+    let mut flex_lines = collect_flex_lines(&constants, available_space, &mut flex_items, scratch);
 
     // If container size is undefined, determine the container's main size
     // and then re-resolve gaps based on newly determined size
@@ -295,7 +323,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     // 6. Resolve the flexible lengths of all the flex items to find their used main size.
     debug_log!("resolve_flexible_lengths");
     for line in &mut flex_lines {
-        resolve_flexible_lengths(line, &constants);
+        resolve_flexible_lengths(line, &constants, scratch);
     }
 
     // 9.4. Cross Size Determination
@@ -360,6 +388,14 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
         return LayoutOutput::from_outer_size(constants.container_size);
     }
 
+    for line in flex_lines.iter() {
+        // Ignore overflow which is only the result of floating point error
+        let free_space = line_free_space(line, &constants);
+        if free_space < -0.01 {
+            tree.report_warning(node, LayoutWarning::FlexLineOverflow { overflow: -free_space });
+        }
+    }
+
     // 16. Align all flex lines per align-content.
     debug_log!("align_flex_lines_per_align_content");
     align_flex_lines_per_align_content(&mut flex_lines, &constants, total_line_cross_size);
@@ -368,6 +404,10 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     debug_log!("final_layout_pass");
     let inflow_content_size = final_layout_pass(tree, &mut flex_lines, &constants);
 
+    // Set detailed flexbox information
+    #[cfg(feature = "detailed_layout_info")]
+    tree.set_detailed_flexbox_info(node, DetailedFlexboxInfo::from_flex_lines(&flex_lines, &constants));
+
     // Before returning we perform absolute layout on all absolutely positioned children
     debug_log!("perform_absolute_layout_on_absolute_children");
     let absolute_content_size = perform_absolute_layout_on_absolute_children(tree, node, &constants);
@@ -376,7 +416,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     let len = tree.child_count(node);
     for order in 0..len {
         let child = tree.get_child_id(node, order);
-        if tree.get_flexbox_child_style(child).box_generation_mode() == BoxGenerationMode::None {
+        if ChildKind::of(&tree.get_flexbox_child_style(child)) == ChildKind::Hidden {
             tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
             tree.perform_child_layout(
                 child,
@@ -463,6 +503,9 @@ fn compute_constants(
         is_column,
         is_wrap,
         is_wrap_reverse,
+        flex_grow_precision: style.flex_grow_precision(),
+        gap_grow: style.gap_grow(),
+        max_items_per_line: style.max_items_per_line(),
         min_size: style
             .min_size()
             .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
@@ -478,6 +521,7 @@ fn compute_constants(
         gap,
         content_box_inset,
         scrollbar_gutter,
+        is_scroll_container: style.overflow().map(Overflow::is_scroll_container),
         align_items,
         align_content,
         justify_content,
@@ -494,16 +538,17 @@ fn compute_constants(
 ///
 /// - [**Generate anonymous flex items**](https://www.w3.org/TR/css-flexbox-1/#algo-anon-box) as described in [§4 Flex Items](https://www.w3.org/TR/css-flexbox-1/#flex-items).
 #[inline]
-fn generate_anonymous_flex_items(
+fn generate_anonymous_flex_items<'bump>(
     tree: &impl LayoutFlexboxContainer,
     node: NodeId,
     constants: &AlgoConstants,
-) -> Vec<FlexItem> {
-    tree.child_ids(node)
+    scratch: Scratch<'bump>,
+) -> ScratchVec<'bump, FlexItem> {
+    let items = tree
+        .child_ids(node)
         .enumerate()
         .map(|(index, child)| (index, child, tree.get_flexbox_child_style(child)))
-        .filter(|(_, _, style)| style.position() != Position::Absolute)
-        .filter(|(_, _, style)| style.box_generation_mode() != BoxGenerationMode::None)
+        .filter(|(_, _, style)| ChildKind::of(style) == ChildKind::InFlow)
         .map(|(index, child, child_style)| {
             let aspect_ratio = child_style.aspect_ratio();
             let padding = child_style
@@ -515,6 +560,8 @@ fn generate_anonymous_flex_items(
             let pb_sum = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
+            let align_self = child_style.align_self().unwrap_or(constants.align_items);
+            let align_self_fallback = child_style.align_self_fallback().filter(|&align| align != AlignSelf::Baseline);
             FlexItem {
                 node: child,
                 order: index as u32,
@@ -547,11 +594,20 @@ fn generate_anonymous_flex_items(
                 border: child_style
                     .border()
                     .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
-                align_self: child_style.align_self().unwrap_or(constants.align_items),
+                // Baseline alignment only applies in rows, so columns use the fallback straight away
+                align_self: match align_self_fallback {
+                    Some(fallback) if constants.is_column && align_self == AlignSelf::Baseline => fallback,
+                    _ => align_self,
+                },
+                align_self_fallback,
                 overflow: child_style.overflow(),
                 scrollbar_width: child_style.scrollbar_width(),
-                flex_grow: child_style.flex_grow(),
-                flex_shrink: child_style.flex_shrink(),
+                flex_grow: sanitize_f32(child_style.flex_grow(), "flex_grow"),
+                flex_shrink: sanitize_f32(child_style.flex_shrink(), "flex_shrink"),
+                flex_grow_max: child_style.flex_grow_max(),
+                flex_shrink_min: child_style.flex_shrink_min(),
+                auto_min_size: child_style.flex_auto_min_size(),
+                break_before: child_style.break_before(),
                 flex_basis: 0.0,
                 inner_flex_basis: 0.0,
                 violation: 0.0,
@@ -569,8 +625,8 @@ fn generate_anonymous_flex_items(
                 offset_main: 0.0,
                 offset_cross: 0.0,
             }
-        })
-        .collect()
+        });
+    collect_scratch_vec(items, scratch)
 }
 
 /// Determine the available main and cross space for the flex items.
@@ -792,8 +848,13 @@ fn determine_flex_base_size(
         // be set to their usual values in the cross axis so that wrapping content can wrap correctly.
         //
         // See https://drafts.csswg.org/css-sizing-3/#min-percentage-contribution
-        let style_min_main_size =
-            child.min_size.or(child.overflow.map(Overflow::maybe_into_automatic_min_size).into()).main(dir);
+        //
+        // Items which have opted out of content-based automatic minimum sizes have a zero minimum size (in the same way as scroll containers)
+        let style_min_main_size = child
+            .min_size
+            .or(child.overflow.map(Overflow::maybe_into_automatic_min_size).into())
+            .main(dir)
+            .or((!child.auto_min_size).then_some(0.0));
 
         child.resolved_minimum_main_size = style_min_main_size.unwrap_or({
             let min_content_main_size = {
@@ -850,11 +911,12 @@ fn determine_flex_base_size(
 fn collect_flex_lines<'a>(
     constants: &AlgoConstants,
     available_space: Size<AvailableSpace>,
-    flex_items: &'a mut Vec<FlexItem>,
-) -> Vec<FlexLine<'a>> {
+    flex_items: &'a mut [FlexItem],
+    scratch: Scratch<'a>,
+) -> ScratchVec<'a, FlexLine<'a>> {
     if !constants.is_wrap {
-        let mut lines = new_vec_with_capacity(1);
-        lines.push(FlexLine { items: flex_items.as_mut_slice(), cross_size: 0.0, offset_cross: 0.0 });
+        let mut lines = scratch_vec_with_capacity(1, scratch);
+        lines.push(FlexLine { items: flex_items, cross_size: 0.0, offset_cross: 0.0 });
         lines
     } else {
         let main_axis_available_space = match constants.max_size.main(constants.dir) {
@@ -869,17 +931,21 @@ fn collect_flex_lines<'a>(
         };
 
         match main_axis_available_space {
-            // If we're sizing under a max-content constraint then the flex items will never wrap
-            // (at least for now - future extensions to the CSS spec may add provisions for forced wrap points)
+            // If we're sizing under a max-content constraint then the flex items only wrap at forced breaks
             AvailableSpace::MaxContent => {
-                let mut lines = new_vec_with_capacity(1);
-                lines.push(FlexLine { items: flex_items.as_mut_slice(), cross_size: 0.0, offset_cross: 0.0 });
+                let mut lines = scratch_vec_with_capacity(1, scratch);
+                let mut flex_items = &mut flex_items[..];
+                while !flex_items.is_empty() {
+                    let (items, rest) = flex_items.split_at_mut(forced_line_length(flex_items, constants));
+                    lines.push(FlexLine { items, cross_size: 0.0, offset_cross: 0.0 });
+                    flex_items = rest;
+                }
                 lines
             }
             // If flex-wrap is Wrap and we're sizing under a min-content constraint, then we take every possible wrapping opportunity
             // and place each item in it's own line
             AvailableSpace::MinContent => {
-                let mut lines = new_vec_with_capacity(flex_items.len());
+                let mut lines = scratch_vec_with_capacity(flex_items.len(), scratch);
                 let mut items = &mut flex_items[..];
                 while !items.is_empty() {
                     let (line_items, rest) = items.split_at_mut(1);
@@ -889,7 +955,7 @@ fn collect_flex_lines<'a>(
                 lines
             }
             AvailableSpace::Definite(main_axis_available_space) => {
-                let mut lines = new_vec_with_capacity(1);
+                let mut lines = scratch_vec_with_capacity(1, scratch);
                 let mut flex_items = &mut flex_items[..];
                 let main_axis_gap = constants.gap.main(constants.dir);
 
@@ -908,7 +974,8 @@ fn collect_flex_lines<'a>(
                             line_length > main_axis_available_space && idx != 0
                         })
                         .map(|(idx, _)| idx)
-                        .unwrap_or(flex_items.len());
+                        .unwrap_or(flex_items.len())
+                        .min(forced_line_length(flex_items, constants));
 
                     let (items, rest) = flex_items.split_at_mut(index);
                     lines.push(FlexLine { items, cross_size: 0.0, offset_cross: 0.0 });
@@ -920,6 +987,16 @@ fn collect_flex_lines<'a>(
     }
 }
 
+/// The number of items at the start of `flex_items` which can share a line before a forced break, which is caused by
+/// the container's `max_items_per_line` or an item's `break_before` (non-standard extensions)
+#[inline]
+fn forced_line_length(flex_items: &[FlexItem], constants: &AlgoConstants) -> usize {
+    let max_items = constants.max_items_per_line.map_or(usize::MAX, |max_items| usize::from(max_items).max(1));
+    let break_before =
+        flex_items.iter().skip(1).position(|item| item.break_before).map_or(flex_items.len(), |idx| idx + 1);
+    break_before.min(max_items)
+}
+
 /// Determine the container's main size (if not already known)
 fn determine_container_main_size(
     tree: &mut impl LayoutFlexboxContainer,
@@ -1165,8 +1242,10 @@ fn determine_container_main_size(
 ///
 /// # [9.7. Resolving Flexible Lengths](https://www.w3.org/TR/css-flexbox-1/#resolve-flexible-lengths)
 #[inline]
-fn resolve_flexible_lengths(line: &mut FlexLine, constants: &AlgoConstants) {
+fn resolve_flexible_lengths(line: &mut FlexLine, constants: &AlgoConstants, scratch: Scratch) {
     let total_main_axis_gap = sum_axis_gaps(constants.gap.main(constants.dir), line.items.len());
+    // Non-standard: gaps with a `gap_grow` factor take a share of the free space as though they were items
+    let gaps_flex_grow = sum_axis_gaps(constants.gap_grow, line.items.len());
 
     // 1. Determine the used flex factor. Sum the outer hypothetical main sizes of all
     //    items on the line. If the sum is less than the flex container’s inner main size,
@@ -1254,18 +1333,19 @@ fn resolve_flexible_lengths(line: &mut FlexLine, constants: &AlgoConstants) {
                 })
                 .sum::<f32>();
 
-        let mut unfrozen: Vec<&mut FlexItem> = line.items.iter_mut().filter(|child| !child.frozen).collect();
+        let mut unfrozen: ScratchVec<&mut FlexItem> =
+            collect_scratch_vec(line.items.iter_mut().filter(|child| !child.frozen), scratch);
 
         let (sum_flex_grow, sum_flex_shrink): (f32, f32) =
-            unfrozen.iter().fold((0.0, 0.0), |(flex_grow, flex_shrink), item| {
+            unfrozen.iter().fold((gaps_flex_grow, 0.0), |(flex_grow, flex_shrink), item| {
                 (flex_grow + item.flex_grow, flex_shrink + item.flex_shrink)
             });
 
         let free_space = if growing && sum_flex_grow < 1.0 {
-            (initial_free_space * sum_flex_grow - total_main_axis_gap)
+            (initial_free_space * sum_flex_grow)
                 .maybe_min(constants.node_inner_size.main(constants.dir).maybe_sub(used_space))
         } else if shrinking && sum_flex_shrink < 1.0 {
-            (initial_free_space * sum_flex_shrink - total_main_axis_gap)
+            (initial_free_space * sum_flex_shrink)
                 .maybe_max(constants.node_inner_size.main(constants.dir).maybe_sub(used_space))
         } else {
             (constants.node_inner_size.main(constants.dir).maybe_sub(used_space))
@@ -1292,7 +1372,20 @@ fn resolve_flexible_lengths(line: &mut FlexLine, constants: &AlgoConstants) {
         //        Do Nothing
 
         if free_space.is_normal() {
-            if growing && sum_flex_grow > 0.0 {
+            if growing && sum_flex_grow > 0.0 && constants.flex_grow_precision == FlexGrowPrecision::Exact {
+                // Give each item the difference between the space distributed up to and including it and the space
+                // distributed before it, so that rounding errors don't accumulate across the line
+                let sum_flex_grow: f64 =
+                    gaps_flex_grow as f64 + unfrozen.iter().map(|child| child.flex_grow as f64).sum::<f64>();
+                let mut cumulative_flex_grow = 0.0f64;
+                let mut distributed = 0.0f64;
+                for child in &mut unfrozen {
+                    cumulative_flex_grow += child.flex_grow as f64;
+                    let end = free_space as f64 * cumulative_flex_grow / sum_flex_grow;
+                    child.target_size.set_main(constants.dir, child.flex_basis + (end - distributed) as f32);
+                    distributed = end;
+                }
+            } else if growing && sum_flex_grow > 0.0 {
                 for child in &mut unfrozen {
                     child
                         .target_size
@@ -1320,8 +1413,17 @@ fn resolve_flexible_lengths(line: &mut FlexLine, constants: &AlgoConstants) {
         //    If the item’s target main size was made larger by this, it’s a min violation.
 
         let total_violation = unfrozen.iter_mut().fold(0.0, |acc, child| -> f32 {
-            let resolved_min_main: Option<f32> = child.resolved_minimum_main_size.into();
-            let max_main = child.max_size.main(constants.dir);
+            let mut resolved_min_main: Option<f32> = child.resolved_minimum_main_size.into();
+            let mut max_main = child.max_size.main(constants.dir);
+
+            // Non-standard: cap how far the item can grow or shrink away from its flex basis
+            if let (true, Some(flex_grow_max)) = (growing, child.flex_grow_max) {
+                let cap = child.flex_basis + flex_grow_max;
+                max_main = Some(max_main.map_or(cap, |max_main| max_main.min(cap)));
+            }
+            if let (true, Some(flex_shrink_min)) = (shrinking, child.flex_shrink_min) {
+                resolved_min_main = resolved_min_main.map(|min_main| min_main.max(child.flex_basis - flex_shrink_min));
+            }
             let clamped = child.target_size.main(constants.dir).maybe_clamp(resolved_min_main, max_main).max(0.0);
             child.violation = clamped - child.target_size.main(constants.dir);
             child.target_size.set_main(constants.dir, clamped);
@@ -1427,15 +1529,15 @@ fn calculate_children_base_lines(
 
     for line in flex_lines {
         // If a flex line has one or zero items participating in baseline alignment then baseline alignment is a no-op so we skip
+        // (unless an item has a fallback alignment, in which case we need to know whether it has a baseline)
         let line_baseline_child_count =
             line.items.iter().filter(|child| child.align_self == AlignSelf::Baseline).count();
-        if line_baseline_child_count <= 1 {
-            continue;
-        }
 
         for child in line.items.iter_mut() {
             // Only calculate baselines for children participating in baseline alignment
-            if child.align_self != AlignSelf::Baseline {
+            if child.align_self != AlignSelf::Baseline
+                || (line_baseline_child_count <= 1 && child.align_self_fallback.is_none())
+            {
                 continue;
             }
 
@@ -1473,7 +1575,10 @@ fn calculate_children_base_lines(
             let baseline = measured_size_and_baselines.first_baselines.y;
             let height = measured_size_and_baselines.size.height;
 
-            child.baseline = baseline.unwrap_or(height) + child.margin.top;
+            match (baseline, child.align_self_fallback) {
+                (None, Some(fallback)) => child.align_self = fallback,
+                _ => child.baseline = baseline.unwrap_or(height) + child.margin.top,
+            }
         }
     }
 }
@@ -1607,12 +1712,11 @@ fn determine_used_cross_size(
                     // For some reason this particular usage of max_width is an exception to the rule that max_width's transfer
                     // using the aspect_ratio (if set). Both Chrome and Firefox agree on this. And reading the spec, it seems like
                     // a reasonable interpretation. Although it seems to me that the spec *should* apply aspect_ratio here.
-                    let padding = child_style
-                        .padding()
-                        .resolve_or_zero(constants.node_inner_size, |val, basis| tree.calc(val, basis));
-                    let border = child_style
-                        .border()
-                        .resolve_or_zero(constants.node_inner_size, |val, basis| tree.calc(val, basis));
+                    let padding_basis = tree.percentage_padding_basis(constants.node_inner_size);
+                    let padding =
+                        child_style.padding().resolve_or_zero(padding_basis, |val, basis| tree.calc(val, basis));
+                    let border =
+                        child_style.border().resolve_or_zero(padding_basis, |val, basis| tree.calc(val, basis));
                     let pb_sum = (padding + border).sum_axes();
                     let box_sizing_adjustment =
                         if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
@@ -1652,10 +1756,16 @@ fn determine_used_cross_size(
 #[inline]
 fn distribute_remaining_free_space(flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
     for line in flex_lines {
-        let total_main_axis_gap = sum_axis_gaps(constants.gap.main(constants.dir), line.items.len());
-        let used_space: f32 = total_main_axis_gap
-            + line.items.iter().map(|child| child.outer_target_size.main(constants.dir)).sum::<f32>();
-        let free_space = constants.inner_container_size.main(constants.dir) - used_space;
+        let mut free_space = line_free_space(line, constants);
+        let mut gap = constants.gap.main(constants.dir);
+        let num_gaps = line.items.len().saturating_sub(1);
+
+        // Non-standard: growable gaps absorb whatever free space the items didn't take
+        if constants.gap_grow > 0.0 && free_space > 0.0 && num_gaps > 0 {
+            gap += free_space / num_gaps as f32;
+            free_space = 0.0;
+        }
+
         let mut num_auto_margins = 0;
 
         for child in line.items.iter_mut() {
@@ -1689,8 +1799,9 @@ fn distribute_remaining_free_space(flex_lines: &mut [FlexLine], constants: &Algo
         } else {
             let num_items = line.items.len();
             let layout_reverse = constants.dir.is_reverse();
-            let gap = constants.gap.main(constants.dir);
-            let is_safe = false; // TODO: Implement safe alignment
+            // Overflowing items must not be pushed into space before the start edge of a scroll container which
+            // can't be scrolled to, so alignment is always "safe" in scroll containers
+            let is_safe = constants.is_scroll_container.main(constants.dir);
             let raw_justify_content_mode = constants.justify_content.unwrap_or(JustifyContent::FlexStart);
             let justify_content_mode =
                 apply_alignment_fallback(free_space, num_items, raw_justify_content_mode, is_safe);
@@ -1709,6 +1820,16 @@ fn distribute_remaining_free_space(flex_lines: &mut [FlexLine], constants: &Algo
     }
 }
 
+/// The space in the main axis of the container which is not used by the items of the line or the gaps between them.
+/// Negative if the items overflow the container.
+#[inline]
+fn line_free_space(line: &FlexLine, constants: &AlgoConstants) -> f32 {
+    let total_main_axis_gap = sum_axis_gaps(constants.gap.main(constants.dir), line.items.len());
+    let used_space: f32 =
+        total_main_axis_gap + line.items.iter().map(|child| child.outer_target_size.main(constants.dir)).sum::<f32>();
+    constants.inner_container_size.main(constants.dir) - used_space
+}
+
 /// Resolve cross-axis `auto` margins.
 ///
 /// # [9.6. Cross-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#cross-alignment)
@@ -1859,7 +1980,7 @@ fn align_flex_lines_per_align_content(flex_lines: &mut [FlexLine], constants: &A
     let gap = constants.gap.cross(constants.dir);
     let total_cross_axis_gap = sum_axis_gaps(gap, num_lines);
     let free_space = constants.inner_container_size.cross(constants.dir) - total_cross_size - total_cross_axis_gap;
-    let is_safe = false; // TODO: Implement safe alignment
+    let is_safe = constants.is_scroll_container.cross(constants.dir);
 
     let align_content_mode = apply_alignment_fallback(free_space, num_lines, constants.align_content, is_safe);
 
@@ -1880,13 +2001,14 @@ fn align_flex_lines_per_align_content(flex_lines: &mut [FlexLine], constants: &A
 fn calculate_flex_item(
     tree: &mut impl LayoutFlexboxContainer,
     item: &mut FlexItem,
-    total_offset_main: &mut f32,
+    total_offset_main: &mut f64,
     total_offset_cross: f32,
     line_offset_cross: f32,
     #[cfg(feature = "content_size")] total_content_size: &mut Size<f32>,
     container_size: Size<f32>,
     node_inner_size: Size<Option<f32>>,
     direction: FlexDirection,
+    flex_grow_precision: FlexGrowPrecision,
 ) {
     let layout_output = tree.perform_child_layout(
         item.node,
@@ -1903,7 +2025,7 @@ fn calculate_flex_item(
         ..
     } = layout_output;
 
-    let offset_main = *total_offset_main
+    let offset_main = *total_offset_main as f32
         + item.offset_main
         + item.margin.main_start(direction)
         + (item.inset.main_start(direction).or(item.inset.main_end(direction).map(|pos| -pos)).unwrap_or(0.0));
@@ -1919,7 +2041,7 @@ fn calculate_flex_item(
         let inner_baseline = layout_output.first_baselines.y.unwrap_or(size.height);
         item.baseline = baseline_offset_cross + inner_baseline;
     } else {
-        let baseline_offset_main = *total_offset_main + item.offset_main + item.margin.main_start(direction);
+        let baseline_offset_main = *total_offset_main as f32 + item.offset_main + item.margin.main_start(direction);
         let inner_baseline = layout_output.first_baselines.y.unwrap_or(size.height);
         item.baseline = baseline_offset_main + inner_baseline;
     }
@@ -1948,7 +2070,13 @@ fn calculate_flex_item(
         },
     );
 
-    *total_offset_main += item.offset_main + item.margin.main_axis_sum(direction) + size.main(direction);
+    let item_main_size = item.offset_main + item.margin.main_axis_sum(direction) + size.main(direction);
+    *total_offset_main = match flex_grow_precision {
+        FlexGrowPrecision::Standard => (*total_offset_main as f32 + item_main_size) as f64,
+        // Accumulated in f64 so that the positions of the items at the end of a long line don't drift from the sum
+        // of the exactly distributed sizes before them
+        FlexGrowPrecision::Exact => *total_offset_main + item_main_size as f64,
+    };
 
     #[cfg(feature = "content_size")]
     {
@@ -1968,8 +2096,9 @@ fn calculate_layout_line(
     node_inner_size: Size<Option<f32>>,
     padding_border: Rect<f32>,
     direction: FlexDirection,
+    flex_grow_precision: FlexGrowPrecision,
 ) {
-    let mut total_offset_main = padding_border.main_start(direction);
+    let mut total_offset_main = padding_border.main_start(direction) as f64;
     let line_offset_cross = line.offset_cross;
 
     if direction.is_reverse() {
@@ -1985,6 +2114,7 @@ fn calculate_layout_line(
                 container_size,
                 node_inner_size,
                 direction,
+                flex_grow_precision,
             );
         }
     } else {
@@ -2000,6 +2130,7 @@ fn calculate_layout_line(
                 container_size,
                 node_inner_size,
                 direction,
+                flex_grow_precision,
             );
         }
     }
@@ -2007,6 +2138,59 @@ fn calculate_layout_line(
     *total_offset_cross += line_offset_cross + line.cross_size;
 }
 
+/// Information from the computation of flexbox
+#[derive(Debug, Clone, PartialEq)]
+#[cfg(feature = "detailed_layout_info")]
+pub struct DetailedFlexboxInfo {
+    /// The flex lines of the container, in the order that items were collected into them
+    ///
+    /// A container which does not wrap always has exactly one line (unless it has no in-flow items).
+    pub lines: Vec<DetailedFlexLineInfo>,
+}
+
+/// Information about a single flex line
+#[derive(Debug, Clone, PartialEq)]
+#[cfg(feature = "detailed_layout_info")]
+pub struct DetailedFlexLineInfo {
+    /// The in-flow items in the line, in `order`-modified document order
+    pub items: Vec<NodeId>,
+    /// The size of the line in the cross axis
+    pub cross_size: f32,
+    /// The offset of the cross-start edge of the line from the cross-start edge of the container's border box
+    ///
+    /// This includes any space that was distributed between the lines by `align-content`.
+    pub cross_offset: f32,
+}
+
+#[cfg(feature = "detailed_layout_info")]
+impl DetailedFlexboxInfo {
+    /// Construct DetailedFlexboxInfo from the lines of a container which has been laid out
+    fn from_flex_lines(flex_lines: &[FlexLine], constants: &AlgoConstants) -> Self {
+        let mut lines: Vec<DetailedFlexLineInfo> = flex_lines
+            .iter()
+            .map(|line| DetailedFlexLineInfo {
+                items: line.items.iter().map(|item| item.node).collect(),
+                cross_size: line.cross_size,
+                cross_offset: 0.0,
+            })
+            .collect();
+
+        // Lines are positioned in the same order as in `final_layout_pass`
+        let mut total_offset_cross = constants.content_box_inset.cross_start(constants.dir);
+        let mut position_line = |(info, line): (&mut DetailedFlexLineInfo, &FlexLine)| {
+            info.cross_offset = total_offset_cross + line.offset_cross;
+            total_offset_cross += line.offset_cross + line.cross_size;
+        };
+        if constants.is_wrap_reverse {
+            lines.iter_mut().zip(flex_lines.iter()).rev().for_each(&mut position_line);
+        } else {
+            lines.iter_mut().zip(flex_lines.iter()).for_each(&mut position_line);
+        }
+
+        DetailedFlexboxInfo { lines }
+    }
+}
+
 /// Do a final layout pass and collect the resulting layouts.
 #[inline]
 fn final_layout_pass(
@@ -2031,6 +2215,7 @@ fn final_layout_pass(
                 constants.node_inner_size,
                 constants.content_box_inset,
                 constants.dir,
+                constants.flex_grow_precision,
             );
         }
     } else {
@@ -2045,6 +2230,7 @@ fn final_layout_pass(
                 constants.node_inner_size,
                 constants.content_box_inset,
                 constants.dir,
+                constants.flex_grow_precision,
             );
         }
     }
@@ -2062,8 +2248,6 @@ fn perform_absolute_layout_on_absolute_children(
     node: NodeId,
     constants: &AlgoConstants,
 ) -> Size<f32> {
-    let container_width = constants.container_size.width;
-    let container_height = constants.container_size.height;
     let inset_relative_size =
         constants.container_size - constants.border.sum_axes() - constants.scrollbar_gutter.into();
 
@@ -2075,8 +2259,7 @@ fn perform_absolute_layout_on_absolute_children(
         let child_style = tree.get_flexbox_child_style(child);
 
         // Skip items that are display:none or are not position:absolute
-        if child_style.box_generation_mode() == BoxGenerationMode::None || child_style.position() != Position::Absolute
-        {
+        if ChildKind::of(&child_style) != ChildKind::Absolute {
             continue;
         }
 
@@ -2145,14 +2328,82 @@ fn perform_absolute_layout_on_absolute_children(
             known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
             known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
         }
+        // The static position of an item whose insets in an axis are both auto is where it would be if it were the
+        // sole flex item, which depends on the alignment of the container and item. The flex-relative values are
+        // flipped when the main axis is reversed (wrap-reverse only affects the cross axis)
+        // Stretch is an invalid value for justify_content in the flexbox algorithm, so we treat it as if it wasn't set
+        // (and thus we default to FlexStart behaviour). The fallback alignment of space-between is also flex-start.
+        let static_main_alignment =
+            match (constants.justify_content.unwrap_or(JustifyContent::FlexStart), constants.dir.is_reverse()) {
+                (JustifyContent::Start, _)
+                | (JustifyContent::Stretch, false)
+                | (JustifyContent::SpaceBetween, false)
+                | (JustifyContent::FlexStart, false)
+                | (JustifyContent::FlexEnd, true) => AlignSelf::Start,
+                (JustifyContent::End, _)
+                | (JustifyContent::FlexEnd, false)
+                | (JustifyContent::FlexStart, true)
+                | (JustifyContent::SpaceBetween, true)
+                | (JustifyContent::Stretch, true) => AlignSelf::End,
+                (JustifyContent::SpaceEvenly, _) | (JustifyContent::SpaceAround, _) | (JustifyContent::Center, _) => {
+                    AlignSelf::Center
+                }
+            };
+        let static_cross_alignment = match (align_self, constants.is_wrap_reverse) {
+            // Stretch alignment does not apply to absolutely positioned items
+            // See "Example 3" at https://www.w3.org/TR/css-flexbox-1/#abspos-items
+            // Note: Stretch should be FlexStart not Start when we support both
+            (AlignSelf::Start, _)
+            | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, false)
+            | (AlignSelf::FlexEnd, true) => AlignSelf::Start,
+            (AlignSelf::End, _)
+            | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, true)
+            | (AlignSelf::FlexEnd, false) => AlignSelf::End,
+            (AlignSelf::Center, _) => AlignSelf::Center,
+        };
+
+        // Auto sizes shrink-to-fit the inset-modified containing block. An auto inset is zero, except that when both
+        // insets in an axis are auto the containing block is limited by the static position
+        // The item's margins are subtracted from this available space when it is laid out
+        // See https://www.w3.org/TR/css-position-3/#abspos-auto-size
+        let padding_box_inset = Rect {
+            left: constants.content_box_inset.left - constants.border.left,
+            right: constants.content_box_inset.right - constants.border.right - constants.scrollbar_gutter.x,
+            top: constants.content_box_inset.top - constants.border.top,
+            bottom: constants.content_box_inset.bottom - constants.border.bottom - constants.scrollbar_gutter.y,
+        };
+        let (horizontal_alignment, vertical_alignment) = if constants.is_row {
+            (static_main_alignment, static_cross_alignment)
+        } else {
+            (static_cross_alignment, static_main_alignment)
+        };
+        let available_space = Size {
+            width: match (left, right) {
+                (None, None) => static_position_available_space(
+                    horizontal_alignment,
+                    inset_relative_size.width,
+                    padding_box_inset.horizontal_components(),
+                ),
+                (left, right) => inset_relative_size.width - left.unwrap_or(0.0) - right.unwrap_or(0.0),
+            },
+            height: match (top, bottom) {
+                (None, None) => static_position_available_space(
+                    vertical_alignment,
+                    inset_relative_size.height,
+                    padding_box_inset.vertical_components(),
+                ),
+                (top, bottom) => inset_relative_size.height - top.unwrap_or(0.0) - bottom.unwrap_or(0.0),
+            },
+        }
+        .f32_max(Size::ZERO)
+        .maybe_clamp(min_size, max_size)
+        .map(AvailableSpace::Definite);
+
         let measured_size = tree.measure_child_size_both(
             child,
             known_dimensions,
             constants.node_inner_size,
-            Size {
-                width: AvailableSpace::Definite(container_width.maybe_clamp(min_size.width, max_size.width)),
-                height: AvailableSpace::Definite(container_height.maybe_clamp(min_size.height, max_size.height)),
-            },
+            available_space,
             SizingMode::InherentSize,
             Line::FALSE,
         );
@@ -2162,10 +2413,7 @@ fn perform_absolute_layout_on_absolute_children(
             child,
             final_size.map(Some),
             constants.node_inner_size,
-            Size {
-                width: AvailableSpace::Definite(container_width.maybe_clamp(min_size.width, max_size.width)),
-                height: AvailableSpace::Definite(container_height.maybe_clamp(min_size.height, max_size.height)),
-            },
+            available_space,
             SizingMode::InherentSize,
             Line::FALSE,
         );
@@ -2223,26 +2471,14 @@ fn perform_absolute_layout_on_absolute_children(
                 - end
                 - resolved_margin.main_end(constants.dir)
         } else {
-            // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
-            // treat it as if it wasn't set (and thus we default to FlexStart behaviour)
-            match (constants.justify_content.unwrap_or(JustifyContent::Start), constants.is_wrap_reverse) {
-                (JustifyContent::SpaceBetween, _)
-                | (JustifyContent::Start, _)
-                | (JustifyContent::Stretch, false)
-                | (JustifyContent::FlexStart, false)
-                | (JustifyContent::FlexEnd, true) => {
-                    constants.content_box_inset.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
-                }
-                (JustifyContent::End, _)
-                | (JustifyContent::FlexEnd, false)
-                | (JustifyContent::FlexStart, true)
-                | (JustifyContent::Stretch, true) => {
+            match static_main_alignment {
+                AlignSelf::End => {
                     constants.container_size.main(constants.dir)
                         - constants.content_box_inset.main_end(constants.dir)
                         - final_size.main(constants.dir)
                         - resolved_margin.main_end(constants.dir)
                 }
-                (JustifyContent::SpaceEvenly, _) | (JustifyContent::SpaceAround, _) | (JustifyContent::Center, _) => {
+                AlignSelf::Center => {
                     (constants.container_size.main(constants.dir)
                         + constants.content_box_inset.main_start(constants.dir)
                         - constants.content_box_inset.main_end(constants.dir)
@@ -2251,6 +2487,7 @@ fn perform_absolute_layout_on_absolute_children(
                         - resolved_margin.main_end(constants.dir))
                         / 2.0
                 }
+                _ => constants.content_box_inset.main_start(constants.dir) + resolved_margin.main_start(constants.dir),
             }
         };
 
@@ -2266,24 +2503,14 @@ fn perform_absolute_layout_on_absolute_children(
                 - end
                 - resolved_margin.cross_end(constants.dir)
         } else {
-            match (align_self, constants.is_wrap_reverse) {
-                // Stretch alignment does not apply to absolutely positioned items
-                // See "Example 3" at https://www.w3.org/TR/css-flexbox-1/#abspos-items
-                // Note: Stretch should be FlexStart not Start when we support both
-                (AlignSelf::Start, _)
-                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, false)
-                | (AlignSelf::FlexEnd, true) => {
-                    constants.content_box_inset.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
-                }
-                (AlignSelf::End, _)
-                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, true)
-                | (AlignSelf::FlexEnd, false) => {
+            match static_cross_alignment {
+                AlignSelf::End => {
                     constants.container_size.cross(constants.dir)
                         - constants.content_box_inset.cross_end(constants.dir)
                         - final_size.cross(constants.dir)
                         - resolved_margin.cross_end(constants.dir)
                 }
-                (AlignSelf::Center, _) => {
+                AlignSelf::Center => {
                     (constants.container_size.cross(constants.dir)
                         + constants.content_box_inset.cross_start(constants.dir)
                         - constants.content_box_inset.cross_end(constants.dir)
@@ -2292,6 +2519,9 @@ fn perform_absolute_layout_on_absolute_children(
                         - resolved_margin.cross_end(constants.dir))
                         / 2.0
                 }
+                _ => {
+                    constants.content_box_inset.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
+                }
             }
         };
 
@@ -2343,6 +2573,25 @@ fn perform_absolute_layout_on_absolute_children(
     content_size
 }
 
+/// The space available to an absolutely positioned item whose insets in an axis are both auto, given its alignment
+/// in that axis, the size of the padding box and the container's padding in that axis
+///
+/// The item's static position is at the start, end or center of the content box. The inset-modified containing block
+/// then extends from the static position to the edge of the padding box that the item grows towards, or in both
+/// directions by the distance to the nearer edge for a centered item.
+/// See https://www.w3.org/TR/css-position-3/#staticpos-rect
+#[inline(always)]
+fn static_position_available_space(alignment: AlignSelf, padding_box_size: f32, padding: Line<f32>) -> f32 {
+    match alignment {
+        AlignSelf::End => padding_box_size - padding.end,
+        AlignSelf::Center => {
+            let static_position = padding.start + (padding_box_size - padding.start - padding.end) / 2.0;
+            2.0 * f32_min(static_position, padding_box_size - static_position)
+        }
+        _ => padding_box_size - padding.start,
+    }
+}
+
 /// Computes the total space taken up by gaps in an axis given:
 ///   - The size of each gap
 ///   - The number of items (children or flex-lines) between which there are gaps
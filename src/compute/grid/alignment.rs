@@ -3,7 +3,7 @@ use super::types::GridTrack;
 use crate::compute::common::alignment::{apply_alignment_fallback, compute_alignment_offset};
 use crate::geometry::{InBothAbsAxis, Line, Point, Rect, Size};
 use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, CoreStyle, GridItemStyle, Overflow, Position};
-use crate::tree::{Layout, LayoutPartialTreeExt, NodeId, SizingMode};
+use crate::tree::{Layout, LayoutPartialTreeExt, LayoutWarning, NodeId, SizingMode};
 use crate::util::sys::f32_max;
 use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
 
@@ -14,12 +14,16 @@ use crate::{BoxSizing, LayoutGridContainer};
 /// Align the grid tracks within the grid according to the align-content (rows) or
 /// justify-content (columns) property. This only does anything if the size of the
 /// grid is not equal to the size of the grid container in the axis being aligned.
+///
+/// In a scroll container alignment is always "safe", so that overflowing tracks can't be pushed into
+/// space before the start edge that can't be scrolled to.
 pub(super) fn align_tracks(
     grid_container_content_box_size: f32,
     padding: Line<f32>,
     border: Line<f32>,
     tracks: &mut [GridTrack],
     track_alignment_style: AlignContent,
+    is_scroll_container: bool,
 ) {
     let used_size: f32 = tracks.iter().map(|track| track.base_size).sum();
     let free_space = grid_container_content_box_size - used_size;
@@ -32,7 +36,7 @@ pub(super) fn align_tracks(
     // simply pass zero here. Grid layout is never reversed.
     let gap = 0.0;
     let layout_is_reversed = false;
-    let is_safe = false; // TODO: Implement safe alignment
+    let is_safe = is_scroll_container;
     let track_alignment = apply_alignment_fallback(free_space, num_tracks, track_alignment_style, is_safe);
 
     // Compute offsets
@@ -200,12 +204,30 @@ pub(super) fn align_and_position_item(
     // Layout node
     drop(style);
 
+    // Auto sizes of absolutely positioned items shrink-to-fit the grid area less their insets. An auto inset is zero:
+    // when both insets in an axis are auto the static position rectangle is the grid area itself (the padding box for
+    // auto placement), and the item is aligned within it with justify-self or align-self
+    // See https://www.w3.org/TR/css-position-3/#abspos-auto-size and https://www.w3.org/TR/css-grid-1/#abspos-items
+    let available_space = if position == Position::Absolute {
+        Size {
+            width: grid_area_minus_item_margins_size.width
+                - inset_horizontal.start.unwrap_or(0.0)
+                - inset_horizontal.end.unwrap_or(0.0),
+            height: grid_area_minus_item_margins_size.height
+                - inset_vertical.start.unwrap_or(0.0)
+                - inset_vertical.end.unwrap_or(0.0),
+        }
+        .f32_max(Size::ZERO)
+    } else {
+        grid_area_minus_item_margins_size
+    };
+
     let size = if position == Position::Absolute && (width.is_none() || height.is_none()) {
         tree.measure_child_size_both(
             node,
             Size { width, height },
             grid_area_size.map(Option::Some),
-            grid_area_minus_item_margins_size.map(AvailableSpace::Definite),
+            available_space.map(AvailableSpace::Definite),
             SizingMode::InherentSize,
             Line::FALSE,
         )
@@ -218,7 +240,7 @@ pub(super) fn align_and_position_item(
         node,
         size,
         grid_area_size.map(Option::Some),
-        grid_area_minus_item_margins_size.map(AvailableSpace::Definite),
+        available_space.map(AvailableSpace::Definite),
         SizingMode::InherentSize,
         Line::FALSE,
     );
@@ -252,6 +274,15 @@ pub(super) fn align_and_position_item(
 
     let resolved_margin = Rect { left: x_margin.start, right: x_margin.end, top: y_margin.start, bottom: y_margin.end };
 
+    // The negative free space of items which don't fit in their grid area is treated as zero when aligning them
+    // Ignore overflow which is only the result of floating point error
+    if position != Position::Absolute
+        && (width > grid_area_minus_item_margins_size.width + 0.01
+            || height > grid_area_minus_item_margins_size.height + 0.01)
+    {
+        tree.report_warning(node, LayoutWarning::GridItemOverflow);
+    }
+
     tree.set_unrounded_layout(
         node,
         &Layout {
@@ -1,16 +1,18 @@
 //! This module is a partial implementation of the CSS Grid Level 1 specification
 //! <https://www.w3.org/TR/css-grid-1>
+use super::common::children::ChildKind;
 use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AlignItems, AlignSelf, AvailableSpace, Overflow, Position};
+use crate::style::{AlignItems, AlignSelf, AvailableSpace, Overflow};
 use crate::tree::{Layout, LayoutInput, LayoutOutput, LayoutPartialTreeExt, NodeId, RunMode, SizingMode};
+use crate::util::arena::{scratch_vec_with_capacity, with_scratch, Scratch};
 use crate::util::debug::debug_log;
 use crate::util::sys::{f32_max, GridTrackVec, Vec};
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{
-    style_helpers::*, AlignContent, BoxGenerationMode, BoxSizing, CoreStyle, GridContainerStyle, GridItemStyle,
-    JustifyContent, LayoutGridContainer,
+    style_helpers::*, AlignContent, BoxSizing, CoreStyle, GridContainerStyle, GridItemStyle, JustifyContent,
+    LayoutGridContainer,
 };
 use alignment::{align_and_position_item, align_tracks};
 use explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks, AutoRepeatStrategy};
@@ -40,10 +42,24 @@ mod util;
 ///   - Placing items (which also resolves the implicit grid)
 ///   - Track (row/column) sizing
 ///   - Alignment & Final item placement
+///
+/// The result depends only on the styles of the container and its children and on the order of the children.
+/// In particular, auto-placement places items in child order and named lines and areas are only ever looked up by name
+/// (never iterated), so the layout is the same across runs and platforms regardless of hash map iteration order.
 pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     tree: &mut Tree,
     node: NodeId,
     inputs: LayoutInput,
+) -> LayoutOutput {
+    with_scratch(|scratch| compute_grid_layout_with_scratch(tree, node, inputs, scratch))
+}
+
+/// Grid layout, allocating the temporary data structures of the pass from `scratch`
+fn compute_grid_layout_with_scratch<Tree: LayoutGridContainer>(
+    tree: &mut Tree,
+    node: NodeId,
+    inputs: LayoutInput,
+    scratch: Scratch,
 ) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
 
@@ -93,6 +109,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     let align_content = style.align_content().unwrap_or(AlignContent::Stretch);
     let justify_content = style.justify_content().unwrap_or(JustifyContent::Stretch);
+    let is_scroll_container = style.overflow().map(Overflow::is_scroll_container);
     let align_items = style.align_items();
     let justify_items = style.justify_items();
 
@@ -195,15 +212,13 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     // 4. Grid Item Placement
     // Match items (children) to a definite grid position (row start/end and column start/end position)
-    let mut items = Vec::with_capacity(tree.child_count(node));
+    let mut items = scratch_vec_with_capacity(tree.child_count(node), scratch);
     let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
     let in_flow_children_iter = || {
         tree.child_ids(node)
             .enumerate()
             .map(|(index, child_node)| (index, child_node, tree.get_grid_child_style(child_node)))
-            .filter(|(_, _, style)| {
-                style.box_generation_mode() != BoxGenerationMode::None && style.position() != Position::Absolute
-            })
+            .filter(|(_, _, style)| ChildKind::of(style) == ChildKind::InFlow)
     };
     place_grid_items(
         &mut cell_occupancy_matrix,
@@ -479,6 +494,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         Line { start: border.left, end: border.right },
         &mut columns,
         justify_content,
+        is_scroll_container.x,
     );
     // Align rows
     align_tracks(
@@ -487,6 +503,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         Line { start: border.top, end: border.bottom },
         &mut rows,
         align_content,
+        is_scroll_container.y,
     );
 
     // 9. Size, Align, and Position Grid Items
@@ -530,9 +547,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     (0..tree.child_count(node)).for_each(|index| {
         let child = tree.get_child_id(node, index);
         let child_style = tree.get_grid_child_style(child);
+        let child_kind = ChildKind::of(&child_style);
 
         // Position hidden child
-        if child_style.box_generation_mode() == BoxGenerationMode::None {
+        if child_kind == ChildKind::Hidden {
             drop(child_style);
             tree.set_unrounded_layout(child, &Layout::with_order(order));
             tree.perform_child_layout(
@@ -548,7 +566,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         }
 
         // Position absolutely positioned child
-        if child_style.position() == Position::Absolute {
+        if child_kind == ChildKind::Absolute {
             // Convert grid-col-{start/end} into Option's of indexes into the columns vector
             // The Option is None if the style property is Auto and an unresolvable Span
             let maybe_col_indexes = name_resolver
@@ -649,6 +667,8 @@ pub struct DetailedGridInfo {
     pub rows: DetailedGridTracksInfo,
     /// <https://drafts.csswg.org/css-grid-1/#grid-column>
     pub columns: DetailedGridTracksInfo,
+    /// The placement of each in-flow item, in the order of the children
+    ///
     /// <https://drafts.csswg.org/css-grid-1/#grid-items>
     pub items: Vec<DetailedGridItemsInfo>,
 }
@@ -668,6 +688,11 @@ pub struct DetailedGridTracksInfo {
     pub gutters: Vec<f32>,
     /// The used size of the tracks
     pub sizes: Vec<f32>,
+    /// The offset of the start edge of each track from the start edge of the grid container's border box
+    ///
+    /// Together with [`sizes`](Self::sizes) this gives the position of each track, including any space that was
+    /// distributed between the tracks by `align-content` or `justify-content`.
+    pub offsets: Vec<f32>,
 }
 
 #[cfg(feature = "detailed_layout_info")]
@@ -684,6 +709,11 @@ impl DetailedGridTracksInfo {
             .collect()
     }
 
+    /// Get the offsets of the tracks
+    fn offsets_from_grid_track_layout(grid_tracks: &[GridTrack]) -> Vec<f32> {
+        grid_tracks.iter().filter(|track| track.kind == GridTrackKind::Track).map(|track| track.offset).collect()
+    }
+
     /// Get the sizes of the gutters
     fn gutters_from_grid_track_layout(grid_tracks: &[GridTrack]) -> Vec<f32> {
         DetailedGridTracksInfo::grid_track_base_size_of_kind(grid_tracks, GridTrackKind::Gutter)
@@ -702,6 +732,7 @@ impl DetailedGridTracksInfo {
             positive_implicit_tracks: track_count.positive_implicit,
             gutters: DetailedGridTracksInfo::gutters_from_grid_track_layout(&grid_tracks),
             sizes: DetailedGridTracksInfo::sizes_from_grid_track_layout(&grid_tracks),
+            offsets: DetailedGridTracksInfo::offsets_from_grid_track_layout(&grid_tracks),
         }
     }
 }
@@ -713,6 +744,14 @@ impl DetailedGridTracksInfo {
 #[derive(Debug, Clone, PartialEq)]
 #[cfg(feature = "detailed_layout_info")]
 pub struct DetailedGridItemsInfo {
+    /// The child which is placed
+    pub node: NodeId,
+    /// The position of the item in the order in which the placement algorithm placed the items
+    ///
+    /// Items with a definite position in both axes are placed first, then items with a definite position in the
+    /// secondary axis of `grid-auto-flow` (the row axis for `row` flow), and then all other items. Within each of these
+    /// groups items are placed in the order of the children.
+    pub placement_order: u16,
     /// row-start with 1-indexed grid line numbers
     pub row_start: u16,
     /// row-end with 1-indexed grid line numbers
@@ -736,6 +775,8 @@ impl DetailedGridItemsInfo {
         }
 
         DetailedGridItemsInfo {
+            node: grid_item.node,
+            placement_order: grid_item.placement_order,
             row_start: to_one_indexed_grid_line(grid_item.row_indexes.start),
             row_end: to_one_indexed_grid_line(grid_item.row_indexes.end),
             column_start: to_one_indexed_grid_line(grid_item.column_indexes.start),
@@ -6,16 +6,27 @@ use crate::geometry::Line;
 use crate::geometry::{AbsoluteAxis, InBothAbsAxis};
 use crate::style::{AlignItems, GridAutoFlow, OriginZeroGridPlacement};
 use crate::tree::NodeId;
-use crate::util::sys::Vec;
+use crate::util::arena::ScratchVec;
 use crate::{CoreStyle, GridItemStyle};
 
 /// 8.5. Grid Item Placement Algorithm
 /// Place items into the grid, generating new rows/column into the implicit grid as required
 ///
+/// Items are placed in a fixed order, so that the result doesn't depend on anything but the styles and the order
+/// of the children:
+///
+/// 1. Items with a definite position in both axes
+/// 2. Items with a definite position in the secondary axis only (the row axis for `grid-auto-flow: row`)
+/// 3. All other items (with a definite position in the primary axis only, or no definite position), which are
+///    auto-placed around the items already placed
+///
+/// Within each step the items are placed in the order of the children. The order in which the items were placed
+/// is recorded in [`GridItem::placement_order`], and the items are pushed to `items` in that order.
+///
 /// [Specification](https://www.w3.org/TR/css-grid-2/#auto-placement-algo)
 pub(super) fn place_grid_items<'a, S, ChildIter>(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
-    items: &mut Vec<GridItem>,
+    items: &mut ScratchVec<GridItem>,
     children_iter: impl Fn() -> ChildIter,
     grid_auto_flow: GridAutoFlow,
     align_items: AlignItems,
@@ -308,7 +319,7 @@ fn place_indefinitely_positioned_item(
 #[allow(clippy::too_many_arguments)]
 fn record_grid_placement<S: GridItemStyle>(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
-    items: &mut Vec<GridItem>,
+    items: &mut ScratchVec<GridItem>,
     node: NodeId,
     index: usize,
     style: S,
@@ -332,7 +343,7 @@ fn record_grid_placement<S: GridItemStyle>(
         AbsoluteAxis::Horizontal => (primary_span, secondary_span),
         AbsoluteAxis::Vertical => (secondary_span, primary_span),
     };
-    items.push(GridItem::new_with_placement_style_and_order(
+    let mut item = GridItem::new_with_placement_style_and_order(
         node,
         col_span,
         row_span,
@@ -340,7 +351,9 @@ fn record_grid_placement<S: GridItemStyle>(
         parent_align_items,
         parent_justify_items,
         index as u16,
-    ));
+    );
+    item.placement_order = items.len() as u16;
+    items.push(item);
 
     #[cfg(test)]
     println!("AFTER placement:");
@@ -361,6 +374,7 @@ mod tests {
         use crate::compute::grid::NamedLineResolver;
         use crate::prelude::*;
         use crate::style::GridAutoFlow;
+        use crate::util::arena::{scratch_vec_with_capacity, with_scratch};
 
         use super::super::place_grid_items;
 
@@ -374,44 +388,50 @@ mod tests {
             expected_row_counts: TrackCounts,
             flow: GridAutoFlow,
         ) {
-            // Setup test
-            let children_iter = || children.iter().map(|(index, style, _)| (*index, NodeId::from(*index), style));
-            let child_styles_iter = children.iter().map(|(_, style, _)| style);
-            let estimated_sizes = compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
-            let mut items = Vec::new();
-            let mut cell_occupancy_matrix =
-                CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1);
-            let mut name_resolver = NamedLineResolver::new(&Style::DEFAULT, 0, 0);
-            name_resolver.set_explicit_column_count(explicit_col_count);
-            name_resolver.set_explicit_row_count(explicit_row_count);
-
-            // Run placement algorithm
-            place_grid_items(
-                &mut cell_occupancy_matrix,
-                &mut items,
-                children_iter,
-                flow,
-                AlignSelf::Start,
-                AlignSelf::Start,
-                // TODO: actually test named line resolution
-                &name_resolver,
-            );
-
-            // Assert that each item has been placed in the right location
-            let mut sorted_children = children.clone();
-            sorted_children.sort_by_key(|child| child.0);
-            for (idx, ((id, _style, expected_placement), item)) in sorted_children.iter().zip(items.iter()).enumerate()
-            {
-                assert_eq!(item.node, NodeId::from(*id));
-                let actual_placement = (item.column.start, item.column.end, item.row.start, item.row.end);
-                assert_eq!(actual_placement, (*expected_placement).into_oz(), "Item {idx} (0-indexed)");
-            }
+            with_scratch(|scratch| {
+                // Setup test
+                let children_iter = || children.iter().map(|(index, style, _)| (*index, NodeId::from(*index), style));
+                let child_styles_iter = children.iter().map(|(_, style, _)| style);
+                let estimated_sizes =
+                    compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+                let mut items = scratch_vec_with_capacity(children.len(), scratch);
+                let mut cell_occupancy_matrix =
+                    CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1);
+                let mut name_resolver = NamedLineResolver::new(&Style::DEFAULT, 0, 0);
+                name_resolver.set_explicit_column_count(explicit_col_count);
+                name_resolver.set_explicit_row_count(explicit_row_count);
+
+                // Run placement algorithm
+                place_grid_items(
+                    &mut cell_occupancy_matrix,
+                    &mut items,
+                    children_iter,
+                    flow,
+                    AlignSelf::Start,
+                    AlignSelf::Start,
+                    // TODO: actually test named line resolution
+                    &name_resolver,
+                );
+
+                // Assert that each item has been placed in the right location
+                let mut sorted_children = children.clone();
+                sorted_children.sort_by_key(|child| child.0);
+                for (idx, ((id, _style, expected_placement), item)) in
+                    sorted_children.iter().zip(items.iter()).enumerate()
+                {
+                    assert_eq!(item.node, NodeId::from(*id));
+                    let actual_placement = (item.column.start, item.column.end, item.row.start, item.row.end);
+                    assert_eq!(actual_placement, (*expected_placement).into_oz(), "Item {idx} (0-indexed)");
+                }
 
-            // Assert that the correct number of implicit rows have been generated
-            let actual_row_counts = *cell_occupancy_matrix.track_counts(crate::compute::grid::AbsoluteAxis::Vertical);
-            assert_eq!(actual_row_counts, expected_row_counts, "row track counts");
-            let actual_col_counts = *cell_occupancy_matrix.track_counts(crate::compute::grid::AbsoluteAxis::Horizontal);
-            assert_eq!(actual_col_counts, expected_col_counts, "column track counts");
+                // Assert that the correct number of implicit rows have been generated
+                let actual_row_counts =
+                    *cell_occupancy_matrix.track_counts(crate::compute::grid::AbsoluteAxis::Vertical);
+                assert_eq!(actual_row_counts, expected_row_counts, "row track counts");
+                let actual_col_counts =
+                    *cell_occupancy_matrix.track_counts(crate::compute::grid::AbsoluteAxis::Horizontal);
+                assert_eq!(actual_col_counts, expected_col_counts, "column track counts");
+            });
         }
 
         #[test]
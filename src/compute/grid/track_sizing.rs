@@ -5,7 +5,7 @@ use crate::geometry::{AbstractAxis, Line, Size};
 use crate::style::{AlignContent, AlignSelf, AvailableSpace};
 use crate::style_helpers::TaffyMinContent;
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, SizingMode};
-use crate::util::sys::{f32_max, f32_min, Vec};
+use crate::util::sys::{f32_max, f32_min};
 use crate::util::{MaybeMath, ResolveOrZero};
 use crate::CompactLength;
 use core::cmp::Ordering;
@@ -247,7 +247,7 @@ pub(super) fn resolve_item_track_indexes(items: &mut [GridItem], column_counts:
 /// Determine (in each axis) whether the item crosses any flexible tracks
 #[inline(always)]
 pub(super) fn determine_if_item_crosses_flexible_or_intrinsic_tracks(
-    items: &mut Vec<GridItem>,
+    items: &mut [GridItem],
     columns: &[GridTrack],
     rows: &[GridTrack],
 ) {
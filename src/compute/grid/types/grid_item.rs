@@ -21,6 +21,10 @@ pub(in super::super) struct GridItem {
     /// for final positioning
     pub source_order: u16,
 
+    /// The position of the item in the order in which the placement algorithm placed the items
+    /// (see [`place_grid_items`](super::super::placement::place_grid_items))
+    pub placement_order: u16,
+
     /// The item's definite row-start and row-end, as resolved by the placement algorithm
     /// (in origin-zero coordinates)
     pub row: Line<OriginZeroLine>,
@@ -105,6 +109,7 @@ impl GridItem {
         GridItem {
             node,
             source_order,
+            placement_order: 0, // Properly initialised later
             row: row_span,
             column: col_span,
             is_compressible_replaced: style.is_compressible_replaced(),
@@ -250,8 +255,9 @@ impl GridItem {
         let margins = self.margins_axis_sums_with_baseline_shims(inner_node_size.width, tree);
 
         let aspect_ratio = self.aspect_ratio;
-        let padding = self.padding.resolve_or_zero(grid_area_size, |val, basis| tree.calc(val, basis));
-        let border = self.border.resolve_or_zero(grid_area_size, |val, basis| tree.calc(val, basis));
+        let padding_basis = tree.percentage_padding_basis(grid_area_size);
+        let padding = self.padding.resolve_or_zero(padding_basis, |val, basis| tree.calc(val, basis));
+        let border = self.border.resolve_or_zero(padding_basis, |val, basis| tree.calc(val, basis));
         let padding_border_size = (padding + border).sum_axes();
         let box_sizing_adjustment =
             if self.box_sizing == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
@@ -464,8 +470,9 @@ impl GridItem {
         known_dimensions: Size<Option<f32>>,
         inner_node_size: Size<Option<f32>>,
     ) -> f32 {
-        let padding = self.padding.resolve_or_zero(inner_node_size, |val, basis| tree.calc(val, basis));
-        let border = self.border.resolve_or_zero(inner_node_size, |val, basis| tree.calc(val, basis));
+        let padding_basis = tree.percentage_padding_basis(inner_node_size);
+        let padding = self.padding.resolve_or_zero(padding_basis, |val, basis| tree.calc(val, basis));
+        let border = self.border.resolve_or_zero(padding_basis, |val, basis| tree.calc(val, basis));
         let padding_border_size = (padding + border).sum_axes();
         let box_sizing_adjustment =
             if self.box_sizing == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
@@ -531,7 +538,7 @@ impl GridItem {
         // Note: The argument to fit-content() does not clamp the content-based minimum size in the same way as a fixed max track
         // sizing function.
         let limit = self.spanned_fixed_track_limit(axis, axis_tracks, inner_node_size.get(axis), &|val, basis| {
-            tree.resolve_calc_value(val, basis)
+            tree.calc(val, basis)
         });
         size.maybe_min(limit)
     }
@@ -392,8 +392,11 @@ impl<S: CheapCloneStr> NamedLineResolver<S> {
 
 impl<S: CheapCloneStr> Debug for NamedLineResolver<S> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The maps are iterated in name order so that the output doesn't depend on the order of a `HashMap`
+        let mut areas: Vec<_> = self.areas.values().collect();
+        areas.sort_unstable_by(|a, b| a.name.as_ref().cmp(b.name.as_ref()));
         writeln!(f, "Grid Areas:")?;
-        for area in self.areas.values() {
+        for area in areas {
             writeln!(
                 f,
                 "{}: row:{}/{} col: {}/{}",
@@ -406,7 +409,9 @@ impl<S: CheapCloneStr> Debug for NamedLineResolver<S> {
         }
 
         writeln!(f, "Grid Rows:")?;
-        for (name, lines) in self.row_lines.iter() {
+        let mut row_lines: Vec<_> = self.row_lines.iter().collect();
+        row_lines.sort_unstable_by(|(a, _), (b, _)| a.0.as_ref().cmp(b.0.as_ref()));
+        for (name, lines) in row_lines {
             write!(f, "{}: ", name.0.as_ref())?;
             for line in lines {
                 write!(f, "{line}  ")?;
@@ -415,7 +420,9 @@ impl<S: CheapCloneStr> Debug for NamedLineResolver<S> {
         }
 
         writeln!(f, "Grid Columns:")?;
-        for (name, lines) in self.column_lines.iter() {
+        let mut column_lines: Vec<_> = self.column_lines.iter().collect();
+        column_lines.sort_unstable_by(|(a, _), (b, _)| a.0.as_ref().cmp(b.0.as_ref()));
+        for (name, lines) in column_lines {
             write!(f, "{}: ", name.0.as_ref())?;
             for line in lines {
                 write!(f, "{line}  ")?;
@@ -6,7 +6,7 @@ use crate::tree::{CollapsibleMarginSet, RunMode};
 use crate::tree::{LayoutInput, LayoutOutput, SizingMode};
 use crate::util::debug::debug_log;
 use crate::util::sys::f32_max;
-use crate::util::MaybeMath;
+use crate::util::{sanitize_f32, MaybeMath};
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BoxSizing, CoreStyle};
 use core::unreachable;
@@ -139,7 +139,8 @@ where
             RunMode::PerformHiddenLayout => unreachable!(),
         },
         available_space,
-    );
+    )
+    .map(|value| sanitize_f32(value, "measure function"));
     let clamped_size = known_dimensions
         .or(node_size)
         .unwrap_or(measured_size + content_box_inset.sum_axes())
@@ -23,6 +23,7 @@
 //!
 pub(crate) mod common;
 pub(crate) mod leaf;
+pub(crate) mod work_stack;
 
 #[cfg(feature = "block_layout")]
 pub(crate) mod block;
@@ -45,17 +46,29 @@ pub use self::flexbox::compute_flexbox_layout;
 pub use self::grid::compute_grid_layout;
 
 use crate::geometry::{Line, Point, Size};
-use crate::style::{AvailableSpace, CoreStyle, Overflow};
+use crate::style::{AvailableSpace, CoreStyle, Overflow, RoundingOrigin};
 use crate::tree::{
     Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RoundTree, SizingMode,
 };
 use crate::util::debug::{debug_log, debug_log_node, debug_pop_node, debug_push_node};
-use crate::util::sys::round;
+use crate::util::sys::{round, Vec};
 use crate::util::ResolveOrZero;
 use crate::{CacheTree, MaybeMath, MaybeResolve};
 
 /// Compute layout for the root node in the tree
+///
+/// With the `std` feature the depth of recursion is bounded: the layouts of nodes nested too deeply are deferred to an
+/// explicit work stack, so very deep trees don't overflow the call stack. This relies on the tree laying out its nodes
+/// through [`compute_cached_layout`] (and [`compute_hidden_layout`] for hidden nodes).
 pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, available_space: Size<AvailableSpace>) {
+    // An infinite amount of definite space is the same as max-content. NaN is a bug in the caller.
+    let available_space = available_space.map(|space| match space {
+        AvailableSpace::Definite(value) if !value.is_finite() => {
+            debug_assert!(!value.is_nan(), "compute_root_layout was called with a NaN available space");
+            AvailableSpace::MaxContent
+        }
+        space => space,
+    });
     let mut known_dimensions = Size::NONE;
 
     #[cfg(feature = "block_layout")]
@@ -114,23 +127,24 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
         }
     }
 
-    // Recursively compute node layout
-    let output = tree.perform_child_layout(
-        root,
-        known_dimensions,
-        available_space.into_options(),
-        available_space,
-        SizingMode::InherentSize,
-        Line::FALSE,
-    );
+    // Compute the node's layout, and the layouts of its descendants. Descendants deeper than the maximum depth of the
+    // call stack are deferred to the work stack of the pass, and the layout is run again once they are computed.
+    let mut output = LayoutOutput::HIDDEN;
+    work_stack::run_pass(tree, |tree| {
+        output = tree.perform_child_layout(
+            root,
+            known_dimensions,
+            available_space.into_options(),
+            available_space,
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+    });
 
     let style = tree.get_core_container_style(root);
-    let padding =
-        style.padding().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
-    let border =
-        style.border().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
-    let margin =
-        style.margin().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
+    let padding = style.padding().resolve_or_zero(available_space.width, |val, basis| tree.calc(val, basis));
+    let border = style.border().resolve_or_zero(available_space.width, |val, basis| tree.calc(val, basis));
+    let margin = style.margin().resolve_or_zero(available_space.width, |val, basis| tree.calc(val, basis));
     let scrollbar_size = Size {
         width: if style.overflow().y == Overflow::Scroll { style.scrollbar_width() } else { 0.0 },
         height: if style.overflow().x == Overflow::Scroll { style.scrollbar_width() } else { 0.0 },
@@ -170,6 +184,12 @@ where
     debug_push_node!(node);
     let LayoutInput { known_dimensions, available_space, run_mode, .. } = inputs;
 
+    // A deferred layout which was already computed in this pass, or a placeholder if the pass has been abandoned
+    if let Some(output) = work_stack::resolved_output(node, &inputs) {
+        debug_pop_node!();
+        return output;
+    }
+
     // First we check if we have a cached result for the given input
     let cache_entry = tree.cache_get(node, known_dimensions, available_space, run_mode);
     if let Some(cached_size_and_baselines) = cache_entry {
@@ -181,8 +201,21 @@ where
 
     debug_log_node!(known_dimensions, inputs.parent_size, available_space, run_mode, inputs.sizing_mode);
 
+    // Defer the layout to the work stack of the pass if the call stack is already too deep
+    let Some(_depth) = work_stack::descend(node, inputs) else {
+        debug_log!("DEFERRED");
+        debug_pop_node!();
+        return LayoutOutput::HIDDEN;
+    };
+
     let computed_size_and_baselines = compute_uncached(tree, node, inputs);
 
+    // Results computed with placeholders for deferred layouts are discarded
+    if work_stack::is_abandoned() {
+        debug_pop_node!();
+        return computed_size_and_baselines;
+    }
+
     // Cache result
     tree.cache_store(node, known_dimensions, available_space, run_mode, computed_size_and_baselines);
 
@@ -204,11 +237,14 @@ where
 ///
 /// In order to prevent innacuracies caused by rounding already-rounded values, we read from `unrounded_layout`
 /// and write to `final_layout`.
+///
+/// The tree is walked with an explicit stack rather than by recursion, so rounding doesn't overflow the call stack of
+/// very deep trees.
 pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
-    return round_layout_inner(tree, node_id, 0.0, 0.0);
-
-    /// Recursive function to apply rounding to all descendents
-    fn round_layout_inner(tree: &mut impl RoundTree, node_id: NodeId, cumulative_x: f32, cumulative_y: f32) {
+    // Each pending node along with the cumulative position of the origin its location is relative to
+    let mut stack: Vec<(NodeId, f32, f32)> = Vec::new();
+    stack.push((node_id, 0.0, 0.0));
+    while let Some((node_id, cumulative_x, cumulative_y)) = stack.pop() {
         let unrounded_layout = tree.get_unrounded_layout(node_id);
         let mut layout = unrounded_layout;
 
@@ -221,6 +257,12 @@ pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
         layout.size.height = round(cumulative_y + unrounded_layout.size.height) - round(cumulative_y);
         layout.scrollbar_size.width = round(unrounded_layout.scrollbar_size.width);
         layout.scrollbar_size.height = round(unrounded_layout.scrollbar_size.height);
+
+        // The insets of the node and the layouts of its descendants are rounded against the node's rounding origin
+        let (cumulative_x, cumulative_y) = match tree.get_rounding_origin(node_id) {
+            RoundingOrigin::Inherit => (cumulative_x, cumulative_y),
+            RoundingOrigin::Node => (0.0, 0.0),
+        };
         layout.border.left = round(cumulative_x + unrounded_layout.border.left) - round(cumulative_x);
         layout.border.right = round(cumulative_x + unrounded_layout.size.width)
             - round(cumulative_x + unrounded_layout.size.width - unrounded_layout.border.right);
@@ -239,10 +281,11 @@ pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
 
         tree.set_final_layout(node_id, &layout);
 
+        // Children are pushed in reverse so that they are rounded in order
         let child_count = tree.child_count(node_id);
-        for index in 0..child_count {
+        for index in (0..child_count).rev() {
             let child = tree.get_child_id(node_id, index);
-            round_layout_inner(tree, child, cumulative_x, cumulative_y);
+            stack.push((child, cumulative_x, cumulative_y));
         }
     }
 
@@ -268,9 +311,15 @@ pub fn compute_hidden_layout(tree: &mut (impl LayoutPartialTree + CacheTree), no
     tree.cache_clear(node);
     tree.set_unrounded_layout(node, &Layout::with_order(0));
 
-    // Perform hidden layout on all children
+    // Perform hidden layout on all children, deferring them to the work stack of the pass if the call stack is too deep
     for index in 0..tree.child_count(node) {
         let child_id = tree.get_child_id(node, index);
+        if work_stack::resolved_output(child_id, &LayoutInput::HIDDEN).is_some() {
+            continue;
+        }
+        let Some(_depth) = work_stack::descend(child_id, LayoutInput::HIDDEN) else {
+            break;
+        };
         tree.compute_child_layout(child_id, LayoutInput::HIDDEN);
     }
 
@@ -280,15 +329,18 @@ pub fn compute_hidden_layout(tree: &mut (impl LayoutPartialTree + CacheTree), no
 /// A module for unified re-exports of detailed layout info structs, used by low level API
 #[cfg(feature = "detailed_layout_info")]
 pub mod detailed_info {
+    #[cfg(feature = "flexbox")]
+    pub use super::flexbox::{DetailedFlexLineInfo, DetailedFlexboxInfo};
     #[cfg(feature = "grid")]
     pub use super::grid::{DetailedGridInfo, DetailedGridItemsInfo, DetailedGridTracksInfo};
 }
 
 #[cfg(test)]
 mod tests {
-    use super::compute_hidden_layout;
+    use super::{compute_hidden_layout, round_layout};
     use crate::geometry::{Point, Size};
     use crate::style::{Display, Style};
+    use crate::tree::{Layout, LayoutPartialTree};
     use crate::TaffyTree;
 
     #[test]
@@ -322,4 +374,31 @@ mod tests {
             assert_eq!(layout.location, Point::zero());
         }
     }
+
+    #[test]
+    fn round_layout_should_not_overflow_the_stack_of_deep_trees() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let mut root = leaf;
+        for _ in 0..100_000 {
+            root = taffy.new_with_children(Style::default(), &[root]).unwrap();
+        }
+
+        let mut node = Some(root);
+        while let Some(id) = node {
+            let mut layout = Layout::new();
+            layout.location = Point { x: 0.25, y: 0.25 };
+            layout.size = Size { width: 10.4, height: 10.4 };
+            taffy.as_layout_tree().set_unrounded_layout(id, &layout);
+            node = taffy.children(id).unwrap().first().copied();
+        }
+
+        round_layout(&mut taffy.as_layout_tree(), root);
+
+        // The leaf is rounded against the cumulative offset of all its ancestors (25000.25)
+        let layout = taffy.layout(leaf).unwrap();
+        assert_eq!(layout.location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(layout.size, Size { width: 11.0, height: 11.0 });
+    }
 }
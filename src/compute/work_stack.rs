@@ -0,0 +1,195 @@
+//! Bounds the depth of recursion during layout by deferring the layouts of deep nodes to an explicit work stack
+//!
+//! Layout algorithms lay out their children through [`LayoutPartialTree::compute_child_layout`], and need the result
+//! partway through their own layout, so a layout pass naturally recurses once for each level of the tree. Without a
+//! bound a tree which is thousands of levels deep overflows the call stack.
+//!
+//! Within a layout pass run by [`run_pass`], once [`MAX_DEPTH`] uncached layouts are in progress the request for the
+//! next node's layout is pushed onto the work stack of the pass instead, and the pass is abandoned: every layout still
+//! in progress is finished with a placeholder output and isn't cached. The deferred request is then computed from the
+//! bottom of the call stack and its output kept for the rest of the pass, before the abandoned layouts are started
+//! again. Layout is deterministic, so a restarted layout makes the same requests as the abandoned one up to the point
+//! at which it was abandoned, and gets at least one request further. The work stack is drained in last-in first-out
+//! order, so a deferred request which is itself abandoned is computed once the requests it deferred are done.
+//!
+//! The state of the passes is stored per thread, so it requires the `std` feature. Without it layout recurses as deep as
+//! the tree.
+use crate::tree::{LayoutInput, LayoutOutput, LayoutPartialTree, NodeId};
+
+/// The maximum number of uncached layouts which can be in progress on the call stack during a pass
+///
+/// The layout of a flexbox or grid container takes a few kilobytes of stack in debug builds, so this leaves plenty of
+/// room on a 2MB thread stack.
+#[cfg(feature = "std")]
+pub(crate) const MAX_DEPTH: usize = 64;
+
+/// The layout passes in progress on each thread
+#[cfg(feature = "std")]
+mod pass {
+    use super::MAX_DEPTH;
+    use crate::tree::{LayoutInput, LayoutOutput, NodeId};
+    use crate::util::sys::{Map, Vec};
+    use core::cell::RefCell;
+
+    /// The state of a layout pass
+    #[derive(Default)]
+    pub(super) struct Pass {
+        /// The number of uncached layouts in progress on the call stack
+        pub(super) depth: usize,
+        /// Whether a request was deferred, so the layouts in progress must be finished without being cached
+        pub(super) abandoned: bool,
+        /// The requests which were deferred and haven't been computed yet, innermost last
+        pub(super) deferred: Vec<(NodeId, LayoutInput)>,
+        /// The outputs of the requests which were deferred and then computed
+        pub(super) outputs: Map<NodeId, Vec<(LayoutInput, LayoutOutput)>>,
+    }
+
+    std::thread_local! {
+        /// The layout passes in progress on this thread, innermost last. Passes nest if a measure function lays out
+        /// another tree.
+        static PASSES: RefCell<Vec<Pass>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Calls `f` with the innermost pass in progress on this thread, if there is one
+    #[inline]
+    pub(super) fn with_pass<R>(f: impl FnOnce(Option<&mut Pass>) -> R) -> R {
+        PASSES.with(|passes| f(passes.borrow_mut().last_mut()))
+    }
+
+    /// Starts a new innermost pass, which ends when the returned guard is dropped
+    pub(super) fn begin() -> PassGuard {
+        PASSES.with(|passes| passes.borrow_mut().push(Pass::default()));
+        PassGuard
+    }
+
+    /// Ends the innermost pass when dropped, including when a layout panics
+    pub(super) struct PassGuard;
+
+    impl Drop for PassGuard {
+        fn drop(&mut self) {
+            PASSES.with(|passes| passes.borrow_mut().pop());
+        }
+    }
+
+    /// Decrements the depth of the innermost pass when dropped
+    pub(crate) struct DepthGuard(pub(super) bool);
+
+    impl Drop for DepthGuard {
+        fn drop(&mut self) {
+            if self.0 {
+                with_pass(|pass| {
+                    if let Some(pass) = pass {
+                        pass.depth -= 1;
+                    }
+                });
+            }
+        }
+    }
+
+    /// Returns the output to use in place of laying out the node, if it doesn't need to be laid out
+    pub(super) fn resolved_output(node: NodeId, inputs: &LayoutInput) -> Option<LayoutOutput> {
+        with_pass(|pass| {
+            let pass = pass?;
+            if pass.abandoned {
+                return Some(LayoutOutput::HIDDEN);
+            }
+            let outputs = pass.outputs.get(&node)?;
+            outputs.iter().find(|(entry_inputs, _)| entry_inputs == inputs).map(|(_, output)| *output)
+        })
+    }
+
+    /// Records that the layout of the node is in progress, or defers it and abandons the pass if too many layouts are
+    /// in progress already
+    pub(super) fn descend(node: NodeId, inputs: LayoutInput) -> Option<DepthGuard> {
+        with_pass(|pass| match pass {
+            None => Some(DepthGuard(false)),
+            Some(pass) if pass.depth < MAX_DEPTH => {
+                pass.depth += 1;
+                Some(DepthGuard(true))
+            }
+            Some(pass) => {
+                pass.deferred.push((node, inputs));
+                pass.abandoned = true;
+                None
+            }
+        })
+    }
+}
+
+/// Decrements the depth of the innermost pass when dropped
+#[cfg(feature = "std")]
+pub(crate) use pass::DepthGuard;
+/// Does nothing: the depth of layout is only tracked with the `std` feature
+#[cfg(not(feature = "std"))]
+pub(crate) struct DepthGuard;
+
+/// Runs `layout` as a layout pass, computing the requests it defers and then running it again until none are deferred
+pub(crate) fn run_pass<Tree: LayoutPartialTree + ?Sized>(tree: &mut Tree, mut layout: impl FnMut(&mut Tree)) {
+    #[cfg(feature = "std")]
+    {
+        let _pass = pass::begin();
+        loop {
+            layout(tree);
+            if !is_abandoned() {
+                return;
+            }
+
+            // Compute the deferred requests, innermost first, each with the whole call stack available to it
+            while let Some((node, inputs)) = pass::with_pass(|pass| {
+                let pass = pass.expect("the pass is in progress");
+                pass.abandoned = false;
+                pass.depth = 0;
+                pass.deferred.last().copied()
+            }) {
+                let output = tree.compute_child_layout(node, inputs);
+                pass::with_pass(|pass| {
+                    let pass = pass.expect("the pass is in progress");
+                    if !pass.abandoned {
+                        pass.deferred.pop();
+                        pass.outputs.entry(node).or_default().push((inputs, output));
+                    }
+                });
+            }
+        }
+    }
+    #[cfg(not(feature = "std"))]
+    layout(tree);
+}
+
+/// Returns the output to use in place of laying out the node with `inputs`, if it doesn't need to be laid out
+///
+/// That is either the output the node's deferred layout produced earlier in the pass, or a placeholder if the pass has
+/// been abandoned.
+#[inline]
+pub(crate) fn resolved_output(node: NodeId, inputs: &LayoutInput) -> Option<LayoutOutput> {
+    #[cfg(feature = "std")]
+    return pass::resolved_output(node, inputs);
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = (node, inputs);
+        None
+    }
+}
+
+/// Records that the layout of the node is starting, until the returned guard is dropped
+///
+/// Returns `None` if the layout was deferred instead, in which case the pass has been abandoned.
+#[inline]
+pub(crate) fn descend(node: NodeId, inputs: LayoutInput) -> Option<DepthGuard> {
+    #[cfg(feature = "std")]
+    return pass::descend(node, inputs);
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = (node, inputs);
+        Some(DepthGuard)
+    }
+}
+
+/// Whether the innermost pass has been abandoned, so layouts which finish shouldn't be cached or reported
+#[inline]
+pub(crate) fn is_abandoned() -> bool {
+    #[cfg(feature = "std")]
+    return pass::with_pass(|pass| pass.map_or(false, |pass| pass.abandoned));
+    #[cfg(not(feature = "std"))]
+    false
+}
@@ -0,0 +1,640 @@
+//! A ready-made [`egui`] panel for browsing and live-editing a [`TaffyTree`]
+use core::fmt::Debug;
+use core::hash::Hash;
+
+use egui::{CollapsingHeader, Color32, ComboBox, DragValue, Grid, Painter, Pos2, Stroke, StrokeKind, Ui, Vec2};
+
+use super::InspectorData;
+use crate::geometry::{Rect, Size};
+use crate::style::{
+    AlignContent, AlignItems, BoxSizing, CompactLength, Dimension, Display, LengthPercentage, LengthPercentageAuto,
+    Position, RoundingOrigin, StyleFieldValue,
+};
+#[cfg(feature = "flexbox")]
+use crate::style::{FlexDirection, FlexGrowPrecision, FlexWrap};
+use crate::tree::{NodeId, PrintTree, TaffyTree};
+
+/// The colours used to draw the margin, border, padding and content boxes, as in the box model diagram of browsers
+const BOX_MODEL_COLORS: [Color32; 4] = [
+    Color32::from_rgba_premultiplied(120, 80, 30, 140),
+    Color32::from_rgba_premultiplied(120, 110, 40, 140),
+    Color32::from_rgba_premultiplied(70, 100, 60, 140),
+    Color32::from_rgba_premultiplied(50, 80, 110, 140),
+];
+
+/// An egui panel which browses the nodes of a [`TaffyTree`], shows the computed layout and styles of the selected
+/// node, and lets the styles of the selected node be edited live
+///
+/// Call [`Inspector::show`] every frame to draw the panel, and [`Inspector::highlight_selected`] to draw the box model
+/// of the selected node over your own rendering of the tree.
+#[derive(Debug, Clone, Default)]
+pub struct Inspector {
+    /// The node whose layout and styles are shown
+    selected: Option<NodeId>,
+}
+
+impl Inspector {
+    /// Create an inspector with no node selected
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The node whose layout and styles are shown, if any
+    pub fn selected(&self) -> Option<NodeId> {
+        self.selected
+    }
+
+    /// Select the node whose layout and styles are shown
+    pub fn select(&mut self, node: Option<NodeId>) {
+        self.selected = node;
+    }
+
+    /// Draw the panel for the tree rooted at `root`
+    ///
+    /// Edits to the styles of the selected node are written back to the tree with [`TaffyTree::set_style`] once per
+    /// frame, so they are recorded by the tree's journal if it is enabled. Returns `true` if a style was edited, in
+    /// which case the layout needs to be recomputed.
+    pub fn show<NodeContext>(&mut self, ui: &mut Ui, tree: &mut TaffyTree<NodeContext>, root: NodeId) -> bool {
+        let mut selection_found = false;
+        CollapsingHeader::new("Tree").id_salt("taffy_inspector_tree").default_open(true).show(ui, |ui| {
+            self.show_node_tree(ui, tree, root, &mut selection_found);
+        });
+        // Forget the selection if the node has been removed from the tree
+        if !selection_found {
+            self.selected = None;
+        }
+
+        let Some(node) = self.selected else {
+            ui.label("Select a node to inspect it");
+            return false;
+        };
+        let Ok(data) = tree.inspector_data(node) else { return false };
+
+        CollapsingHeader::new("Layout").id_salt("taffy_inspector_layout").default_open(true).show(ui, |ui| {
+            show_box_model_diagram(ui, &data);
+            show_layout(ui, tree, node, &data);
+        });
+
+        let mut edited = false;
+        CollapsingHeader::new("Style").id_salt("taffy_inspector_style").default_open(true).show(ui, |ui| {
+            let Ok(style) = tree.style(node) else { return };
+            let mut style = style.clone();
+            let fields: Vec<_> = style.fields().collect();
+            Grid::new("taffy_inspector_style_grid").num_columns(2).striped(true).show(ui, |ui| {
+                for (name, mut value) in fields {
+                    ui.label(name);
+                    if edit_field(ui, name, &mut value) && style.set_field(name, value).is_ok() {
+                        edited = true;
+                    }
+                    ui.end_row();
+                }
+            });
+            if edited {
+                edited = tree.set_style(node, style).is_ok();
+            }
+        });
+
+        edited
+    }
+
+    /// Draw the margin, border, padding and content boxes of the selected node, as a browser does for the element
+    /// that is hovered in its devtools
+    ///
+    /// `origin` is the screen position at which the root of the tree is drawn.
+    pub fn highlight_selected<NodeContext>(&self, painter: &Painter, tree: &TaffyTree<NodeContext>, origin: Pos2) {
+        let Some(data) = self.selected.and_then(|node| tree.inspector_data(node).ok()) else { return };
+
+        let border_box = egui::Rect::from_min_size(
+            origin + Vec2::new(data.absolute_location.x, data.absolute_location.y),
+            Vec2::new(data.layout.size.width, data.layout.size.height),
+        );
+        let margin_box = expand(border_box, data.layout.margin);
+        let padding_box = shrink(border_box, data.layout.border);
+        let content_box = shrink(padding_box, data.layout.padding);
+
+        paint_band(painter, margin_box, border_box, BOX_MODEL_COLORS[0]);
+        paint_band(painter, border_box, padding_box, BOX_MODEL_COLORS[1]);
+        paint_band(painter, padding_box, content_box, BOX_MODEL_COLORS[2]);
+        painter.rect_filled(content_box, 0.0, BOX_MODEL_COLORS[3]);
+    }
+
+    /// Draw the node and its descendants as a collapsible list, in which clicking on a node selects it
+    fn show_node_tree<NodeContext>(
+        &mut self,
+        ui: &mut Ui,
+        tree: &TaffyTree<NodeContext>,
+        node: NodeId,
+        selection_found: &mut bool,
+    ) {
+        let is_selected = self.selected == Some(node);
+        *selection_found |= is_selected;
+
+        let label = format!("{} (#{})", tree.get_debug_label(node), u64::from(node));
+        let children = tree.children(node).unwrap_or_default();
+        if children.is_empty() {
+            if ui.selectable_label(is_selected, label).clicked() {
+                self.selected = Some(node);
+            }
+        } else {
+            let response = CollapsingHeader::new(label).id_salt(node).default_open(true).show(ui, |ui| {
+                for child in children {
+                    self.show_node_tree(ui, tree, child, selection_found);
+                }
+            });
+            if response.header_response.clicked() {
+                self.selected = Some(node);
+            }
+        }
+    }
+}
+
+/// Draw the nested margin, border, padding and content boxes of a node, labelled with the size of each edge
+fn show_box_model_diagram(ui: &mut Ui, data: &InspectorData) {
+    /// The width of each ring of the diagram
+    const BAND: f32 = 24.0;
+
+    let (outer, _) = ui.allocate_exact_size(Vec2::new(8.0 * BAND + 80.0, 6.0 * BAND + 40.0), egui::Sense::hover());
+    let painter = ui.painter_at(outer);
+    let text_color = ui.visuals().strong_text_color();
+    let font = egui::FontId::monospace(10.0);
+
+    let layout = &data.layout;
+    let edges = [layout.margin, layout.border, layout.padding];
+    let mut rect = outer;
+    for (index, (name, edge)) in ["margin", "border", "padding"].into_iter().zip(edges).enumerate() {
+        let inner = rect.shrink(BAND);
+        paint_band(&painter, rect, inner, BOX_MODEL_COLORS[index]);
+        painter.rect_stroke(rect, 0.0, Stroke::new(1.0, text_color), StrokeKind::Inside);
+        painter.text(rect.left_top() + Vec2::splat(3.0), egui::Align2::LEFT_TOP, name, font.clone(), text_color);
+        for (anchor, value) in [
+            (egui::pos2(rect.center().x, rect.top() + BAND / 2.0), edge.top),
+            (egui::pos2(rect.center().x, rect.bottom() - BAND / 2.0), edge.bottom),
+            (egui::pos2(rect.left() + BAND / 2.0, rect.center().y), edge.left),
+            (egui::pos2(rect.right() - BAND / 2.0, rect.center().y), edge.right),
+        ] {
+            painter.text(anchor, egui::Align2::CENTER_CENTER, format_number(value), font.clone(), text_color);
+        }
+        rect = inner;
+    }
+
+    painter.rect_filled(rect, 0.0, BOX_MODEL_COLORS[3]);
+    let content_size = data.content_box_size();
+    let content_label = format!("{} × {}", format_number(content_size.width), format_number(content_size.height));
+    painter.text(rect.center(), egui::Align2::CENTER_CENTER, content_label, font, text_color);
+}
+
+/// Draw the position and size of the node, and the tracks or lines of the node if it is a grid or flex container
+fn show_layout<NodeContext>(ui: &mut Ui, tree: &TaffyTree<NodeContext>, node: NodeId, data: &InspectorData) {
+    let format_size = |size: Size<f32>| format!("{} × {}", format_number(size.width), format_number(size.height));
+
+    Grid::new("taffy_inspector_layout_grid").num_columns(2).striped(true).show(ui, |ui| {
+        ui.label("kind");
+        ui.label(data.label);
+        ui.end_row();
+        ui.label("location");
+        ui.label(format!("{}, {}", format_number(data.layout.location.x), format_number(data.layout.location.y)));
+        ui.end_row();
+        ui.label("absolute location");
+        ui.label(format!("{}, {}", format_number(data.absolute_location.x), format_number(data.absolute_location.y)));
+        ui.end_row();
+        ui.label("size");
+        ui.label(format_size(data.layout.size));
+        ui.end_row();
+        #[cfg(feature = "content_size")]
+        {
+            ui.label("content size");
+            ui.label(format_size(data.layout.content_size));
+            ui.end_row();
+        }
+        ui.label("paint order");
+        ui.label(tree.paint_order(node).map_or_else(|_| String::new(), |order| order.to_string()));
+        ui.end_row();
+
+        #[cfg(feature = "grid")]
+        if let Some(info) = tree.grid_info(node) {
+            ui.label("columns");
+            ui.label(format_tracks(&info.columns.offsets, &info.columns.sizes));
+            ui.end_row();
+            ui.label("rows");
+            ui.label(format_tracks(&info.rows.offsets, &info.rows.sizes));
+            ui.end_row();
+        }
+
+        #[cfg(feature = "flexbox")]
+        if let Some(info) = tree.flexbox_info(node) {
+            for (index, line) in info.lines.iter().enumerate() {
+                ui.label(format!("line {}", index + 1));
+                ui.label(format!(
+                    "{} items at {} ({})",
+                    line.items.len(),
+                    format_number(line.cross_offset),
+                    format_number(line.cross_size)
+                ));
+                ui.end_row();
+            }
+        }
+    });
+}
+
+/// Show an editor for the value of a style property, returning `true` if the value was changed
+///
+/// Properties with complex values (such as grid templates) are shown but can't be edited.
+fn edit_field(ui: &mut Ui, name: &'static str, value: &mut StyleFieldValue) -> bool {
+    match value {
+        StyleFieldValue::Display(display) => edit_choice(ui, name, display, DISPLAY_CHOICES),
+        StyleFieldValue::Bool(value) => ui.checkbox(value, "").changed(),
+        StyleFieldValue::BoxSizing(box_sizing) => {
+            edit_choice(ui, name, box_sizing, &[BoxSizing::BorderBox, BoxSizing::ContentBox])
+        }
+        StyleFieldValue::Float(value) => ui.add(DragValue::new(value).speed(0.1)).changed(),
+        StyleFieldValue::OptionalFloat(value) => {
+            let mut is_set = value.is_some();
+            let mut number = value.unwrap_or(1.0);
+            let changed = ui
+                .horizontal(|ui| {
+                    ui.checkbox(&mut is_set, "").changed()
+                        | ui.add_enabled(is_set, DragValue::new(&mut number).speed(0.1)).changed()
+                })
+                .inner;
+            *value = is_set.then_some(number);
+            changed
+        }
+        #[cfg(feature = "flexbox")]
+        StyleFieldValue::OptionalCount(value) => {
+            let mut is_set = value.is_some();
+            let mut count = value.unwrap_or(1);
+            let changed = ui
+                .horizontal(|ui| {
+                    ui.checkbox(&mut is_set, "").changed()
+                        | ui.add_enabled(is_set, DragValue::new(&mut count).range(1..=u16::MAX)).changed()
+                })
+                .inner;
+            *value = is_set.then_some(count);
+            changed
+        }
+        StyleFieldValue::Position(position) => {
+            edit_choice(ui, name, position, &[Position::Relative, Position::Absolute])
+        }
+        StyleFieldValue::RoundingOrigin(origin) => {
+            edit_choice(ui, name, origin, &[RoundingOrigin::Inherit, RoundingOrigin::Node])
+        }
+        StyleFieldValue::LengthPercentageAutoRect(rect) => edit_rect(ui, name, rect, edit_length_percentage_auto),
+        StyleFieldValue::LengthPercentageRect(rect) => edit_rect(ui, name, rect, edit_length_percentage),
+        StyleFieldValue::DimensionSize(size) => {
+            ui.horizontal(|ui| {
+                edit_dimension(ui, (name, "width"), &mut size.width)
+                    | edit_dimension(ui, (name, "height"), &mut size.height)
+            })
+            .inner
+        }
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        StyleFieldValue::AlignItems(align) => edit_choice(ui, name, align, ALIGN_ITEMS_CHOICES),
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        StyleFieldValue::AlignContent(align) => edit_choice(ui, name, align, ALIGN_CONTENT_CHOICES),
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        StyleFieldValue::LengthPercentageSize(size) => {
+            ui.horizontal(|ui| {
+                edit_length_percentage(ui, (name, "width"), &mut size.width)
+                    | edit_length_percentage(ui, (name, "height"), &mut size.height)
+            })
+            .inner
+        }
+        #[cfg(feature = "flexbox")]
+        StyleFieldValue::FlexDirection(direction) => edit_choice(
+            ui,
+            name,
+            direction,
+            &[FlexDirection::Row, FlexDirection::Column, FlexDirection::RowReverse, FlexDirection::ColumnReverse],
+        ),
+        #[cfg(feature = "flexbox")]
+        StyleFieldValue::FlexWrap(wrap) => {
+            edit_choice(ui, name, wrap, &[FlexWrap::NoWrap, FlexWrap::Wrap, FlexWrap::WrapReverse])
+        }
+        #[cfg(feature = "flexbox")]
+        StyleFieldValue::FlexGrowPrecision(precision) => {
+            edit_choice(ui, name, precision, &[FlexGrowPrecision::Standard, FlexGrowPrecision::Exact])
+        }
+        #[cfg(feature = "flexbox")]
+        StyleFieldValue::Dimension(dimension) => edit_dimension(ui, name, dimension),
+        other => {
+            ui.label(format!("{other:?}"));
+            false
+        }
+    }
+}
+
+/// The values of `display` which can be chosen in the editor
+const DISPLAY_CHOICES: &[Display] = &[
+    #[cfg(feature = "block_layout")]
+    Display::Block,
+    #[cfg(feature = "flexbox")]
+    Display::Flex,
+    #[cfg(feature = "grid")]
+    Display::Grid,
+    Display::Contents,
+    Display::None,
+];
+
+/// The values of `align-items` and similar properties which can be chosen in the editor
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+const ALIGN_ITEMS_CHOICES: &[Option<AlignItems>] = &[
+    None,
+    Some(AlignItems::Start),
+    Some(AlignItems::End),
+    Some(AlignItems::FlexStart),
+    Some(AlignItems::FlexEnd),
+    Some(AlignItems::Center),
+    Some(AlignItems::Baseline),
+    Some(AlignItems::Stretch),
+];
+
+/// The values of `align-content` and `justify-content` which can be chosen in the editor
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+const ALIGN_CONTENT_CHOICES: &[Option<AlignContent>] = &[
+    None,
+    Some(AlignContent::Start),
+    Some(AlignContent::End),
+    Some(AlignContent::FlexStart),
+    Some(AlignContent::FlexEnd),
+    Some(AlignContent::Center),
+    Some(AlignContent::Stretch),
+    Some(AlignContent::SpaceBetween),
+    Some(AlignContent::SpaceEvenly),
+    Some(AlignContent::SpaceAround),
+];
+
+/// Show a drop-down list of `choices`, returning `true` if a different value was chosen
+fn edit_choice<T: PartialEq + Copy + Debug>(ui: &mut Ui, id_salt: impl Hash, value: &mut T, choices: &[T]) -> bool {
+    let mut changed = false;
+    ComboBox::from_id_salt(id_salt).selected_text(format!("{value:?}")).show_ui(ui, |ui| {
+        for &choice in choices {
+            changed |= ui.selectable_value(value, choice, format!("{choice:?}")).changed();
+        }
+    });
+    changed
+}
+
+/// Show an editor for each edge of a rect, returning `true` if any of them was changed
+fn edit_rect<T>(
+    ui: &mut Ui,
+    name: &'static str,
+    rect: &mut Rect<T>,
+    mut edit: impl FnMut(&mut Ui, (&'static str, &'static str), &mut T) -> bool,
+) -> bool {
+    ui.vertical(|ui| {
+        let mut changed = false;
+        for (edge, value) in
+            [("left", &mut rect.left), ("right", &mut rect.right), ("top", &mut rect.top), ("bottom", &mut rect.bottom)]
+        {
+            changed |= ui.horizontal(|ui| edit(ui, (name, edge), value)).inner;
+        }
+        changed
+    })
+    .inner
+}
+
+/// The unit of a value in a length editor
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LengthUnit {
+    /// An absolute length in pixels
+    Px,
+    /// A percentage of the parent's size
+    Percent,
+    /// The `auto` keyword
+    Auto,
+}
+
+/// Show an editor for a length or percentage (or `auto` if `allow_auto` is set), returning the new unit and number
+/// if the value was changed
+///
+/// Percentages are shown and returned as numbers between 0 and 100. `calc()` values can't be edited.
+fn edit_length(ui: &mut Ui, id_salt: impl Hash, raw: CompactLength, allow_auto: bool) -> Option<(LengthUnit, f32)> {
+    let original = match raw.tag() {
+        CompactLength::LENGTH_TAG => (LengthUnit::Px, raw.value()),
+        CompactLength::PERCENT_TAG => (LengthUnit::Percent, raw.value() * 100.0),
+        CompactLength::AUTO_TAG => (LengthUnit::Auto, 0.0),
+        _ => {
+            ui.label("calc()");
+            return None;
+        }
+    };
+    let (mut unit, mut number) = original;
+    ui.push_id(id_salt, |ui| {
+        ui.add_enabled(unit != LengthUnit::Auto, DragValue::new(&mut number).speed(1.0));
+        ComboBox::from_id_salt("unit").width(48.0).selected_text(unit_label(unit)).show_ui(ui, |ui| {
+            let units: &[LengthUnit] = if allow_auto {
+                &[LengthUnit::Px, LengthUnit::Percent, LengthUnit::Auto]
+            } else {
+                &[LengthUnit::Px, LengthUnit::Percent]
+            };
+            for &choice in units {
+                ui.selectable_value(&mut unit, choice, unit_label(choice));
+            }
+        });
+    });
+
+    ((unit, number) != original).then_some((unit, number))
+}
+
+/// The label for a unit in a length editor
+fn unit_label(unit: LengthUnit) -> &'static str {
+    match unit {
+        LengthUnit::Px => "px",
+        LengthUnit::Percent => "%",
+        LengthUnit::Auto => "auto",
+    }
+}
+
+/// Show an editor for a [`Dimension`], returning `true` if it was changed
+fn edit_dimension(ui: &mut Ui, id_salt: impl Hash, value: &mut Dimension) -> bool {
+    let Some((unit, number)) = edit_length(ui, id_salt, value.into_raw(), true) else { return false };
+    *value = match unit {
+        LengthUnit::Px => Dimension::length(number),
+        LengthUnit::Percent => Dimension::percent(number / 100.0),
+        LengthUnit::Auto => Dimension::auto(),
+    };
+    true
+}
+
+/// Show an editor for a [`LengthPercentageAuto`], returning `true` if it was changed
+fn edit_length_percentage_auto(ui: &mut Ui, id_salt: impl Hash, value: &mut LengthPercentageAuto) -> bool {
+    let Some((unit, number)) = edit_length(ui, id_salt, value.into_raw(), true) else { return false };
+    *value = match unit {
+        LengthUnit::Px => LengthPercentageAuto::length(number),
+        LengthUnit::Percent => LengthPercentageAuto::percent(number / 100.0),
+        LengthUnit::Auto => LengthPercentageAuto::auto(),
+    };
+    true
+}
+
+/// Show an editor for a [`LengthPercentage`], returning `true` if it was changed
+fn edit_length_percentage(ui: &mut Ui, id_salt: impl Hash, value: &mut LengthPercentage) -> bool {
+    let Some((unit, number)) = edit_length(ui, id_salt, value.into_raw(), false) else { return false };
+    *value = match unit {
+        LengthUnit::Percent => LengthPercentage::percent(number / 100.0),
+        LengthUnit::Px | LengthUnit::Auto => LengthPercentage::length(number),
+    };
+    true
+}
+
+/// Format a number without trailing zeros
+fn format_number(value: f32) -> String {
+    let formatted = format!("{value:.2}");
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Format the offset and size of each track of a grid
+#[cfg(feature = "grid")]
+fn format_tracks(offsets: &[f32], sizes: &[f32]) -> String {
+    let tracks: Vec<String> = offsets
+        .iter()
+        .zip(sizes)
+        .map(|(offset, size)| format!("{} ({})", format_number(*offset), format_number(*size)))
+        .collect();
+    tracks.join(", ")
+}
+
+/// Grow `rect` by the size of each edge
+fn expand(rect: egui::Rect, edges: Rect<f32>) -> egui::Rect {
+    egui::Rect::from_min_max(
+        rect.min - Vec2::new(edges.left, edges.top),
+        rect.max + Vec2::new(edges.right, edges.bottom),
+    )
+}
+
+/// Shrink `rect` by the size of each edge
+fn shrink(rect: egui::Rect, edges: Rect<f32>) -> egui::Rect {
+    expand(rect, Rect { left: -edges.left, right: -edges.right, top: -edges.top, bottom: -edges.bottom })
+}
+
+/// Fill the area between `outer` and `inner`, which must lie within `outer`
+fn paint_band(painter: &Painter, outer: egui::Rect, inner: egui::Rect, color: Color32) {
+    let top = egui::Rect::from_min_max(outer.min, egui::pos2(outer.max.x, inner.min.y));
+    let bottom = egui::Rect::from_min_max(egui::pos2(outer.min.x, inner.max.y), outer.max);
+    let left = egui::Rect::from_min_max(egui::pos2(outer.min.x, inner.min.y), egui::pos2(inner.min.x, inner.max.y));
+    let right = egui::Rect::from_min_max(egui::pos2(inner.max.x, inner.min.y), egui::pos2(outer.max.x, inner.max.y));
+    for rect in [top, bottom, left, right] {
+        painter.rect_filled(rect, 0.0, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, format_number, shrink, Inspector};
+    use crate::prelude::*;
+
+    /// Run one frame which shows the inspector and highlights its selected node at `origin`, returning whether a
+    /// style was edited and the shapes which were painted
+    fn run_frame(
+        inspector: &mut Inspector,
+        taffy: &mut TaffyTree<()>,
+        root: NodeId,
+        origin: egui::Pos2,
+    ) -> (bool, Vec<egui::epaint::ClippedShape>) {
+        let mut edited = false;
+        let output = egui::Context::default().run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                edited = inspector.show(ui, taffy, root);
+                inspector.highlight_selected(ui.painter(), taffy, origin);
+            });
+        });
+        (edited, output.shapes)
+    }
+
+    #[test]
+    fn format_number_trims_trailing_zeros() {
+        assert_eq!(format_number(12.0), "12");
+        assert_eq!(format_number(0.5), "0.5");
+        assert_eq!(format_number(1.256), "1.26");
+    }
+
+    #[test]
+    fn expand_and_shrink_move_each_edge() {
+        let rect = egui::Rect::from_min_max(egui::pos2(10.0, 20.0), egui::pos2(110.0, 70.0));
+        let edges = Rect { left: 1.0, right: 2.0, top: 3.0, bottom: 4.0 };
+        assert_eq!(expand(rect, edges), egui::Rect::from_min_max(egui::pos2(9.0, 17.0), egui::pos2(112.0, 74.0)));
+        assert_eq!(shrink(rect, edges), egui::Rect::from_min_max(egui::pos2(11.0, 23.0), egui::pos2(108.0, 66.0)));
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn format_tracks_lists_the_offset_and_size_of_each_track() {
+        assert_eq!(super::format_tracks(&[0.0, 12.5], &[12.5, 30.0]), "0 (12.5), 12.5 (30)");
+        assert_eq!(super::format_tracks(&[], &[]), "");
+    }
+
+    #[test]
+    fn inspector_forgets_removed_nodes() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let mut inspector = Inspector::new();
+        inspector.select(Some(leaf));
+        assert!(!run_frame(&mut inspector, &mut taffy, root, egui::Pos2::ZERO).0);
+        assert_eq!(inspector.selected(), Some(leaf));
+
+        taffy.remove(leaf).unwrap();
+        run_frame(&mut inspector, &mut taffy, root, egui::Pos2::ZERO);
+        assert_eq!(inspector.selected(), None);
+    }
+
+    #[test]
+    fn highlight_selected_paints_the_content_box_of_the_node() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy
+            .new_leaf(Style {
+                size: Size::from_lengths(40.0, 30.0),
+                margin: Rect::length(5.0),
+                border: Rect::length(2.0),
+                padding: Rect::length(3.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let root =
+            taffy.new_with_children(Style { padding: Rect::length(10.0), ..Default::default() }, &[leaf]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let mut inspector = Inspector::new();
+        inspector.select(Some(leaf));
+        let (_, shapes) = run_frame(&mut inspector, &mut taffy, root, egui::pos2(100.0, 200.0));
+
+        // The border box starts at the root's padding plus the leaf's margin, and is inset by the border and padding
+        let content_box = egui::Rect::from_min_size(egui::pos2(120.0, 220.0), egui::vec2(30.0, 20.0));
+        assert!(shapes
+            .iter()
+            .any(|clipped| matches!(&clipped.shape, egui::Shape::Rect(rect) if rect.rect == content_box)));
+    }
+
+    #[test]
+    fn inspecting_containers_doesnt_edit_their_styles() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let mut leaf = || taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let leaves: Vec<[NodeId; 2]> = (0..3).map(|_| [leaf(), leaf()]).collect();
+        let containers: Vec<NodeId> = [
+            #[cfg(feature = "flexbox")]
+            Display::Flex,
+            #[cfg(feature = "grid")]
+            Display::Grid,
+            #[cfg(feature = "block_layout")]
+            Display::Block,
+        ]
+        .into_iter()
+        .zip(&leaves)
+        .map(|(display, children)| taffy.new_with_children(Style { display, ..Default::default() }, children).unwrap())
+        .collect();
+        let root = taffy.new_with_children(Style::default(), &containers).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let mut inspector = Inspector::new();
+        for node in containers.into_iter().chain([root]) {
+            let style = taffy.style(node).unwrap().clone();
+            inspector.select(Some(node));
+            assert!(!run_frame(&mut inspector, &mut taffy, root, egui::Pos2::ZERO).0);
+            assert_eq!(inspector.selected(), Some(node));
+            assert_eq!(taffy.style(node).unwrap(), &style);
+            assert!(!taffy.dirty(node).unwrap());
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Data for building layout inspectors, such as a devtools overlay which draws the box model of the selected node
+use crate::geometry::{Point, Size};
+#[cfg(feature = "detailed_layout_info")]
+use crate::tree::DetailedLayoutInfo;
+use crate::tree::{Layout, NodeId, PrintTree, TaffyResult, TaffyTree};
+
+#[cfg(feature = "inspector")]
+mod inspector;
+#[cfg(feature = "std")]
+pub use crate::util::print::render_ascii;
+#[cfg(feature = "inspector")]
+pub use inspector::Inspector;
+
+/// Everything an inspector needs to describe the final layout of a single node
+///
+/// Returned by [`TaffyTree::inspector_data`]. The margin, border and padding of the node are available from
+/// [`layout`](Self::layout), which together with [`absolute_location`](Self::absolute_location) is enough to draw the
+/// familiar browser-style box model diagram.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectorData {
+    /// A short label for the kind of node (typically the layout algorithm of a container: "FLEX ROW", "GRID", "LEAF")
+    pub label: &'static str,
+    /// The final layout of the node, relative to its parent
+    pub layout: Layout,
+    /// The position of the node's border box relative to the root of the tree that contains it
+    pub absolute_location: Point<f32>,
+    /// The flex lines or grid tracks of the node, if it is a flex or grid container
+    #[cfg(feature = "detailed_layout_info")]
+    pub detailed_layout_info: DetailedLayoutInfo,
+}
+
+impl InspectorData {
+    /// The size of the node's margin box
+    pub fn margin_box_size(&self) -> Size<f32> {
+        let Layout { size, margin, .. } = self.layout;
+        Size { width: size.width + margin.left + margin.right, height: size.height + margin.top + margin.bottom }
+    }
+
+    /// The size of the node's border box
+    pub fn border_box_size(&self) -> Size<f32> {
+        self.layout.size
+    }
+
+    /// The size of the node's padding box
+    pub fn padding_box_size(&self) -> Size<f32> {
+        let Layout { size, border, .. } = self.layout;
+        Size { width: size.width - border.left - border.right, height: size.height - border.top - border.bottom }
+    }
+
+    /// The size of the node's content box
+    pub fn content_box_size(&self) -> Size<f32> {
+        self.layout.content_box_size()
+    }
+}
+
+impl<NodeContext> TaffyTree<NodeContext> {
+    /// Collect the final layout of `node` together with its label and position for display in an inspector
+    ///
+    /// The result reflects the most recent call to [`compute_layout`](Self::compute_layout).
+    pub fn inspector_data(&self, node: NodeId) -> TaffyResult<InspectorData> {
+        let layout = *self.layout(node)?;
+
+        let mut absolute_location = layout.location;
+        let mut ancestor = self.parent(node);
+        while let Some(parent) = ancestor {
+            let location = self.layout(parent)?.location;
+            absolute_location = Point { x: absolute_location.x + location.x, y: absolute_location.y + location.y };
+            ancestor = self.parent(parent);
+        }
+
+        Ok(InspectorData {
+            label: self.get_debug_label(node),
+            layout,
+            absolute_location,
+            #[cfg(feature = "detailed_layout_info")]
+            detailed_layout_info: self.detailed_layout_info(node).clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Point;
+    use crate::prelude::*;
+
+    #[test]
+    fn inspector_data_describes_the_box_model() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy
+            .new_leaf(Style {
+                size: Size::from_lengths(50.0, 20.0),
+                margin: Rect { left: length(1.0), right: length(2.0), top: length(3.0), bottom: length(4.0) },
+                border: Rect { left: length(1.0), right: length(1.0), top: length(2.0), bottom: length(2.0) },
+                padding: Rect { left: length(4.0), right: length(4.0), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                Style {
+                    padding: Rect { left: length(10.0), right: zero(), top: zero(), bottom: zero() },
+                    ..Default::default()
+                },
+                &[leaf],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    padding: Rect { left: zero(), right: zero(), top: length(5.0), bottom: zero() },
+                    ..Default::default()
+                },
+                &[container],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let data = taffy.inspector_data(leaf).unwrap();
+        assert_eq!(data.label, "LEAF");
+        assert_eq!(data.absolute_location, Point { x: 11.0, y: 8.0 });
+        assert_eq!(data.margin_box_size(), Size { width: 53.0, height: 27.0 });
+        assert_eq!(data.border_box_size(), Size { width: 50.0, height: 20.0 });
+        assert_eq!(data.padding_box_size(), Size { width: 48.0, height: 16.0 });
+        assert_eq!(data.content_box_size(), Size { width: 40.0, height: 16.0 });
+        assert_eq!(taffy.inspector_data(root).unwrap().absolute_location, Point::ZERO);
+    }
+}
@@ -5,6 +5,7 @@ use crate::CompactLength;
 use crate::{style::Dimension, util::sys::f32_min};
 use core::ops::{Add, Sub};
 
+use crate::style::Direction;
 #[cfg(feature = "flexbox")]
 use crate::style::FlexDirection;
 
@@ -246,6 +247,26 @@ impl<T> Rect<T>
 where
     T: Copy + Clone,
 {
+    /// The `left` or `right` value of the [`Rect`], whichever is at the start of the inline axis for `direction`
+    #[inline]
+    pub const fn inline_start(&self, direction: Direction) -> T {
+        if direction.is_rtl() {
+            self.right
+        } else {
+            self.left
+        }
+    }
+
+    /// The `right` or `left` value of the [`Rect`], whichever is at the end of the inline axis for `direction`
+    #[inline]
+    pub const fn inline_end(&self, direction: Direction) -> T {
+        if direction.is_rtl() {
+            self.left
+        } else {
+            self.right
+        }
+    }
+
     /// The `start` or `top` value of the [`Rect`], from the perspective of the main layout axis
     #[cfg(feature = "flexbox")]
     pub(crate) const fn main_start(&self, direction: FlexDirection) -> T {
@@ -576,9 +597,9 @@ impl Size<Option<f32>> {
     ///   - If width is `Some` but height is `None`, then height is computed from width and aspect_ratio
     ///   - If height is `Some` but width is `None`, then width is computed from height and aspect_ratio
     ///
-    /// If aspect_ratio is `None` then this function simply returns self.
+    /// If aspect_ratio is `None` (or is NaN or infinite) then this function simply returns self.
     pub fn maybe_apply_aspect_ratio(self, aspect_ratio: Option<f32>) -> Size<Option<f32>> {
-        match aspect_ratio {
+        match aspect_ratio.filter(|ratio| ratio.is_finite()) {
             Some(ratio) => match (self.width, self.height) {
                 (Some(width), None) => Size { width: Some(width), height: Some(width / ratio) },
                 (None, Some(height)) => Size { width: Some(height * ratio), height: Some(height) },
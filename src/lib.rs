@@ -85,6 +85,8 @@ extern crate alloc;
 extern crate serde;
 
 pub mod compute;
+#[cfg(feature = "taffy_tree")]
+pub mod debug;
 pub mod geometry;
 pub mod prelude;
 pub mod style;
@@ -118,6 +120,10 @@ pub use crate::style::Style;
 pub use crate::tree::traits::*;
 #[cfg(feature = "taffy_tree")]
 #[doc(inline)]
+#[allow(deprecated)]
+pub use crate::tree::Taffy;
+#[cfg(feature = "taffy_tree")]
+#[doc(inline)]
 pub use crate::tree::TaffyTree;
 #[cfg(feature = "std")]
 #[doc(inline)]
@@ -3,18 +3,20 @@
 pub use crate::{
     geometry::{Line, Rect, Size},
     style::{
-        AlignContent, AlignItems, AlignSelf, AvailableSpace, BoxSizing, CompactLength, Dimension, Display,
-        JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style,
+        AlignContent, AlignItems, AlignSelf, AvailableSpace, BoxSizing, CompactLength, Dimension, Direction, Display,
+        JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Position, RoundingOrigin,
+        Style,
     },
     style_helpers::{
         auto, fit_content, length, max_content, min_content, percent, zero, FromFr, FromLength, FromPercent, TaffyAuto,
         TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
     },
     tree::{Layout, LayoutPartialTree, NodeId, PrintTree, RoundTree, TraversePartialTree, TraverseTree},
+    util::MaybeSet,
 };
 
 #[cfg(feature = "flexbox")]
-pub use crate::style::{FlexDirection, FlexWrap};
+pub use crate::style::{FlexDirection, FlexGrowPrecision, FlexWrap};
 
 #[cfg(feature = "grid")]
 pub use crate::style::{
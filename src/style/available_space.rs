@@ -2,13 +2,14 @@
 use crate::{
     prelude::{FromLength, TaffyMaxContent, TaffyMinContent, TaffyZero},
     sys::abs,
+    util::MaybeSet,
     Size,
 };
 
 /// The amount of space available to a node in a given axis
 /// <https://www.w3.org/TR/css-sizing-3/#available>
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AvailableSpace {
     /// The amount of space available is the specified number of pixels
     Definite(f32),
@@ -80,11 +81,10 @@ impl AvailableSpace {
     }
 
     /// If passed value is Some then return AvailableSpace::Definite containing that value, else return self
+    ///
+    /// This is the same as [`MaybeSet::maybe_set`], which also applies to other types.
     pub fn maybe_set(self, value: Option<f32>) -> AvailableSpace {
-        match value {
-            Some(value) => AvailableSpace::Definite(value),
-            None => self,
-        }
+        MaybeSet::maybe_set(self, value)
     }
 
     /// If passed value is Some then return AvailableSpace::Definite containing that value, else return self
@@ -139,7 +139,9 @@ impl Size<AvailableSpace> {
     }
 
     /// If passed value is Some then return AvailableSpace::Definite containing that value, else return self
+    ///
+    /// This is the same as [`MaybeSet::maybe_set`], which also applies to other types.
     pub fn maybe_set(self, value: Size<Option<f32>>) -> Size<AvailableSpace> {
-        Size { width: self.width.maybe_set(value.width), height: self.height.maybe_set(value.height) }
+        MaybeSet::maybe_set(self, value)
     }
 }
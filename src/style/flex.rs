@@ -14,6 +14,21 @@ pub trait FlexboxContainerStyle: CoreStyle {
     fn flex_wrap(&self) -> FlexWrap {
         Style::<Self::CustomIdent>::DEFAULT.flex_wrap
     }
+    /// How precisely free space is distributed between growing items
+    #[inline(always)]
+    fn flex_grow_precision(&self) -> FlexGrowPrecision {
+        Style::<Self::CustomIdent>::DEFAULT.flex_grow_precision
+    }
+    /// The relative rate at which each gap between items grows when the line is expanding to fill space
+    #[inline(always)]
+    fn gap_grow(&self) -> f32 {
+        Style::<Self::CustomIdent>::DEFAULT.gap_grow
+    }
+    /// The most items that a line of a wrapping container can hold, if limited
+    #[inline(always)]
+    fn max_items_per_line(&self) -> Option<u16> {
+        Style::<Self::CustomIdent>::DEFAULT.max_items_per_line
+    }
 
     /// How large should the gaps between items in a grid or flex container be?
     #[inline(always)]
@@ -57,6 +72,31 @@ pub trait FlexboxItemStyle: CoreStyle {
     fn flex_shrink(&self) -> f32 {
         Style::<Self::CustomIdent>::DEFAULT.flex_shrink
     }
+    /// The most that this item can grow beyond its flex basis, if limited
+    #[inline(always)]
+    fn flex_grow_max(&self) -> Option<f32> {
+        Style::<Self::CustomIdent>::DEFAULT.flex_grow_max
+    }
+    /// The most that this item can shrink below its flex basis, if limited
+    #[inline(always)]
+    fn flex_shrink_min(&self) -> Option<f32> {
+        Style::<Self::CustomIdent>::DEFAULT.flex_shrink_min
+    }
+    /// Whether an `auto` minimum size in the main axis resolves to a content-based minimum (true) or to zero (false)
+    #[inline(always)]
+    fn flex_auto_min_size(&self) -> bool {
+        Style::<Self::CustomIdent>::DEFAULT.flex_auto_min_size
+    }
+    /// Whether this item always starts a new line in a wrapping container
+    #[inline(always)]
+    fn break_before(&self) -> bool {
+        Style::<Self::CustomIdent>::DEFAULT.break_before
+    }
+    /// The alignment to use instead of baseline alignment when this item has no baseline
+    #[inline(always)]
+    fn align_self_fallback(&self) -> Option<AlignSelf> {
+        Style::<Self::CustomIdent>::DEFAULT.align_self_fallback
+    }
 
     /// How this node should be aligned in the cross/block axis
     /// Falls back to the parents [`AlignItems`] if not set
@@ -85,6 +125,26 @@ pub enum FlexWrap {
     WrapReverse,
 }
 
+/// How precisely the free space of a flex line is distributed between its growing items
+///
+/// Each item normally gets its share of the free space independently, in `f32`, and the items are positioned by
+/// summing their sizes in `f32`, so the items of a long line may not add up to exactly the free space. Together with
+/// the rounding of the final layout, the last items of a long line can end a pixel short of (or past) the end of the
+/// line.
+///
+/// Defaults to [`FlexGrowPrecision::Standard`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FlexGrowPrecision {
+    /// Each item's share is computed independently, as in browsers
+    #[default]
+    Standard,
+    /// The free space is accumulated item by item in `f64`, and each item's share is the difference between the
+    /// accumulated totals before and after it, so the shares always add up to the whole free space. The positions of
+    /// the items are also accumulated in `f64`.
+    Exact,
+}
+
 /// The direction of the flexbox layout main axis.
 ///
 /// There are always two perpendicular layout axes: main (or primary) and cross (or secondary).
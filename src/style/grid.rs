@@ -27,6 +27,183 @@ pub struct GridTemplateArea<CustomIdent: CheapCloneStr> {
     pub column_end: u16,
 }
 
+/// Returns the byte range of the `column`th whitespace-separated cell name in `row`, or `None` if there are fewer
+/// cells than that
+const fn grid_area_cell(row: &str, column: usize) -> Option<(usize, usize)> {
+    let bytes = row.as_bytes();
+    let mut index = 0;
+    let mut cell = 0;
+    loop {
+        while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+            index += 1;
+        }
+        if index == bytes.len() {
+            return None;
+        }
+        let start = index;
+        while index < bytes.len() && !bytes[index].is_ascii_whitespace() {
+            index += 1;
+        }
+        if cell == column {
+            return Some((start, index));
+        }
+        cell += 1;
+    }
+}
+
+/// The name of a cell in a row of a `grid-template-areas` string, as the bytes of the row and the range of the name
+#[derive(Clone, Copy)]
+struct GridAreaCellName<'a> {
+    /// The bytes of the row
+    row: &'a [u8],
+    /// The index of the first byte of the name
+    start: usize,
+    /// The index after the last byte of the name
+    end: usize,
+}
+
+impl<'a> GridAreaCellName<'a> {
+    /// Returns the name of the cell at `row` and `column`, which must exist
+    const fn at(rows: &[&'a str], row: usize, column: usize) -> Self {
+        let Some((start, end)) = grid_area_cell(rows[row], column) else { panic!("grid area cell out of bounds") };
+        Self { row: rows[row].as_bytes(), start, end }
+    }
+
+    /// Returns whether two cell names are equal, in a form usable in const functions
+    const fn eq(self, other: Self) -> bool {
+        if self.end - self.start != other.end - other.start {
+            return false;
+        }
+        let mut index = 0;
+        while index < self.end - self.start {
+            if self.row[self.start + index] != other.row[other.start + index] {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+
+    /// Returns whether the name is a null cell token (a sequence of `.` characters) which doesn't belong to an area
+    const fn is_null(self) -> bool {
+        let mut index = self.start;
+        while index < self.end {
+            if self.row[index] != b'.' {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+}
+
+/// Returns the number of cells in each row of a `grid-template-areas` string, panicking if the rows don't all have
+/// the same number of cells
+const fn grid_area_column_count(rows: &[&str]) -> usize {
+    let mut column_count = 0;
+    while grid_area_cell(rows[0], column_count).is_some() {
+        column_count += 1;
+    }
+    let mut row = 1;
+    while row < rows.len() {
+        if grid_area_cell(rows[row], column_count).is_some()
+            || (column_count > 0 && grid_area_cell(rows[row], column_count - 1).is_none())
+        {
+            panic!("every row of grid template areas must have the same number of cells");
+        }
+        row += 1;
+    }
+    column_count
+}
+
+/// Returns the area whose top-left cell is at `row` and `column`, as `(row_end, column_end)` exclusive cell indices,
+/// or `None` if the cell is a null cell or belongs to an area which starts at an earlier cell. Panics if the cells
+/// with the same name don't form a single filled-in rectangle.
+const fn grid_area_starting_at(
+    rows: &[&str],
+    column_count: usize,
+    row: usize,
+    column: usize,
+) -> Option<(usize, usize)> {
+    let name = GridAreaCellName::at(rows, row, column);
+    if name.is_null()
+        || (row > 0 && GridAreaCellName::at(rows, row - 1, column).eq(name))
+        || (column > 0 && GridAreaCellName::at(rows, row, column - 1).eq(name))
+    {
+        return None;
+    }
+
+    let mut column_end = column + 1;
+    while column_end < column_count && GridAreaCellName::at(rows, row, column_end).eq(name) {
+        column_end += 1;
+    }
+    let mut row_end = row + 1;
+    while row_end < rows.len() && GridAreaCellName::at(rows, row_end, column).eq(name) {
+        row_end += 1;
+    }
+
+    // Every cell with the name must be inside the rectangle, and every cell inside the rectangle must have the name
+    let mut cell_row = 0;
+    while cell_row < rows.len() {
+        let mut cell_column = 0;
+        while cell_column < column_count {
+            let inside = cell_row >= row && cell_row < row_end && cell_column >= column && cell_column < column_end;
+            if inside != GridAreaCellName::at(rows, cell_row, cell_column).eq(name) {
+                panic!("every named area in grid template areas must be a single rectangle");
+            }
+            cell_column += 1;
+        }
+        cell_row += 1;
+    }
+
+    Some((row_end, column_end))
+}
+
+/// Checks that each row of a `grid-template-areas` string has the same number of cells and that each named area is
+/// a single rectangle, panicking otherwise. Used by [`grid_areas!`](crate::grid_areas!) to check areas at compile time.
+#[doc(hidden)]
+pub const fn validate_grid_template_areas(rows: &[&str]) {
+    if rows.is_empty() {
+        return;
+    }
+    let column_count = grid_area_column_count(rows);
+    let mut row = 0;
+    while row < rows.len() {
+        let mut column = 0;
+        while column < column_count {
+            grid_area_starting_at(rows, column_count, row, column);
+            column += 1;
+        }
+        row += 1;
+    }
+}
+
+/// Converts the rows of a `grid-template-areas` string into the areas they define, in the order their top-left
+/// cells appear. Panics if the areas are invalid, which [`grid_areas!`](crate::grid_areas!) checks at compile time.
+#[doc(hidden)]
+pub fn grid_template_areas_from_rows<S: CheapCloneStr>(rows: &[&str]) -> Vec<GridTemplateArea<S>> {
+    let mut areas = Vec::new();
+    if rows.is_empty() {
+        return areas;
+    }
+    let column_count = grid_area_column_count(rows);
+    for row in 0..rows.len() {
+        for column in 0..column_count {
+            if let Some((row_end, column_end)) = grid_area_starting_at(rows, column_count, row, column) {
+                let name = GridAreaCellName::at(rows, row, column);
+                areas.push(GridTemplateArea {
+                    name: S::from(&rows[row][name.start..name.end]),
+                    row_start: row as u16 + 1,
+                    row_end: row_end as u16 + 1,
+                    column_start: column as u16 + 1,
+                    column_end: column_end as u16 + 1,
+                });
+            }
+        }
+    }
+    areas
+}
+
 /// Defines a named grid line
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -333,7 +510,7 @@ pub(crate) type OriginZeroGridPlacement = GenericGridPlacement<OriginZeroLine>;
 /// been resolved by the time values of this type are constructed).
 pub(crate) type NonNamedGridPlacement = GenericGridPlacement<GridLine>;
 
-/// A grid line placement specification. Used for grid-[row/column]-[start/end]. Named tracks are not implemented.
+/// A grid line placement specification. Used for grid-[row/column]-[start/end].
 ///
 /// Defaults to `GridPlacement::Auto`
 ///
@@ -0,0 +1,456 @@
+//! The [`style!`](crate::style!) and [`grid_areas!`](crate::grid_areas!) macros, for writing [`Style`]s with
+//! CSS-like values
+use super::*;
+
+/// Creates a [`Style`] from CSS-like `property: value` pairs, with every other property left at its default
+///
+/// Properties are the names of the fields of [`Style`], plus the longhands `width`, `height`, `min_width`,
+/// `min_height`, `max_width`, `max_height`, `row_gap` and `column_gap`. Each value is one of:
+///
+/// - a length such as `10 px`, or a unitless number such as `0` or `1.5`, which is also used for plain `f32`
+///   properties like `flex_grow`
+/// - a percentage such as `50 %`
+/// - a flex fraction such as `1 fr`
+/// - a keyword such as `auto`, `column`, `space_between` or `border_box`, which is the CSS keyword with `-`
+///   replaced by `_`
+/// - any other Rust expression of the property's type, such as `true`, `vec![fr(1.0), length(100.0)]` or the name
+///   of a local variable. A variable which has the same name as a keyword must be wrapped in parentheses.
+///
+/// A length or percentage given for a property with several sides or axes (such as `padding` or `gap`) applies
+/// to all of them. Unknown properties, unknown keywords and keywords which don't apply to a property are
+/// compile errors, and the resulting expression doesn't parse anything at runtime.
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// let style = taffy::style! {
+///     width: 100 %,
+///     padding: 4 px,
+///     flex_direction: column,
+///     justify_content: space_between,
+///     flex_grow: 1,
+/// };
+/// assert_eq!(style.size.width, Dimension::percent(1.0));
+/// assert_eq!(style.padding, Rect::length(4.0));
+/// assert_eq!(style.flex_direction, FlexDirection::Column);
+/// assert_eq!(style.justify_content, Some(JustifyContent::SpaceBetween));
+/// assert_eq!(style.flex_grow, 1.0);
+///
+/// let width = Dimension::length(20.0);
+/// let auto = Dimension::length(30.0);
+/// let style = taffy::style! { width: width, height: (auto), max_width: auto };
+/// assert_eq!(style.size, Size { width: Dimension::length(20.0), height: Dimension::length(30.0) });
+/// assert_eq!(style.max_size.width, Dimension::auto());
+/// ```
+#[macro_export]
+macro_rules! style {
+    ($($fields:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut style: $crate::Style = $crate::Style::DEFAULT;
+        $crate::__style_fields!(style; $($fields)* ,);
+        style
+    }};
+}
+
+/// Converts each value passed to [`style!`] and assigns it to its property
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __style_fields {
+    ($style:ident; $(,)?) => {};
+    ($style:ident; $property:ident : $value:literal px , $($rest:tt)*) => {
+        $crate::__style_field!($style, $property, $crate::style_helpers::length($value as f32));
+        $crate::__style_fields!($style; $($rest)*);
+    };
+    ($style:ident; $property:ident : $value:literal % , $($rest:tt)*) => {
+        $crate::__style_field!($style, $property, $crate::style_helpers::percent($value as f32 / 100.0));
+        $crate::__style_fields!($style; $($rest)*);
+    };
+    ($style:ident; $property:ident : $value:literal fr , $($rest:tt)*) => {
+        $crate::__style_field!($style, $property, $crate::style_helpers::fr($value as f32));
+        $crate::__style_fields!($style; $($rest)*);
+    };
+    ($style:ident; $property:ident : true , $($rest:tt)*) => {
+        $crate::__style_field!($style, $property, true);
+        $crate::__style_fields!($style; $($rest)*);
+    };
+    ($style:ident; $property:ident : false , $($rest:tt)*) => {
+        $crate::__style_field!($style, $property, false);
+        $crate::__style_fields!($style; $($rest)*);
+    };
+    ($style:ident; $property:ident : $value:literal , $($rest:tt)*) => {
+        $crate::__style_field!($style, $property, $crate::style_helpers::length($value as f32));
+        $crate::__style_fields!($style; $($rest)*);
+    };
+    ($style:ident; $property:ident : $keyword:ident , $($rest:tt)*) => {
+        $crate::__style_field!(
+            $style,
+            $property,
+            // A local variable or constant with the name of a keyword is shadowed by the keyword, while any other
+            // identifier resolves to the caller's variable or constant
+            ::core::convert::From::from({
+                #[allow(unused_imports)]
+                use $crate::style::style_macro_keywords::*;
+                $keyword
+            })
+        );
+        $crate::__style_fields!($style; $($rest)*);
+    };
+    ($style:ident; $property:ident : $value:expr , $($rest:tt)*) => {
+        $crate::__style_field!($style, $property, $value);
+        $crate::__style_fields!($style; $($rest)*);
+    };
+}
+
+/// Assigns a value passed to [`style!`] to its property, expanding the longhand properties which aren't fields
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __style_field {
+    ($style:ident, width, $value:expr) => {
+        $style.size.width = $value
+    };
+    ($style:ident, height, $value:expr) => {
+        $style.size.height = $value
+    };
+    ($style:ident, min_width, $value:expr) => {
+        $style.min_size.width = $value
+    };
+    ($style:ident, min_height, $value:expr) => {
+        $style.min_size.height = $value
+    };
+    ($style:ident, max_width, $value:expr) => {
+        $style.max_size.width = $value
+    };
+    ($style:ident, max_height, $value:expr) => {
+        $style.max_size.height = $value
+    };
+    ($style:ident, row_gap, $value:expr) => {
+        $style.gap.height = $value
+    };
+    ($style:ident, column_gap, $value:expr) => {
+        $style.gap.width = $value
+    };
+    ($style:ident, $property:ident, $value:expr) => {
+        $style.$property = $value
+    };
+}
+
+/// Creates the named grid areas of a `grid-template-areas` declaration from one string per row
+///
+/// Each row is a whitespace-separated list of cell names, where a sequence of `.` characters is a cell which
+/// doesn't belong to any area. The cells with the same name form an area, which must be a single rectangle. The
+/// rows are checked at compile time, so rows with different numbers of cells or areas which aren't rectangular fail
+/// to compile.
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// # use taffy::GridTemplateArea;
+/// let style: Style = Style {
+///     display: Display::Grid,
+///     grid_template_areas: taffy::grid_areas![
+///         "header header",
+///         "nav    main",
+///         ".      main",
+///     ],
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     style.grid_template_areas[2],
+///     GridTemplateArea { name: "main".into(), row_start: 2, row_end: 4, column_start: 2, column_end: 3 }
+/// );
+/// ```
+///
+/// ```compile_fail
+/// // The `main` area isn't a rectangle
+/// let areas: Vec<taffy::GridTemplateArea<String>> = taffy::grid_areas!["main nav", "main main"];
+/// ```
+#[cfg(feature = "grid")]
+#[macro_export]
+macro_rules! grid_areas {
+    ($($row:literal),* $(,)?) => {{
+        const _: () = $crate::style::validate_grid_template_areas(&[$($row),*]);
+        $crate::style::grid_template_areas_from_rows(&[$($row),*])
+    }};
+}
+
+/// Defines a unit struct for each keyword accepted by [`style!`], converting into each type it is a value of
+macro_rules! keywords {
+    ($($keyword:ident { $($(#[cfg($cfg:meta)])? $ty:ty => $value:expr,)* })*) => {
+        $(
+            #[doc = concat!("The `", stringify!($keyword), "` keyword")]
+            #[allow(non_camel_case_types)]
+            #[derive(Copy, Clone, Debug)]
+            pub struct $keyword;
+
+            $(
+                $(#[cfg($cfg)])?
+                impl From<$keyword> for $ty {
+                    fn from(_: $keyword) -> Self {
+                        $value
+                    }
+                }
+            )*
+        )*
+    };
+}
+
+/// The keywords accepted by [`style!`], each of which converts into the values it names
+#[doc(hidden)]
+pub mod keywords {
+    use super::*;
+
+    keywords! {
+        auto {
+            Dimension => Dimension::auto(),
+            LengthPercentageAuto => LengthPercentageAuto::auto(),
+            Size<Dimension> => Size::auto(),
+            Rect<LengthPercentageAuto> => Rect::auto(),
+            Option<f32> => None,
+            Option<AlignItems> => None,
+            Option<AlignContent> => None,
+            #[cfg(feature = "block_layout")]
+            TextAlign => TextAlign::Auto,
+        }
+        block {
+            #[cfg(feature = "block_layout")]
+            Display => Display::Block,
+        }
+        flex {
+            #[cfg(feature = "flexbox")]
+            Display => Display::Flex,
+        }
+        grid {
+            #[cfg(feature = "grid")]
+            Display => Display::Grid,
+        }
+        contents {
+            Display => Display::Contents,
+        }
+        none {
+            Display => Display::None,
+        }
+        relative {
+            Position => Position::Relative,
+        }
+        absolute {
+            Position => Position::Absolute,
+        }
+        border_box {
+            BoxSizing => BoxSizing::BorderBox,
+        }
+        content_box {
+            BoxSizing => BoxSizing::ContentBox,
+        }
+        visible {
+            Overflow => Overflow::Visible,
+            Point<Overflow> => Point { x: Overflow::Visible, y: Overflow::Visible },
+        }
+        clip {
+            Overflow => Overflow::Clip,
+            Point<Overflow> => Point { x: Overflow::Clip, y: Overflow::Clip },
+        }
+        hidden {
+            Overflow => Overflow::Hidden,
+            Point<Overflow> => Point { x: Overflow::Hidden, y: Overflow::Hidden },
+        }
+        scroll {
+            Overflow => Overflow::Scroll,
+            Point<Overflow> => Point { x: Overflow::Scroll, y: Overflow::Scroll },
+        }
+        row {
+            #[cfg(feature = "flexbox")]
+            FlexDirection => FlexDirection::Row,
+            #[cfg(feature = "grid")]
+            GridAutoFlow => GridAutoFlow::Row,
+        }
+        column {
+            #[cfg(feature = "flexbox")]
+            FlexDirection => FlexDirection::Column,
+            #[cfg(feature = "grid")]
+            GridAutoFlow => GridAutoFlow::Column,
+        }
+        row_reverse {
+            #[cfg(feature = "flexbox")]
+            FlexDirection => FlexDirection::RowReverse,
+        }
+        column_reverse {
+            #[cfg(feature = "flexbox")]
+            FlexDirection => FlexDirection::ColumnReverse,
+        }
+        row_dense {
+            #[cfg(feature = "grid")]
+            GridAutoFlow => GridAutoFlow::RowDense,
+        }
+        column_dense {
+            #[cfg(feature = "grid")]
+            GridAutoFlow => GridAutoFlow::ColumnDense,
+        }
+        nowrap {
+            #[cfg(feature = "flexbox")]
+            FlexWrap => FlexWrap::NoWrap,
+        }
+        wrap {
+            #[cfg(feature = "flexbox")]
+            FlexWrap => FlexWrap::Wrap,
+        }
+        wrap_reverse {
+            #[cfg(feature = "flexbox")]
+            FlexWrap => FlexWrap::WrapReverse,
+        }
+        standard {
+            #[cfg(feature = "flexbox")]
+            FlexGrowPrecision => FlexGrowPrecision::Standard,
+        }
+        exact {
+            #[cfg(feature = "flexbox")]
+            FlexGrowPrecision => FlexGrowPrecision::Exact,
+        }
+        start {
+            Option<AlignItems> => Some(AlignItems::Start),
+            Option<AlignContent> => Some(AlignContent::Start),
+        }
+        end {
+            Option<AlignItems> => Some(AlignItems::End),
+            Option<AlignContent> => Some(AlignContent::End),
+        }
+        flex_start {
+            Option<AlignItems> => Some(AlignItems::FlexStart),
+            Option<AlignContent> => Some(AlignContent::FlexStart),
+        }
+        flex_end {
+            Option<AlignItems> => Some(AlignItems::FlexEnd),
+            Option<AlignContent> => Some(AlignContent::FlexEnd),
+        }
+        center {
+            Option<AlignItems> => Some(AlignItems::Center),
+            Option<AlignContent> => Some(AlignContent::Center),
+        }
+        stretch {
+            Option<AlignItems> => Some(AlignItems::Stretch),
+            Option<AlignContent> => Some(AlignContent::Stretch),
+        }
+        baseline {
+            Option<AlignItems> => Some(AlignItems::Baseline),
+        }
+        space_between {
+            Option<AlignContent> => Some(AlignContent::SpaceBetween),
+        }
+        space_evenly {
+            Option<AlignContent> => Some(AlignContent::SpaceEvenly),
+        }
+        space_around {
+            Option<AlignContent> => Some(AlignContent::SpaceAround),
+        }
+        legacy_left {
+            #[cfg(feature = "block_layout")]
+            TextAlign => TextAlign::LegacyLeft,
+        }
+        legacy_right {
+            #[cfg(feature = "block_layout")]
+            TextAlign => TextAlign::LegacyRight,
+        }
+        legacy_center {
+            #[cfg(feature = "block_layout")]
+            TextAlign => TextAlign::LegacyCenter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    #[cfg(feature = "grid")]
+    use crate::GridTemplateArea;
+    use crate::{Overflow, Point};
+
+    #[test]
+    fn style_macro_sets_only_the_given_properties() {
+        let style = crate::style! {};
+        assert_eq!(style, Style::DEFAULT);
+
+        let style = crate::style! {
+            min_width: 10 px,
+            max_height: 50 %,
+            margin: auto,
+            border: 0,
+            position: absolute,
+            overflow: hidden,
+            scrollbar_width: 8,
+            aspect_ratio: 1.5,
+        };
+        let expected = Style {
+            min_size: Size { width: length(10.0), height: auto() },
+            max_size: Size { width: auto(), height: percent(0.5) },
+            margin: Rect::auto(),
+            position: Position::Absolute,
+            overflow: Point { x: Overflow::Hidden, y: Overflow::Hidden },
+            scrollbar_width: 8.0,
+            aspect_ratio: Some(1.5),
+            ..Default::default()
+        };
+        assert_eq!(style, expected);
+    }
+
+    #[test]
+    #[cfg(all(feature = "flexbox", feature = "grid"))]
+    fn style_macro_keywords_convert_to_the_type_of_the_property() {
+        let style = crate::style! {
+            display: grid,
+            flex_direction: row_reverse,
+            grid_auto_flow: column,
+            align_items: center,
+            align_content: center,
+            column_gap: 2 px,
+            grid_template_columns: vec![fr(1.0), length(20.0)],
+            flex_auto_min_size: false,
+        };
+        assert_eq!(style.display, Display::Grid);
+        assert_eq!(style.flex_direction, FlexDirection::RowReverse);
+        assert_eq!(style.grid_auto_flow, GridAutoFlow::Column);
+        assert_eq!(style.align_items, Some(AlignItems::Center));
+        assert_eq!(style.align_content, Some(AlignContent::Center));
+        assert_eq!(style.gap, Size { width: length(2.0), height: zero() });
+        assert_eq!(style.grid_template_columns.len(), 2);
+        assert!(!style.flex_auto_min_size);
+    }
+
+    #[test]
+    fn style_macro_accepts_local_variables() {
+        let w = Dimension::length(10.0);
+        let grow = 2.0_f32;
+        let position = Position::Absolute;
+        let style = crate::style! { width: w, flex_grow: grow, position: position, aspect_ratio: grow };
+        assert_eq!(style.size.width, length(10.0));
+        assert_eq!(style.flex_grow, 2.0);
+        assert_eq!(style.position, Position::Absolute);
+        assert_eq!(style.aspect_ratio, Some(2.0));
+    }
+    #[test]
+    #[cfg(feature = "grid")]
+    fn grid_areas_macro_creates_an_area_for_each_name() {
+        let areas: Vec<GridTemplateArea<String>> = crate::grid_areas!["a a b", ". . b", "c c c"];
+        let area = |name: &str, row_start, row_end, column_start, column_end| GridTemplateArea {
+            name: name.into(),
+            row_start,
+            row_end,
+            column_start,
+            column_end,
+        };
+        assert_eq!(areas, vec![area("a", 1, 2, 1, 3), area("b", 1, 3, 3, 4), area("c", 3, 4, 1, 4)]);
+
+        let areas: Vec<GridTemplateArea<String>> = crate::grid_areas![];
+        assert!(areas.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    #[should_panic(expected = "must be a single rectangle")]
+    fn grid_template_areas_must_be_rectangular() {
+        crate::style::grid_template_areas_from_rows::<String>(&["a b", "b b"]);
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    #[should_panic(expected = "the same number of cells")]
+    fn grid_template_area_rows_must_have_the_same_length() {
+        crate::style::grid_template_areas_from_rows::<String>(&["a b", "a b c"]);
+    }
+}
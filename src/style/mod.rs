@@ -3,6 +3,11 @@ mod alignment;
 mod available_space;
 mod compact_length;
 mod dimension;
+mod macros;
+mod partial;
+mod reflect;
+mod responsive;
+mod value;
 
 #[cfg(feature = "block_layout")]
 mod block;
@@ -15,12 +20,21 @@ pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, J
 pub use self::available_space::AvailableSpace;
 pub use self::compact_length::CompactLength;
 pub use self::dimension::{Dimension, LengthPercentage, LengthPercentageAuto};
+#[doc(hidden)]
+pub use self::macros::keywords as style_macro_keywords;
+pub use self::partial::PartialStyle;
+pub use self::reflect::{StyleFieldError, StyleFieldValue};
+pub use self::responsive::{Breakpoint, ResponsiveStyle};
+pub use self::value::StyleValue;
 use crate::sys::DefaultCheapStr;
 
 #[cfg(feature = "block_layout")]
 pub use self::block::{BlockContainerStyle, BlockItemStyle, TextAlign};
 #[cfg(feature = "flexbox")]
-pub use self::flex::{FlexDirection, FlexWrap, FlexboxContainerStyle, FlexboxItemStyle};
+pub use self::flex::{FlexDirection, FlexGrowPrecision, FlexWrap, FlexboxContainerStyle, FlexboxItemStyle};
+#[cfg(feature = "grid")]
+#[doc(hidden)]
+pub use self::grid::{grid_template_areas_from_rows, validate_grid_template_areas};
 #[cfg(feature = "grid")]
 pub use self::grid::{
     GenericGridPlacement, GenericGridTemplateComponent, GenericRepetition, GridAutoFlow, GridContainerStyle,
@@ -166,8 +180,11 @@ pub trait CoreStyle {
 /// Sets the layout used for the children of this node
 ///
 /// The default values depends on on which feature flags are enabled. The order of precedence is: Flex, Grid, Block, None.
+///
+/// New variants may be added in minor releases, so matches on this enum must include a wildcard arm.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum Display {
     /// The children will follow the block layout algorithm
     #[cfg(feature = "block_layout")]
@@ -178,6 +195,12 @@ pub enum Display {
     /// The children will follow the CSS Grid layout algorithm
     #[cfg(feature = "grid")]
     Grid,
+    /// The node does not generate a box of its own, and its children are laid out as if they were children of its parent
+    ///
+    /// This is implemented by [`TaffyTree`](crate::TaffyTree), which flattens `Contents` nodes out of the list of children
+    /// that it presents to layout algorithms. Implementors of the low-level API must do the same (layout algorithms treat
+    /// a `Contents` child like any other node). A `Contents` node that is laid out directly (e.g. as the root) is hidden.
+    Contents,
     /// The node is hidden, and it's children will also be hidden
     None,
 }
@@ -210,6 +233,7 @@ impl core::fmt::Display for Display {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Display::None => write!(f, "NONE"),
+            Display::Contents => write!(f, "CONTENTS"),
             #[cfg(feature = "block_layout")]
             Display::Block => write!(f, "BLOCK"),
             #[cfg(feature = "flexbox")]
@@ -258,8 +282,8 @@ pub enum Position {
     /// Offsets do not affect the position of any other items; they are effectively a correction factor applied at the end.
     #[default]
     Relative,
-    /// The offset is computed relative to this item's closest positioned ancestor, if any.
-    /// Otherwise, it is placed relative to the origin.
+    /// The offset is computed relative to this item's parent, which always acts as its containing block.
+    /// (Taffy has no equivalent of CSS's `position: static`, so every node is a "positioned ancestor").
     /// No space is created for the item in the page layout, and its size will not be altered.
     ///
     /// WARNING: to opt-out of layouting entirely, you must use [`Display::None`] instead on your [`Style`] object.
@@ -289,6 +313,48 @@ pub enum BoxSizing {
     ContentBox,
 }
 
+/// The inline base direction of text and content, which determines which physical side is the "start" of the inline axis
+///
+/// Taffy currently always lays content out left-to-right. This is used by direction-aware helpers such as
+/// [`Rect::inline_start`] so that code can be written in terms of logical sides.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/direction>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction {
+    /// Content flows from left to right, so the inline start is the left side
+    #[default]
+    Ltr,
+    /// Content flows from right to left, so the inline start is the right side
+    Rtl,
+}
+
+impl Direction {
+    /// Returns true if the direction is [`Direction::Rtl`]
+    #[inline]
+    pub const fn is_rtl(self) -> bool {
+        matches!(self, Self::Rtl)
+    }
+}
+
+/// The origin against which the layouts of a node's descendants are snapped to whole pixels
+///
+/// By default layouts are rounded relative to the root of the tree, which assumes that the tree is rendered without
+/// any offsets that layout doesn't know about. Content inside a scroll container, or inside a panel which is
+/// transformed or rendered onto its own canvas, is displayed at an offset from that origin, so it should be rounded
+/// relative to the container instead.
+///
+/// This only has an effect when rounding is enabled (see [`TaffyTree::enable_rounding`](crate::TaffyTree::enable_rounding)).
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RoundingOrigin {
+    /// Descendants are rounded against the same origin as this node (ultimately the root of the tree)
+    #[default]
+    Inherit,
+    /// Descendants are rounded against the top-left corner of this node's border box
+    Node,
+}
+
 /// How children overflowing their container should affect layout
 ///
 /// In CSS the primary effect of this property is to control whether contents of a parent container that overflow that container should
@@ -385,6 +451,9 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     /// What should the `position` value of this struct use as a base offset?
     pub position: Position,
     /// How should the position of this element be tweaked relative to the layout defined?
+    ///
+    /// For absolutely positioned items, insets are measured from the padding edge of the parent (just inside its border),
+    /// and percentage insets resolve against the size of the parent's padding box, matching CSS.
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
     pub inset: Rect<LengthPercentageAuto>,
 
@@ -413,6 +482,13 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     /// How large should the border be on each side?
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::zero"))]
     pub border: Rect<LengthPercentage>,
+    /// How far each edge of the area which responds to pointer input is inset from the node's border box
+    ///
+    /// Negative values expand the area beyond the border box, for example to give a small icon a larger touch target.
+    /// This does not affect layout: it is only used by [`TaffyTree::hit_test`](crate::TaffyTree::hit_test).
+    pub hit_inset: Rect<f32>,
+    /// The origin against which the layouts of this node's descendants are rounded
+    pub rounding_origin: RoundingOrigin,
 
     // Alignment properties
     /// How this node's children aligned in the cross/block axis?
@@ -452,6 +528,27 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     /// Should elements wrap, or stay in a single line?
     #[cfg(feature = "flexbox")]
     pub flex_wrap: FlexWrap,
+    /// How precisely free space is distributed between growing items
+    #[cfg(feature = "flexbox")]
+    pub flex_grow_precision: FlexGrowPrecision,
+    /// The relative rate at which each gap between items grows when the line is expanding to fill space
+    ///
+    /// Each gap in the main axis takes a share of the free space as though it were an item with this `flex_grow`,
+    /// and the gaps absorb any free space which is left after the items are sized, so `justify-content` and `auto`
+    /// margins have no effect on lines which grow their gaps. This is not a CSS property.
+    ///
+    /// 0.0 is the default value, and this value must be positive.
+    #[cfg(feature = "flexbox")]
+    pub gap_grow: f32,
+    /// The most items that a line of a wrapping container can hold
+    ///
+    /// Items which would otherwise fit on a line are moved to the next line once it holds this many items. This has
+    /// no effect unless `flex_wrap` is `Wrap` or `WrapReverse`, and a line always holds at least one item. This is
+    /// not a CSS property.
+    ///
+    /// `None` (no limit) is the default value.
+    #[cfg(feature = "flexbox")]
+    pub max_items_per_line: Option<u16>,
 
     // Flexbox item properties
     /// Sets the initial main axis size of the item
@@ -467,6 +564,45 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     /// 1.0 is the default value, and this value must be positive.
     #[cfg(feature = "flexbox")]
     pub flex_shrink: f32,
+    /// The most that this item can grow beyond its flex basis, in pixels
+    ///
+    /// The item's main size is capped at its flex basis plus this amount when it grows, in addition to its max size,
+    /// and the free space it can't take is distributed between the other items. This is not a CSS property.
+    /// `None` (no limit) is the default value.
+    #[cfg(feature = "flexbox")]
+    pub flex_grow_max: Option<f32>,
+    /// The most that this item can shrink below its flex basis, in pixels
+    ///
+    /// The item's main size is floored at its flex basis minus this amount when it shrinks, in addition to its min
+    /// size, and the overflow it can't absorb is taken from the other items. This is not a CSS property.
+    /// `None` (no limit) is the default value.
+    #[cfg(feature = "flexbox")]
+    pub flex_shrink_min: Option<f32>,
+    /// Whether an `auto` minimum size in the main axis resolves to the item's content-based
+    /// [automatic minimum size](https://www.w3.org/TR/css-flexbox-1/#min-size-auto) (as in CSS) or to zero
+    ///
+    /// `true` is the default value.
+    #[cfg(feature = "flexbox")]
+    pub flex_auto_min_size: bool,
+    /// Whether this item always starts a new line when its container wraps
+    ///
+    /// This has no effect unless the container's `flex_wrap` is `Wrap` or `WrapReverse`, or on the first item. Forced
+    /// breaks also apply when the container is sized under a max-content constraint, where items otherwise never wrap.
+    /// This is not a CSS property.
+    ///
+    /// `false` is the default value.
+    #[cfg(feature = "flexbox")]
+    pub break_before: bool,
+    /// The alignment to use instead of `Baseline` alignment when this item has no baseline of its own
+    ///
+    /// This applies whether `Baseline` comes from `align_self` or the container's `align_items`. An item has no
+    /// baseline if its layout doesn't report one (for example an empty box or an image), and baseline alignment
+    /// never applies in column containers, so the fallback is always used there. Without a fallback a baseline is
+    /// synthesized from the bottom edge of the item, as in CSS. This is not a CSS property.
+    ///
+    /// `None` is the default value.
+    #[cfg(feature = "flexbox")]
+    pub align_self_fallback: Option<AlignSelf>,
 
     // Grid container properies
     /// Defines the track sizing functions (heights) of the grid rows
@@ -520,6 +656,8 @@ impl<S: CheapCloneStr> Style<S> {
         margin: Rect::zero(),
         padding: Rect::zero(),
         border: Rect::zero(),
+        hit_inset: Rect::zero(),
+        rounding_origin: RoundingOrigin::Inherit,
         size: Size::auto(),
         min_size: Size::auto(),
         max_size: Size::auto(),
@@ -548,11 +686,27 @@ impl<S: CheapCloneStr> Style<S> {
         #[cfg(feature = "flexbox")]
         flex_wrap: FlexWrap::NoWrap,
         #[cfg(feature = "flexbox")]
+        flex_grow_precision: FlexGrowPrecision::Standard,
+        #[cfg(feature = "flexbox")]
+        gap_grow: 0.0,
+        #[cfg(feature = "flexbox")]
+        max_items_per_line: None,
+        #[cfg(feature = "flexbox")]
         flex_grow: 0.0,
         #[cfg(feature = "flexbox")]
         flex_shrink: 1.0,
         #[cfg(feature = "flexbox")]
+        flex_grow_max: None,
+        #[cfg(feature = "flexbox")]
+        flex_shrink_min: None,
+        #[cfg(feature = "flexbox")]
         flex_basis: Dimension::AUTO,
+        #[cfg(feature = "flexbox")]
+        flex_auto_min_size: true,
+        #[cfg(feature = "flexbox")]
+        break_before: false,
+        #[cfg(feature = "flexbox")]
+        align_self_fallback: None,
         // Grid
         #[cfg(feature = "grid")]
         grid_template_rows: GridTrackVec::new(),
@@ -575,6 +729,54 @@ impl<S: CheapCloneStr> Style<S> {
         #[cfg(feature = "grid")]
         grid_column: Line { start: GridPlacement::<S>::Auto, end: GridPlacement::<S>::Auto },
     };
+
+    /// The margin on the inline start side for the given `direction`
+    #[inline]
+    pub const fn margin_inline_start(&self, direction: Direction) -> LengthPercentageAuto {
+        self.margin.inline_start(direction)
+    }
+
+    /// The margin on the inline end side for the given `direction`
+    #[inline]
+    pub const fn margin_inline_end(&self, direction: Direction) -> LengthPercentageAuto {
+        self.margin.inline_end(direction)
+    }
+
+    /// The padding on the inline start side for the given `direction`
+    #[inline]
+    pub const fn padding_inline_start(&self, direction: Direction) -> LengthPercentage {
+        self.padding.inline_start(direction)
+    }
+
+    /// The padding on the inline end side for the given `direction`
+    #[inline]
+    pub const fn padding_inline_end(&self, direction: Direction) -> LengthPercentage {
+        self.padding.inline_end(direction)
+    }
+
+    /// The border on the inline start side for the given `direction`
+    #[inline]
+    pub const fn border_inline_start(&self, direction: Direction) -> LengthPercentage {
+        self.border.inline_start(direction)
+    }
+
+    /// The border on the inline end side for the given `direction`
+    #[inline]
+    pub const fn border_inline_end(&self, direction: Direction) -> LengthPercentage {
+        self.border.inline_end(direction)
+    }
+
+    /// The inset on the inline start side for the given `direction`
+    #[inline]
+    pub const fn inset_inline_start(&self, direction: Direction) -> LengthPercentageAuto {
+        self.inset.inline_start(direction)
+    }
+
+    /// The inset on the inline end side for the given `direction`
+    #[inline]
+    pub const fn inset_inline_end(&self, direction: Direction) -> LengthPercentageAuto {
+        self.inset.inline_end(direction)
+    }
 }
 
 impl<S: CheapCloneStr> Default for Style<S> {
@@ -760,6 +962,18 @@ impl<S: CheapCloneStr> FlexboxContainerStyle for Style<S> {
         self.flex_wrap
     }
     #[inline(always)]
+    fn flex_grow_precision(&self) -> FlexGrowPrecision {
+        self.flex_grow_precision
+    }
+    #[inline(always)]
+    fn gap_grow(&self) -> f32 {
+        self.gap_grow
+    }
+    #[inline(always)]
+    fn max_items_per_line(&self) -> Option<u16> {
+        self.max_items_per_line
+    }
+    #[inline(always)]
     fn gap(&self) -> Size<LengthPercentage> {
         self.gap
     }
@@ -788,6 +1002,18 @@ impl<T: FlexboxContainerStyle> FlexboxContainerStyle for &'_ T {
         (*self).flex_wrap()
     }
     #[inline(always)]
+    fn flex_grow_precision(&self) -> FlexGrowPrecision {
+        (*self).flex_grow_precision()
+    }
+    #[inline(always)]
+    fn gap_grow(&self) -> f32 {
+        (*self).gap_grow()
+    }
+    #[inline(always)]
+    fn max_items_per_line(&self) -> Option<u16> {
+        (*self).max_items_per_line()
+    }
+    #[inline(always)]
     fn gap(&self) -> Size<LengthPercentage> {
         (*self).gap()
     }
@@ -820,6 +1046,26 @@ impl<S: CheapCloneStr> FlexboxItemStyle for Style<S> {
         self.flex_shrink
     }
     #[inline(always)]
+    fn flex_grow_max(&self) -> Option<f32> {
+        self.flex_grow_max
+    }
+    #[inline(always)]
+    fn flex_shrink_min(&self) -> Option<f32> {
+        self.flex_shrink_min
+    }
+    #[inline(always)]
+    fn flex_auto_min_size(&self) -> bool {
+        self.flex_auto_min_size
+    }
+    #[inline(always)]
+    fn break_before(&self) -> bool {
+        self.break_before
+    }
+    #[inline(always)]
+    fn align_self_fallback(&self) -> Option<AlignSelf> {
+        self.align_self_fallback
+    }
+    #[inline(always)]
     fn align_self(&self) -> Option<AlignSelf> {
         self.align_self
     }
@@ -840,6 +1086,26 @@ impl<T: FlexboxItemStyle> FlexboxItemStyle for &'_ T {
         (*self).flex_shrink()
     }
     #[inline(always)]
+    fn flex_grow_max(&self) -> Option<f32> {
+        (*self).flex_grow_max()
+    }
+    #[inline(always)]
+    fn flex_shrink_min(&self) -> Option<f32> {
+        (*self).flex_shrink_min()
+    }
+    #[inline(always)]
+    fn flex_auto_min_size(&self) -> bool {
+        (*self).flex_auto_min_size()
+    }
+    #[inline(always)]
+    fn break_before(&self) -> bool {
+        (*self).break_before()
+    }
+    #[inline(always)]
+    fn align_self_fallback(&self) -> Option<AlignSelf> {
+        (*self).align_self_fallback()
+    }
+    #[inline(always)]
     fn align_self(&self) -> Option<AlignSelf> {
         (*self).align_self()
     }
@@ -1090,6 +1356,12 @@ mod tests {
             flex_direction: Default::default(),
             #[cfg(feature = "flexbox")]
             flex_wrap: Default::default(),
+            #[cfg(feature = "flexbox")]
+            flex_grow_precision: Default::default(),
+            #[cfg(feature = "flexbox")]
+            gap_grow: 0.0,
+            #[cfg(feature = "flexbox")]
+            max_items_per_line: None,
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_items: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
@@ -1106,6 +1378,8 @@ mod tests {
             margin: Rect::zero(),
             padding: Rect::zero(),
             border: Rect::zero(),
+            hit_inset: Rect::zero(),
+            rounding_origin: Default::default(),
             gap: Size::zero(),
             #[cfg(feature = "block_layout")]
             text_align: Default::default(),
@@ -1114,7 +1388,17 @@ mod tests {
             #[cfg(feature = "flexbox")]
             flex_shrink: 1.0,
             #[cfg(feature = "flexbox")]
+            flex_grow_max: None,
+            #[cfg(feature = "flexbox")]
+            flex_shrink_min: None,
+            #[cfg(feature = "flexbox")]
             flex_basis: super::Dimension::AUTO,
+            #[cfg(feature = "flexbox")]
+            flex_auto_min_size: true,
+            #[cfg(feature = "flexbox")]
+            break_before: false,
+            #[cfg(feature = "flexbox")]
+            align_self_fallback: None,
             size: Size::auto(),
             min_size: Size::auto(),
             max_size: Size::auto(),
@@ -1145,6 +1429,26 @@ mod tests {
         assert_eq!(Style::DEFAULT, old_defaults);
     }
 
+    #[test]
+    fn inline_sides_follow_direction() {
+        use super::{Direction, LengthPercentage, Style};
+        use crate::geometry::Rect;
+
+        let style: Style = Style {
+            padding: Rect {
+                left: LengthPercentage::length(1.0),
+                right: LengthPercentage::length(2.0),
+                top: LengthPercentage::length(3.0),
+                bottom: LengthPercentage::length(4.0),
+            },
+            ..Default::default()
+        };
+        assert_eq!(style.padding_inline_start(Direction::Ltr), LengthPercentage::length(1.0));
+        assert_eq!(style.padding_inline_end(Direction::Ltr), LengthPercentage::length(2.0));
+        assert_eq!(style.padding_inline_start(Direction::Rtl), LengthPercentage::length(2.0));
+        assert_eq!(style.padding_inline_end(Direction::Rtl), LengthPercentage::length(1.0));
+    }
+
     // NOTE: Please feel free the update the sizes in this test as required. This test is here to prevent unintentional size changes
     // and to serve as accurate up-to-date documentation on the sizes.
     #[test]
@@ -1196,6 +1500,7 @@ mod tests {
         // Flexbox Container
         assert_type_size::<FlexDirection>(1);
         assert_type_size::<FlexWrap>(1);
+        assert_type_size::<FlexGrowPrecision>(1);
 
         // CSS Grid Container
         assert_type_size::<GridAutoFlow>(1);
@@ -1209,12 +1514,12 @@ mod tests {
         assert_type_size::<GridTemplateComponent<String>>(56);
         assert_type_size::<GridPlacement<String>>(32);
         assert_type_size::<Line<GridPlacement<String>>>(64);
-        assert_type_size::<Style<String>>(536);
+        assert_type_size::<Style<String>>(584);
 
         // String-type dependent (Arc<str>)
         assert_type_size::<GridTemplateComponent<Arc<str>>>(56);
         assert_type_size::<GridPlacement<Arc<str>>>(24);
         assert_type_size::<Line<GridPlacement<Arc<str>>>>(48);
-        assert_type_size::<Style<Arc<str>>>(504);
+        assert_type_size::<Style<Arc<str>>>(552);
     }
 }
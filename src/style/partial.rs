@@ -0,0 +1,195 @@
+//! A partial [`Style`] where each property may or may not be set, which can be layered on top of a full style
+use super::*;
+
+/// Generates the `PartialStyle` struct and the methods which apply it from a list of `Style` fields
+macro_rules! partial_style {
+    ($($(#[cfg($cfg:meta)])? $field:ident: $ty:ty,)*) => {
+        /// A set of overrides for the properties of a [`Style`]
+        ///
+        /// Every field is optional: fields that are `None` leave the corresponding property untouched when the
+        /// partial style is applied using [`Style::apply`]. This allows styles to be built up from multiple layers
+        /// (for example a theme, a widget's own style, and per-instance overrides) without merging structs by hand.
+        ///
+        /// ```rust
+        /// # use taffy::prelude::*;
+        /// # use taffy::PartialStyle;
+        /// let theme = PartialStyle { padding: Some(length(8.0)), ..Default::default() };
+        /// let instance = PartialStyle { flex_grow: Some(1.0), ..Default::default() };
+        ///
+        /// let mut style: Style = Style::default();
+        /// style.apply(&theme);
+        /// style.apply(&instance);
+        /// assert_eq!(style.padding, length(8.0));
+        /// assert_eq!(style.flex_grow, 1.0);
+        /// ```
+        ///
+        /// With the `serde` feature enabled only the fields which are set are serialized, so a partial style can be
+        /// stored as a small patch (e.g. a theme file) and applied at runtime once deserialized. Note that a field
+        /// whose value is itself an `Option` (such as `aspect_ratio`) cannot be explicitly cleared by a patch in
+        /// this form, as `null` deserializes to "not set".
+        #[derive(Clone, PartialEq, Debug)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub struct PartialStyle<S: CheapCloneStr = DefaultCheapStr> {
+            /// This is a dummy field which is necessary to make Taffy compile with the `grid` feature disabled
+            /// It should always be set to `core::marker::PhantomData`.
+            #[cfg_attr(feature = "serde", serde(skip))]
+            pub dummy: core::marker::PhantomData<S>,
+            $(
+                $(#[cfg($cfg)])?
+                #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+                #[doc = concat!("Overrides [`Style::", stringify!($field), "`] if set")]
+                pub $field: Option<$ty>,
+            )*
+        }
+
+        impl<S: CheapCloneStr> Default for PartialStyle<S> {
+            fn default() -> Self {
+                PartialStyle {
+                    dummy: core::marker::PhantomData,
+                    $(
+                        $(#[cfg($cfg)])?
+                        $field: None,
+                    )*
+                }
+            }
+        }
+
+        impl<S: CheapCloneStr> PartialStyle<S> {
+            /// Overrides the properties of this partial style with the properties that are set in `patch`
+            ///
+            /// Applying the combined patch to a [`Style`] is equivalent to applying `self` followed by `patch`.
+            pub fn apply(&mut self, patch: &PartialStyle<S>) {
+                $(
+                    $(#[cfg($cfg)])?
+                    if let Some(value) = &patch.$field {
+                        self.$field = Some(value.clone());
+                    }
+                )*
+            }
+        }
+
+        impl<S: CheapCloneStr> Style<S> {
+            /// Overrides the properties of this style with the properties that are set in `patch`
+            pub fn apply(&mut self, patch: &PartialStyle<S>) {
+                $(
+                    $(#[cfg($cfg)])?
+                    if let Some(value) = &patch.$field {
+                        self.$field = value.clone();
+                    }
+                )*
+            }
+        }
+    };
+}
+
+partial_style! {
+    display: Display,
+    item_is_table: bool,
+    item_is_replaced: bool,
+    box_sizing: BoxSizing,
+    overflow: Point<Overflow>,
+    scrollbar_width: f32,
+    position: Position,
+    inset: Rect<LengthPercentageAuto>,
+    size: Size<Dimension>,
+    min_size: Size<Dimension>,
+    max_size: Size<Dimension>,
+    aspect_ratio: Option<f32>,
+    margin: Rect<LengthPercentageAuto>,
+    padding: Rect<LengthPercentage>,
+    border: Rect<LengthPercentage>,
+    hit_inset: Rect<f32>,
+    rounding_origin: RoundingOrigin,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    align_items: Option<AlignItems>,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    align_self: Option<AlignSelf>,
+    #[cfg(feature = "grid")]
+    justify_items: Option<AlignItems>,
+    #[cfg(feature = "grid")]
+    justify_self: Option<AlignSelf>,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    align_content: Option<AlignContent>,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    justify_content: Option<JustifyContent>,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    gap: Size<LengthPercentage>,
+    #[cfg(feature = "block_layout")]
+    text_align: TextAlign,
+    #[cfg(feature = "flexbox")]
+    flex_direction: FlexDirection,
+    #[cfg(feature = "flexbox")]
+    flex_wrap: FlexWrap,
+    #[cfg(feature = "flexbox")]
+    flex_grow_precision: FlexGrowPrecision,
+    #[cfg(feature = "flexbox")]
+    gap_grow: f32,
+    #[cfg(feature = "flexbox")]
+    max_items_per_line: Option<u16>,
+    #[cfg(feature = "flexbox")]
+    flex_basis: Dimension,
+    #[cfg(feature = "flexbox")]
+    flex_grow: f32,
+    #[cfg(feature = "flexbox")]
+    flex_shrink: f32,
+    #[cfg(feature = "flexbox")]
+    flex_grow_max: Option<f32>,
+    #[cfg(feature = "flexbox")]
+    flex_shrink_min: Option<f32>,
+    #[cfg(feature = "flexbox")]
+    flex_auto_min_size: bool,
+    #[cfg(feature = "flexbox")]
+    break_before: bool,
+    #[cfg(feature = "flexbox")]
+    align_self_fallback: Option<AlignSelf>,
+    #[cfg(feature = "grid")]
+    grid_template_rows: GridTrackVec<GridTemplateComponent<S>>,
+    #[cfg(feature = "grid")]
+    grid_template_columns: GridTrackVec<GridTemplateComponent<S>>,
+    #[cfg(feature = "grid")]
+    grid_auto_rows: GridTrackVec<TrackSizingFunction>,
+    #[cfg(feature = "grid")]
+    grid_auto_columns: GridTrackVec<TrackSizingFunction>,
+    #[cfg(feature = "grid")]
+    grid_auto_flow: GridAutoFlow,
+    #[cfg(feature = "grid")]
+    grid_template_areas: GridTrackVec<GridTemplateArea<S>>,
+    #[cfg(feature = "grid")]
+    grid_template_column_names: GridTrackVec<GridTrackVec<S>>,
+    #[cfg(feature = "grid")]
+    grid_template_row_names: GridTrackVec<GridTrackVec<S>>,
+    #[cfg(feature = "grid")]
+    grid_row: Line<GridPlacement<S>>,
+    #[cfg(feature = "grid")]
+    grid_column: Line<GridPlacement<S>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PartialStyle;
+    use crate::style::{Display, Style};
+    use crate::style_helpers::{length, percent};
+
+    #[test]
+    fn apply_only_overrides_set_fields() {
+        let mut style: Style = Style { display: Display::None, padding: length(4.0), ..Default::default() };
+        style.apply(&PartialStyle { padding: Some(percent(0.5)), ..Default::default() });
+
+        assert_eq!(style.display, Display::None);
+        assert_eq!(style.padding, percent(0.5));
+        let default: Style = Style::DEFAULT;
+        assert_eq!(style.margin, default.margin);
+    }
+
+    #[test]
+    fn later_layers_take_precedence() {
+        let mut layers: PartialStyle = PartialStyle { aspect_ratio: Some(Some(2.0)), ..Default::default() };
+        layers.apply(&PartialStyle { aspect_ratio: Some(None), display: Some(Display::None), ..Default::default() });
+
+        let mut style: Style = Style { aspect_ratio: Some(1.0), ..Default::default() };
+        style.apply(&layers);
+        assert_eq!(style.aspect_ratio, None);
+        assert_eq!(style.display, Display::None);
+    }
+}
@@ -0,0 +1,258 @@
+//! Access to the properties of a [`Style`] by name, for dynamic editors and scripting bindings
+use super::*;
+
+/// The value of a single [`Style`] property, as returned by [`Style::get_field`] and accepted by [`Style::set_field`]
+///
+/// There is one variant for each distinct type of property (so for example `size`, `min_size` and `max_size`
+/// all use [`StyleFieldValue::DimensionSize`]).
+#[derive(Clone, PartialEq, Debug)]
+pub enum StyleFieldValue<S: CheapCloneStr = DefaultCheapStr> {
+    /// A [`Display`] property
+    Display(Display),
+    /// A boolean property
+    Bool(bool),
+    /// A [`BoxSizing`] property
+    BoxSizing(BoxSizing),
+    /// An [`Overflow`] property in each axis
+    Overflow(Point<Overflow>),
+    /// A number
+    Float(f32),
+    /// An optional number
+    OptionalFloat(Option<f32>),
+    /// An optional count
+    #[cfg(feature = "flexbox")]
+    OptionalCount(Option<u16>),
+    /// A [`Position`] property
+    Position(Position),
+    /// A [`RoundingOrigin`] property
+    RoundingOrigin(RoundingOrigin),
+    /// A [`LengthPercentageAuto`] for each edge
+    LengthPercentageAutoRect(Rect<LengthPercentageAuto>),
+    /// A [`LengthPercentage`] for each edge
+    LengthPercentageRect(Rect<LengthPercentage>),
+    /// A number for each edge
+    FloatRect(Rect<f32>),
+    /// A [`Dimension`] in each axis
+    DimensionSize(Size<Dimension>),
+    /// An optional [`AlignItems`] property (also used for `align_self`, `align_self_fallback`, `justify_items` and
+    /// `justify_self`)
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    AlignItems(Option<AlignItems>),
+    /// An optional [`AlignContent`] property (also used for `justify_content`)
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    AlignContent(Option<AlignContent>),
+    /// A [`LengthPercentage`] in each axis
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    LengthPercentageSize(Size<LengthPercentage>),
+    /// A [`TextAlign`] property
+    #[cfg(feature = "block_layout")]
+    TextAlign(TextAlign),
+    /// A [`FlexDirection`] property
+    #[cfg(feature = "flexbox")]
+    FlexDirection(FlexDirection),
+    /// A [`FlexWrap`] property
+    #[cfg(feature = "flexbox")]
+    FlexWrap(FlexWrap),
+    /// A [`FlexGrowPrecision`] property
+    #[cfg(feature = "flexbox")]
+    FlexGrowPrecision(FlexGrowPrecision),
+    /// A [`Dimension`] property
+    #[cfg(feature = "flexbox")]
+    Dimension(Dimension),
+    /// A list of grid template tracks
+    #[cfg(feature = "grid")]
+    GridTemplateComponents(GridTrackVec<GridTemplateComponent<S>>),
+    /// A list of implicit grid tracks
+    #[cfg(feature = "grid")]
+    TrackSizingFunctions(GridTrackVec<TrackSizingFunction>),
+    /// A [`GridAutoFlow`] property
+    #[cfg(feature = "grid")]
+    GridAutoFlow(GridAutoFlow),
+    /// A list of named grid areas
+    #[cfg(feature = "grid")]
+    GridTemplateAreas(GridTrackVec<GridTemplateArea<S>>),
+    /// A list of names for each grid line
+    #[cfg(feature = "grid")]
+    GridLineNames(GridTrackVec<GridTrackVec<S>>),
+    /// The start and end [`GridPlacement`] of a grid item in one axis
+    #[cfg(feature = "grid")]
+    GridPlacement(Line<GridPlacement<S>>),
+    /// This is a dummy variant which is necessary to make Taffy compile with the `grid` feature disabled.
+    /// It does not correspond to any property.
+    #[doc(hidden)]
+    Dummy(core::marker::PhantomData<S>),
+}
+
+/// An error returned by [`Style::set_field`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StyleFieldError {
+    /// There is no property with the given name
+    UnknownField,
+    /// The property exists but the value is of the wrong type for it
+    WrongType,
+    /// The value is a keyword which is not valid for the property
+    UnknownKeyword,
+}
+
+impl core::fmt::Display for StyleFieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            StyleFieldError::UnknownField => write!(f, "Unknown style property"),
+            StyleFieldError::WrongType => write!(f, "Value has the wrong type for the style property"),
+            StyleFieldError::UnknownKeyword => write!(f, "Unknown keyword for the style property"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StyleFieldError {}
+
+/// Generates the by-name accessors for the properties of `Style` from a list of fields and their value variants
+macro_rules! style_fields {
+    ($($(#[cfg($cfg:meta)])? $field:ident: $variant:ident,)*) => {
+        impl<S: CheapCloneStr> Style<S> {
+            /// The names of all of the properties of a [`Style`] (with the currently enabled features)
+            pub const FIELD_NAMES: &'static [&'static str] = &[$($(#[cfg($cfg)])? stringify!($field),)*];
+
+            /// Returns the value of the property called `name`, or `None` if there is no such property
+            pub fn get_field(&self, name: &str) -> Option<StyleFieldValue<S>> {
+                match name {
+                    $(
+                        $(#[cfg($cfg)])?
+                        stringify!($field) => Some(StyleFieldValue::$variant(self.$field.clone())),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Sets the value of the property called `name`
+            ///
+            /// Fails if there is no such property, or if `value` is not the type of value the property holds.
+            pub fn set_field(&mut self, name: &str, value: StyleFieldValue<S>) -> Result<(), StyleFieldError> {
+                match (name, value) {
+                    $(
+                        $(#[cfg($cfg)])?
+                        (stringify!($field), StyleFieldValue::$variant(value)) => self.$field = value,
+                    )*
+                    (name, _) if Self::FIELD_NAMES.contains(&name) => return Err(StyleFieldError::WrongType),
+                    _ => return Err(StyleFieldError::UnknownField),
+                }
+                Ok(())
+            }
+
+            /// Returns the name and value of each of the properties of the style, in declaration order
+            pub fn fields(&self) -> impl Iterator<Item = (&'static str, StyleFieldValue<S>)> + '_ {
+                Self::FIELD_NAMES.iter().filter_map(move |&name| Some((name, self.get_field(name)?)))
+            }
+        }
+    };
+}
+
+style_fields! {
+    display: Display,
+    item_is_table: Bool,
+    item_is_replaced: Bool,
+    box_sizing: BoxSizing,
+    overflow: Overflow,
+    scrollbar_width: Float,
+    position: Position,
+    inset: LengthPercentageAutoRect,
+    size: DimensionSize,
+    min_size: DimensionSize,
+    max_size: DimensionSize,
+    aspect_ratio: OptionalFloat,
+    margin: LengthPercentageAutoRect,
+    padding: LengthPercentageRect,
+    border: LengthPercentageRect,
+    hit_inset: FloatRect,
+    rounding_origin: RoundingOrigin,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    align_items: AlignItems,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    align_self: AlignItems,
+    #[cfg(feature = "grid")]
+    justify_items: AlignItems,
+    #[cfg(feature = "grid")]
+    justify_self: AlignItems,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    align_content: AlignContent,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    justify_content: AlignContent,
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    gap: LengthPercentageSize,
+    #[cfg(feature = "block_layout")]
+    text_align: TextAlign,
+    #[cfg(feature = "flexbox")]
+    flex_direction: FlexDirection,
+    #[cfg(feature = "flexbox")]
+    flex_wrap: FlexWrap,
+    #[cfg(feature = "flexbox")]
+    flex_grow_precision: FlexGrowPrecision,
+    #[cfg(feature = "flexbox")]
+    gap_grow: Float,
+    #[cfg(feature = "flexbox")]
+    max_items_per_line: OptionalCount,
+    #[cfg(feature = "flexbox")]
+    flex_basis: Dimension,
+    #[cfg(feature = "flexbox")]
+    flex_grow: Float,
+    #[cfg(feature = "flexbox")]
+    flex_shrink: Float,
+    #[cfg(feature = "flexbox")]
+    flex_grow_max: OptionalFloat,
+    #[cfg(feature = "flexbox")]
+    flex_shrink_min: OptionalFloat,
+    #[cfg(feature = "flexbox")]
+    flex_auto_min_size: Bool,
+    #[cfg(feature = "flexbox")]
+    break_before: Bool,
+    #[cfg(feature = "flexbox")]
+    align_self_fallback: AlignItems,
+    #[cfg(feature = "grid")]
+    grid_template_rows: GridTemplateComponents,
+    #[cfg(feature = "grid")]
+    grid_template_columns: GridTemplateComponents,
+    #[cfg(feature = "grid")]
+    grid_auto_rows: TrackSizingFunctions,
+    #[cfg(feature = "grid")]
+    grid_auto_columns: TrackSizingFunctions,
+    #[cfg(feature = "grid")]
+    grid_auto_flow: GridAutoFlow,
+    #[cfg(feature = "grid")]
+    grid_template_areas: GridTemplateAreas,
+    #[cfg(feature = "grid")]
+    grid_template_column_names: GridLineNames,
+    #[cfg(feature = "grid")]
+    grid_template_row_names: GridLineNames,
+    #[cfg(feature = "grid")]
+    grid_row: GridPlacement,
+    #[cfg(feature = "grid")]
+    grid_column: GridPlacement,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StyleFieldError, StyleFieldValue};
+    use crate::style::{Display, Style};
+    use crate::style_helpers::length;
+
+    #[test]
+    fn fields_lists_every_property() {
+        let style: Style = Style::DEFAULT;
+        let fields: Vec<_> = style.fields().collect();
+        assert_eq!(fields.len(), <Style>::FIELD_NAMES.len());
+        assert_eq!(fields[0], ("display", StyleFieldValue::Display(style.display)));
+    }
+
+    #[test]
+    fn set_field_by_name() {
+        let mut style: Style = Style::DEFAULT;
+        style.set_field("padding", StyleFieldValue::LengthPercentageRect(length(3.0))).unwrap();
+        style.set_field("display", StyleFieldValue::Display(Display::None)).unwrap();
+        assert_eq!(style.padding, length(3.0));
+        assert_eq!(style.display, Display::None);
+
+        assert_eq!(style.set_field("padding", StyleFieldValue::Bool(true)), Err(StyleFieldError::WrongType));
+        assert_eq!(style.set_field("colour", StyleFieldValue::Bool(true)), Err(StyleFieldError::UnknownField));
+    }
+}
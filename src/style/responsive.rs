@@ -0,0 +1,84 @@
+//! A [`Style`] with overrides which apply at different available widths, like CSS media queries
+use super::*;
+use crate::util::sys::Vec;
+
+/// A set of style overrides which apply while the width available to the root of the tree is within a range
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Breakpoint<S: CheapCloneStr = DefaultCheapStr> {
+    /// The smallest available width at which the overrides apply, or `None` for no lower bound
+    pub min_width: Option<f32>,
+    /// The available width at and above which the overrides no longer apply, or `None` for no upper bound
+    pub max_width: Option<f32>,
+    /// The properties which are overridden while the breakpoint applies
+    pub overrides: PartialStyle<S>,
+}
+
+impl<S: CheapCloneStr> Breakpoint<S> {
+    /// Returns whether the overrides apply at the given available width
+    pub fn matches(&self, available_width: f32) -> bool {
+        self.min_width.map_or(true, |min| available_width >= min)
+            && self.max_width.map_or(true, |max| available_width < max)
+    }
+}
+
+/// A [`Style`] which adapts to the width available to the root of the tree
+///
+/// Attach one to a node with [`TaffyTree::set_responsive_style`](crate::TaffyTree::set_responsive_style). At the
+/// start of each call to [`TaffyTree::compute_layout`](crate::TaffyTree::compute_layout) the style of the node is set
+/// to the base style with the overrides of every matching breakpoint applied in order, so later breakpoints take
+/// precedence over earlier ones.
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// # use taffy::{PartialStyle, ResponsiveStyle};
+/// let style: ResponsiveStyle = ResponsiveStyle::new(Style { flex_direction: FlexDirection::Row, ..Default::default() })
+///     .with_breakpoint(None, Some(600.0), PartialStyle { flex_direction: Some(FlexDirection::Column), ..Default::default() });
+///
+/// assert_eq!(style.resolve(400.0).flex_direction, FlexDirection::Column);
+/// assert_eq!(style.resolve(800.0).flex_direction, FlexDirection::Row);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ResponsiveStyle<S: CheapCloneStr = DefaultCheapStr> {
+    /// The style which applies when no breakpoint matches
+    pub base: Style<S>,
+    /// The breakpoints, in increasing order of precedence
+    pub breakpoints: Vec<Breakpoint<S>>,
+}
+
+impl<S: CheapCloneStr> ResponsiveStyle<S> {
+    /// Create a responsive style with no breakpoints
+    pub fn new(base: Style<S>) -> Self {
+        Self { base, breakpoints: Vec::new() }
+    }
+
+    /// Add a breakpoint whose `overrides` apply while the available width is at least `min_width` and less than
+    /// `max_width`
+    pub fn with_breakpoint(
+        mut self,
+        min_width: Option<f32>,
+        max_width: Option<f32>,
+        overrides: PartialStyle<S>,
+    ) -> Self {
+        self.breakpoints.push(Breakpoint { min_width, max_width, overrides });
+        self
+    }
+
+    /// The style which applies at the given available width
+    pub fn resolve(&self, available_width: f32) -> Style<S>
+    where
+        Style<S>: Clone,
+    {
+        let mut style = self.base.clone();
+        self.apply_overrides(&mut style, available_width);
+        style
+    }
+
+    /// Applies the overrides of every breakpoint which matches the given available width to `style`, in order
+    pub(crate) fn apply_overrides(&self, style: &mut Style<S>, available_width: f32) {
+        for breakpoint in self.breakpoints.iter().filter(|breakpoint| breakpoint.matches(available_width)) {
+            style.apply(&breakpoint.overrides);
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! A dynamically typed style value, for scripting bindings and style parsers
+use super::*;
+
+/// A dynamically typed value of a style property
+///
+/// This is a common representation that scripting bindings and CSS-like parsers can produce without knowing the
+/// concrete type of each property. It can be converted to and from the typed values of [`Style`] properties using
+/// `TryFrom`, or applied to a property by name using [`Style::set_field_from_value`].
+///
+/// Keywords use their CSS names (e.g. `"flex-start"`, `"row-reverse"` or `"border-box"`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleValue<'a> {
+    /// A boolean
+    Bool(bool),
+    /// A plain number or an absolute length
+    Number(f32),
+    /// A percentage, as a fraction in the range [0.0, 1.0]
+    Percent(f32),
+    /// The `auto` keyword. Also used for properties whose value is optional when they are unset.
+    Auto,
+    /// Any other keyword
+    Keyword(&'a str),
+}
+
+impl<'a> StyleValue<'a> {
+    /// Converts a keyword to a `StyleValue`, using [`StyleValue::Auto`] for `"auto"`
+    fn from_keyword(keyword: &'a str) -> Self {
+        match keyword {
+            "auto" => StyleValue::Auto,
+            keyword => StyleValue::Keyword(keyword),
+        }
+    }
+}
+
+impl From<bool> for StyleValue<'_> {
+    fn from(value: bool) -> Self {
+        StyleValue::Bool(value)
+    }
+}
+
+impl From<f32> for StyleValue<'_> {
+    fn from(value: f32) -> Self {
+        StyleValue::Number(value)
+    }
+}
+
+impl From<Option<f32>> for StyleValue<'_> {
+    fn from(value: Option<f32>) -> Self {
+        value.map(StyleValue::Number).unwrap_or(StyleValue::Auto)
+    }
+}
+
+impl TryFrom<StyleValue<'_>> for bool {
+    type Error = StyleFieldError;
+    fn try_from(value: StyleValue<'_>) -> Result<Self, Self::Error> {
+        match value {
+            StyleValue::Bool(value) => Ok(value),
+            _ => Err(StyleFieldError::WrongType),
+        }
+    }
+}
+
+impl TryFrom<StyleValue<'_>> for f32 {
+    type Error = StyleFieldError;
+    fn try_from(value: StyleValue<'_>) -> Result<Self, Self::Error> {
+        match value {
+            StyleValue::Number(value) => Ok(value),
+            _ => Err(StyleFieldError::WrongType),
+        }
+    }
+}
+
+impl TryFrom<StyleValue<'_>> for Option<f32> {
+    type Error = StyleFieldError;
+    fn try_from(value: StyleValue<'_>) -> Result<Self, Self::Error> {
+        match value {
+            StyleValue::Number(value) => Ok(Some(value)),
+            StyleValue::Auto => Ok(None),
+            _ => Err(StyleFieldError::WrongType),
+        }
+    }
+}
+
+/// Accepts whole numbers in the range of `u16`, or `auto` for `None`
+impl TryFrom<StyleValue<'_>> for Option<u16> {
+    type Error = StyleFieldError;
+    fn try_from(value: StyleValue<'_>) -> Result<Self, Self::Error> {
+        match value {
+            StyleValue::Number(value) if value >= 0.0 && value as u16 as f32 == value => Ok(Some(value as u16)),
+            StyleValue::Auto => Ok(None),
+            _ => Err(StyleFieldError::WrongType),
+        }
+    }
+}
+
+/// Implements the conversions between `StyleValue` and a length type which supports lengths, percentages
+/// and optionally `auto`
+macro_rules! length_conversions {
+    ($ty:ty, $auto:expr) => {
+        impl TryFrom<StyleValue<'_>> for $ty {
+            type Error = StyleFieldError;
+            fn try_from(value: StyleValue<'_>) -> Result<Self, Self::Error> {
+                match value {
+                    StyleValue::Number(value) => Ok(<$ty>::length(value)),
+                    StyleValue::Percent(value) => Ok(<$ty>::percent(value)),
+                    StyleValue::Auto => $auto.ok_or(StyleFieldError::WrongType),
+                    _ => Err(StyleFieldError::WrongType),
+                }
+            }
+        }
+
+        /// Fails for `calc()` values, which can not be represented as a `StyleValue`
+        impl TryFrom<$ty> for StyleValue<'static> {
+            type Error = StyleFieldError;
+            fn try_from(value: $ty) -> Result<Self, Self::Error> {
+                let raw = value.into_raw();
+                match raw.tag() {
+                    CompactLength::LENGTH_TAG => Ok(StyleValue::Number(raw.value())),
+                    CompactLength::PERCENT_TAG => Ok(StyleValue::Percent(raw.value())),
+                    CompactLength::AUTO_TAG => Ok(StyleValue::Auto),
+                    _ => Err(StyleFieldError::WrongType),
+                }
+            }
+        }
+    };
+}
+
+length_conversions!(LengthPercentage, None::<LengthPercentage>);
+length_conversions!(LengthPercentageAuto, Some(LengthPercentageAuto::auto()));
+length_conversions!(Dimension, Some(Dimension::auto()));
+
+/// Implements the conversions between `StyleValue` and a keyword enum, using the CSS name of each variant
+///
+/// The `"auto"` keyword is represented by [`StyleValue::Auto`].
+macro_rules! keyword_conversions {
+    ($ty:ident { $($(#[cfg($cfg:meta)])? $keyword:literal => $variant:ident,)* }) => {
+        impl TryFrom<StyleValue<'_>> for $ty {
+            type Error = StyleFieldError;
+            fn try_from(value: StyleValue<'_>) -> Result<Self, Self::Error> {
+                let value = if value == StyleValue::Auto { StyleValue::Keyword("auto") } else { value };
+                match value {
+                    $($(#[cfg($cfg)])? StyleValue::Keyword($keyword) => Ok($ty::$variant),)*
+                    StyleValue::Keyword(_) => Err(StyleFieldError::UnknownKeyword),
+                    _ => Err(StyleFieldError::WrongType),
+                }
+            }
+        }
+
+        impl From<$ty> for StyleValue<'static> {
+            fn from(value: $ty) -> Self {
+                StyleValue::from_keyword(match value {
+                    $($(#[cfg($cfg)])? $ty::$variant => $keyword,)*
+                })
+            }
+        }
+    };
+}
+
+/// Implements the conversions between `StyleValue` and an optional keyword enum, where `auto` corresponds to `None`
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+macro_rules! optional_keyword_conversions {
+    ($ty:ident) => {
+        impl TryFrom<StyleValue<'_>> for Option<$ty> {
+            type Error = StyleFieldError;
+            fn try_from(value: StyleValue<'_>) -> Result<Self, Self::Error> {
+                match value {
+                    StyleValue::Auto => Ok(None),
+                    value => Ok(Some(value.try_into()?)),
+                }
+            }
+        }
+
+        impl From<Option<$ty>> for StyleValue<'static> {
+            fn from(value: Option<$ty>) -> Self {
+                value.map(StyleValue::from).unwrap_or(StyleValue::Auto)
+            }
+        }
+    };
+}
+
+keyword_conversions!(Display {
+    #[cfg(feature = "block_layout")]
+    "block" => Block,
+    #[cfg(feature = "flexbox")]
+    "flex" => Flex,
+    #[cfg(feature = "grid")]
+    "grid" => Grid,
+    "contents" => Contents,
+    "none" => None,
+});
+keyword_conversions!(Position {
+    "relative" => Relative,
+    "absolute" => Absolute,
+});
+keyword_conversions!(BoxSizing {
+    "border-box" => BorderBox,
+    "content-box" => ContentBox,
+});
+keyword_conversions!(RoundingOrigin {
+    "inherit" => Inherit,
+    "node" => Node,
+});
+keyword_conversions!(Overflow {
+    "visible" => Visible,
+    "clip" => Clip,
+    "hidden" => Hidden,
+    "scroll" => Scroll,
+});
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+keyword_conversions!(AlignItems {
+    "start" => Start,
+    "end" => End,
+    "flex-start" => FlexStart,
+    "flex-end" => FlexEnd,
+    "center" => Center,
+    "baseline" => Baseline,
+    "stretch" => Stretch,
+});
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+keyword_conversions!(AlignContent {
+    "start" => Start,
+    "end" => End,
+    "flex-start" => FlexStart,
+    "flex-end" => FlexEnd,
+    "center" => Center,
+    "stretch" => Stretch,
+    "space-between" => SpaceBetween,
+    "space-evenly" => SpaceEvenly,
+    "space-around" => SpaceAround,
+});
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+optional_keyword_conversions!(AlignItems);
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+optional_keyword_conversions!(AlignContent);
+#[cfg(feature = "block_layout")]
+keyword_conversions!(TextAlign {
+    "auto" => Auto,
+    "-webkit-left" => LegacyLeft,
+    "-webkit-right" => LegacyRight,
+    "-webkit-center" => LegacyCenter,
+});
+#[cfg(feature = "flexbox")]
+keyword_conversions!(FlexDirection {
+    "row" => Row,
+    "column" => Column,
+    "row-reverse" => RowReverse,
+    "column-reverse" => ColumnReverse,
+});
+#[cfg(feature = "flexbox")]
+keyword_conversions!(FlexWrap {
+    "nowrap" => NoWrap,
+    "wrap" => Wrap,
+    "wrap-reverse" => WrapReverse,
+});
+#[cfg(feature = "flexbox")]
+keyword_conversions!(FlexGrowPrecision {
+    "standard" => Standard,
+    "exact" => Exact,
+});
+#[cfg(feature = "grid")]
+keyword_conversions!(GridAutoFlow {
+    "row" => Row,
+    "column" => Column,
+    "row dense" => RowDense,
+    "column dense" => ColumnDense,
+});
+
+impl<S: CheapCloneStr> Style<S> {
+    /// Sets the property called `name` from a dynamically typed value
+    ///
+    /// Properties which have a value for each edge or axis (such as `padding` or `size`) are set to `value` in every
+    /// edge or axis. Grid track lists, line names, areas and placements can not be set from a [`StyleValue`].
+    pub fn set_field_from_value(&mut self, name: &str, value: StyleValue<'_>) -> Result<(), StyleFieldError> {
+        fn rect<T: Copy>(value: T) -> Rect<T> {
+            Rect { left: value, right: value, top: value, bottom: value }
+        }
+        fn size<T: Copy>(value: T) -> Size<T> {
+            Size { width: value, height: value }
+        }
+
+        let field = match self.get_field(name).ok_or(StyleFieldError::UnknownField)? {
+            StyleFieldValue::Display(_) => StyleFieldValue::Display(value.try_into()?),
+            StyleFieldValue::Bool(_) => StyleFieldValue::Bool(value.try_into()?),
+            StyleFieldValue::BoxSizing(_) => StyleFieldValue::BoxSizing(value.try_into()?),
+            StyleFieldValue::Overflow(_) => {
+                let overflow: Overflow = value.try_into()?;
+                StyleFieldValue::Overflow(Point { x: overflow, y: overflow })
+            }
+            StyleFieldValue::Float(_) => StyleFieldValue::Float(value.try_into()?),
+            StyleFieldValue::OptionalFloat(_) => StyleFieldValue::OptionalFloat(value.try_into()?),
+            #[cfg(feature = "flexbox")]
+            StyleFieldValue::OptionalCount(_) => StyleFieldValue::OptionalCount(value.try_into()?),
+            StyleFieldValue::Position(_) => StyleFieldValue::Position(value.try_into()?),
+            StyleFieldValue::RoundingOrigin(_) => StyleFieldValue::RoundingOrigin(value.try_into()?),
+            StyleFieldValue::LengthPercentageAutoRect(_) => {
+                StyleFieldValue::LengthPercentageAutoRect(rect(LengthPercentageAuto::try_from(value)?))
+            }
+            StyleFieldValue::LengthPercentageRect(_) => {
+                StyleFieldValue::LengthPercentageRect(rect(LengthPercentage::try_from(value)?))
+            }
+            StyleFieldValue::FloatRect(_) => StyleFieldValue::FloatRect(rect(value.try_into()?)),
+            StyleFieldValue::DimensionSize(_) => StyleFieldValue::DimensionSize(size(Dimension::try_from(value)?)),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            StyleFieldValue::AlignItems(_) => StyleFieldValue::AlignItems(value.try_into()?),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            StyleFieldValue::AlignContent(_) => StyleFieldValue::AlignContent(value.try_into()?),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            StyleFieldValue::LengthPercentageSize(_) => {
+                StyleFieldValue::LengthPercentageSize(size(LengthPercentage::try_from(value)?))
+            }
+            #[cfg(feature = "block_layout")]
+            StyleFieldValue::TextAlign(_) => StyleFieldValue::TextAlign(value.try_into()?),
+            #[cfg(feature = "flexbox")]
+            StyleFieldValue::FlexDirection(_) => StyleFieldValue::FlexDirection(value.try_into()?),
+            #[cfg(feature = "flexbox")]
+            StyleFieldValue::FlexWrap(_) => StyleFieldValue::FlexWrap(value.try_into()?),
+            #[cfg(feature = "flexbox")]
+            StyleFieldValue::FlexGrowPrecision(_) => StyleFieldValue::FlexGrowPrecision(value.try_into()?),
+            #[cfg(feature = "flexbox")]
+            StyleFieldValue::Dimension(_) => StyleFieldValue::Dimension(value.try_into()?),
+            #[cfg(feature = "grid")]
+            StyleFieldValue::GridAutoFlow(_) => StyleFieldValue::GridAutoFlow(value.try_into()?),
+            #[cfg(feature = "grid")]
+            StyleFieldValue::GridTemplateComponents(_)
+            | StyleFieldValue::TrackSizingFunctions(_)
+            | StyleFieldValue::GridTemplateAreas(_)
+            | StyleFieldValue::GridLineNames(_)
+            | StyleFieldValue::GridPlacement(_) => return Err(StyleFieldError::WrongType),
+            StyleFieldValue::Dummy(_) => return Err(StyleFieldError::WrongType),
+        };
+        self.set_field(name, field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StyleValue;
+    use crate::geometry::Rect;
+    use crate::style::{AlignItems, Dimension, Display, LengthPercentage, Style, StyleFieldError, TextAlign};
+    use crate::style_helpers::{auto, length, percent};
+
+    #[test]
+    fn convert_to_and_from_typed_values() {
+        assert_eq!(Dimension::try_from(StyleValue::Percent(0.5)), Ok(percent(0.5)));
+        assert_eq!(Dimension::try_from(StyleValue::Auto), Ok(auto()));
+        assert_eq!(LengthPercentage::try_from(StyleValue::Auto), Err(StyleFieldError::WrongType));
+        assert_eq!(StyleValue::try_from(Dimension::length(3.0)), Ok(StyleValue::Number(3.0)));
+
+        assert_eq!(Display::try_from(StyleValue::Keyword("none")), Ok(Display::None));
+        assert_eq!(Display::try_from(StyleValue::Keyword("inline")), Err(StyleFieldError::UnknownKeyword));
+        assert_eq!(StyleValue::from(Some(AlignItems::FlexEnd)), StyleValue::Keyword("flex-end"));
+        assert_eq!(Option::<AlignItems>::try_from(StyleValue::Auto), Ok(None));
+        assert_eq!(TextAlign::try_from(StyleValue::Auto), Ok(TextAlign::Auto));
+
+        assert_eq!(Option::<u16>::try_from(StyleValue::Number(3.0)), Ok(Some(3)));
+        assert_eq!(Option::<u16>::try_from(StyleValue::Number(2.5)), Err(StyleFieldError::WrongType));
+        assert_eq!(Option::<u16>::try_from(StyleValue::Number(-1.0)), Err(StyleFieldError::WrongType));
+    }
+
+    #[test]
+    fn set_field_from_value() {
+        let mut style: Style = Style::DEFAULT;
+        style.set_field_from_value("display", StyleValue::Keyword("none")).unwrap();
+        style.set_field_from_value("margin", StyleValue::Auto).unwrap();
+        style.set_field_from_value("padding", StyleValue::Number(2.0)).unwrap();
+        style.set_field_from_value("aspect_ratio", StyleValue::Number(1.5)).unwrap();
+        style.set_field_from_value("hit_inset", StyleValue::Number(4.0)).unwrap();
+
+        assert_eq!(style.display, Display::None);
+        assert_eq!(style.margin, auto());
+        assert_eq!(style.padding, length(2.0));
+        assert_eq!(style.aspect_ratio, Some(1.5));
+        assert_eq!(style.hit_inset, Rect { left: 4.0, right: 4.0, top: 4.0, bottom: 4.0 });
+        assert_eq!(style.set_field_from_value("padding", StyleValue::Auto), Err(StyleFieldError::WrongType));
+    }
+}
@@ -8,8 +8,8 @@ use crate::{
 use crate::{
     geometry::MinMax,
     style::{
-        GridTemplateComponent, GridTemplateRepetition, MaxTrackSizingFunction, MinTrackSizingFunction, RepetitionCount,
-        TrackSizingFunction,
+        GridPlacement, GridTemplateComponent, GridTemplateRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+        RepetitionCount, TrackSizingFunction,
     },
     util::sys::Vec,
     CheapCloneStr,
@@ -66,6 +66,16 @@ pub trait TaffyGridSpan {
     fn from_span(span: u16) -> Self;
 }
 
+/// Places a grid item in the grid area called `name`, like `grid-area: name` in CSS. Use it for both `grid_row` and
+/// `grid_column`.
+///
+/// This resolves to the `name-start` and `name-end` lines, which each area in `grid_template_areas` defines.
+#[cfg(feature = "grid")]
+pub fn area<S: CheapCloneStr>(name: impl Into<S>) -> Line<GridPlacement<S>> {
+    let name = name.into();
+    Line { start: GridPlacement::NamedLine(name.clone(), 0), end: GridPlacement::NamedLine(name, 0) }
+}
+
 /// Returns a MinMax with min value of min and max value of max
 #[cfg(feature = "grid")]
 pub fn minmax<Output>(min: MinTrackSizingFunction, max: MaxTrackSizingFunction) -> Output
@@ -515,6 +525,39 @@ impl<T: FromPercent> Rect<T> {
     }
 }
 
+/// Returns the [`aspect_ratio`](crate::Style::aspect_ratio) which makes the height of a node the given fraction of its own width
+///
+/// As with [`percent`], `1.0` is 100% (so `height_from_width(0.5)` makes a node half as tall as it is wide). The
+/// height is only derived from the width if it is not otherwise determined, so leave the node's height as `auto`.
+/// Returns `None` (no aspect ratio) unless `fraction` is positive.
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// use taffy::style_helpers::height_from_width;
+///
+/// let banner: Style = Style {
+///     size: Size { width: length(200.0), height: auto() },
+///     aspect_ratio: height_from_width(0.5),
+///     ..Default::default()
+/// };
+/// ```
+pub fn height_from_width(fraction: f32) -> Option<f32> {
+    (fraction > 0.0).then(|| 1.0 / fraction)
+}
+
+/// Returns the [`aspect_ratio`](crate::Style::aspect_ratio) which makes the width of a node the given fraction of its own height
+///
+/// As with [`percent`], `1.0` is 100%. The width is only derived from the height if it is not otherwise determined, so
+/// leave the node's width as `auto`. Returns `None` (no aspect ratio) unless `fraction` is positive.
+pub fn width_from_height(fraction: f32) -> Option<f32> {
+    (fraction > 0.0).then_some(fraction)
+}
+
+/// Returns the [`aspect_ratio`](crate::Style::aspect_ratio) of a square, for nodes whose width and height are equal
+pub const fn square() -> Option<f32> {
+    Some(1.0)
+}
+
 /// Create a `Fraction` track sizing function (`fr` in CSS)
 #[cfg(feature = "grid")]
 pub fn fr<Input: Into<f32> + Copy, T: FromFr>(flex: Input) -> T {
@@ -527,6 +570,50 @@ pub trait FromFr {
     fn from_fr<Input: Into<f32> + Copy>(flex: Input) -> Self;
 }
 
+#[cfg(all(test, feature = "taffy_tree", feature = "flexbox"))]
+mod own_size_tests {
+    use super::{height_from_width, square, width_from_height};
+    use crate::prelude::*;
+
+    #[test]
+    fn one_axis_is_sized_from_the_other() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let banner = taffy
+            .new_leaf(Style {
+                size: Size { width: length(200.0), height: auto() },
+                aspect_ratio: height_from_width(0.25),
+                ..Default::default()
+            })
+            .unwrap();
+        let pillar = taffy
+            .new_leaf(Style {
+                size: Size { width: auto(), height: length(80.0) },
+                aspect_ratio: width_from_height(0.5),
+                ..Default::default()
+            })
+            .unwrap();
+        let tile = taffy
+            .new_leaf(Style {
+                size: Size { width: length(30.0), height: auto() },
+                aspect_ratio: square(),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { align_items: Some(AlignItems::Start), ..Default::default() },
+                &[banner, pillar, tile],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(banner).unwrap().size, Size { width: 200.0, height: 50.0 });
+        assert_eq!(taffy.layout(pillar).unwrap().size, Size { width: 40.0, height: 80.0 });
+        assert_eq!(taffy.layout(tile).unwrap().size, Size { width: 30.0, height: 30.0 });
+        assert_eq!(height_from_width(0.0), None);
+    }
+}
+
 #[cfg(feature = "grid")]
 #[cfg(test)]
 mod repeat_fn_tests {
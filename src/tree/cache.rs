@@ -2,34 +2,116 @@
 use crate::geometry::Size;
 use crate::style::AvailableSpace;
 use crate::tree::{LayoutOutput, RunMode};
+use crate::util::sys::Box;
 
-/// The number of cache entries for each node in the tree
-const CACHE_SIZE: usize = 9;
+/// The number of cache slots chosen by [`Cache::compute_cache_slot`]
+const CACHE_SIZE: usize = 8;
+
+/// The number of cache slots in which exactly one of the known dimensions is set (slots 1-4)
+const ONE_KNOWN_DIMENSION_SLOTS: usize = 4;
+
+/// The index of the first measure entry which holds the entry most recently replaced in slot 1 (followed by those of
+/// slots 2-4)
+const PREVIOUS_ENTRIES_START: usize = CACHE_SIZE;
+
+/// The index of the first measure entry which holds an intrinsic size (see [`Cache::intrinsic_size_index`])
+const INTRINSIC_ENTRIES_START: usize = PREVIOUS_ENTRIES_START + ONE_KNOWN_DIMENSION_SLOTS;
+
+/// The total number of measure entries of a node
+const MEASURE_ENTRIES: usize = INTRINSIC_ENTRIES_START + 4;
+
+/// The cache entries for a node's size measurements: the slots chosen by [`Cache::compute_cache_slot`], followed by
+/// the previous entries of slots 1-4 and then the intrinsic sizes
+type MeasureEntries = [Option<CacheEntry<Size<f32>>>; MEASURE_ENTRIES];
+
+/// The input a cache entry was computed with in one axis
+///
+/// The available space is only stored when no dimension is known, as it is ignored when matching an entry whose
+/// dimension was known (see [`CacheEntry::matches`]).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+enum AxisInput {
+    /// The known dimension of the node
+    Known(f32),
+    /// The available space of the node, as the dimension wasn't known
+    Available(AvailableSpace),
+}
+
+impl AxisInput {
+    /// The input in an axis with the given known dimension and available space
+    #[inline]
+    fn new(known_dimension: Option<f32>, available_space: AvailableSpace) -> Self {
+        match known_dimension {
+            Some(known_dimension) => Self::Known(known_dimension),
+            None => Self::Available(available_space),
+        }
+    }
+
+    /// Whether an entry computed with this input, which produced a size of `cached_size` in this axis, can be reused
+    /// for the given known dimension and available space
+    #[inline]
+    fn matches(self, known_dimension: Option<f32>, available_space: AvailableSpace, cached_size: f32) -> bool {
+        match (known_dimension, self) {
+            (Some(known_dimension), _) => self == Self::Known(known_dimension) || known_dimension == cached_size,
+            (None, Self::Available(entry_available_space)) => entry_available_space.is_roughly_equal(available_space),
+            (None, Self::Known(_)) => false,
+        }
+    }
+}
 
 /// Cached intermediate layout results
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub(crate) struct CacheEntry<T> {
-    /// The initial cached size of the node itself
-    known_dimensions: Size<Option<f32>>,
-    /// The initial cached size of the parent's node
-    available_space: Size<AvailableSpace>,
+    /// The inputs the entry was computed with, in each axis
+    inputs: Size<AxisInput>,
     /// The cached size and baselines of the item
     content: T,
 }
 
 /// A cache for caching the results of a sizing a Grid Item or Flexbox Item
+///
+/// This is the cache used by [`TaffyTree`](crate::TaffyTree). It can also be stored on the nodes of a custom tree
+/// to give [`CacheTree`](crate::CacheTree) implementations the same caching behaviour as the built-in tree.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Cache {
     /// The cache entry for the node's final layout
     final_layout_entry: Option<CacheEntry<LayoutOutput>>,
     /// The cache entries for the node's preliminary size measurements
-    measure_entries: [Option<CacheEntry<Size<f32>>>; CACHE_SIZE],
+    ///
+    /// These are only allocated once the node is first measured, as many nodes never are (or are never laid out
+    /// at all). Once allocated they are kept when the cache is cleared.
+    measure_entries: Option<Box<MeasureEntries>>,
     /// Tracks if all cache entries are empty
     is_empty: bool,
 }
 
+impl<T> CacheEntry<T> {
+    /// An entry for a result computed with the given inputs
+    #[inline]
+    fn new(known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>, content: T) -> Self {
+        let inputs = Size {
+            width: AxisInput::new(known_dimensions.width, available_space.width),
+            height: AxisInput::new(known_dimensions.height, available_space.height),
+        };
+        Self { inputs, content }
+    }
+
+    /// Whether this entry, which produced a node size of `cached_size`, can be reused for the given inputs.
+    /// See [`Cache::matches`].
+    #[inline]
+    fn matches(
+        &self,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        cached_size: Size<f32>,
+    ) -> bool {
+        self.inputs.width.matches(known_dimensions.width, available_space.width, cached_size.width)
+            && self.inputs.height.matches(known_dimensions.height, available_space.height, cached_size.height)
+    }
+}
+
 impl Default for Cache {
     fn default() -> Self {
         Self::new()
@@ -39,7 +121,7 @@ impl Default for Cache {
 impl Cache {
     /// Create a new empty cache
     pub const fn new() -> Self {
-        Self { final_layout_entry: None, measure_entries: [None; CACHE_SIZE], is_empty: true }
+        Self { final_layout_entry: None, measure_entries: None, is_empty: true }
     }
 
     /// Return the cache slot to cache the current computed result in
@@ -64,11 +146,19 @@ impl Cache {
     ///   - Slot 2: width but not height known_dimension was set and the other dimension was a MinContent constraint
     ///   - Slot 3: height but not width known_dimension was set and the other dimension was either a MaxContent or Definite available space constraintable space constraint
     ///   - Slot 4: height but not width known_dimension was set and the other dimension was a MinContent constraint
-    /// - Slots 5-8: Neither known_dimensions were set and:
+    /// - Slots 5-7: Neither known_dimensions were set and at least one axis has a Definite available space constraint:
     ///   - Slot 5: x-axis available space is MaxContent or Definite and y-axis available space is MaxContent or Definite
-    ///   - Slot 6: x-axis available space is MaxContent or Definite and y-axis available space is MinContent
-    ///   - Slot 7: x-axis available space is MinContent and y-axis available space is MaxContent or Definite
-    ///   - Slot 8: x-axis available space is MinContent and y-axis available space is MinContent
+    ///   - Slot 6: x-axis available space is Definite and y-axis available space is MinContent
+    ///   - Slot 7: x-axis available space is MinContent and y-axis available space is Definite
+    ///
+    /// Sizes computed with neither known dimension set and no Definite available space are the node's intrinsic sizes.
+    /// These don't use a slot: they are stored separately (see [`Cache::intrinsic_size_index`]) so that they are not
+    /// evicted by sizing the node under a definite constraint.
+    ///
+    /// Slots 1-4 each keep the entry they held before the most recent one as well. A container whose children flow in
+    /// the opposite axis to it (e.g. a column inside a row) is asked for its size in one axis given a size in the other
+    /// for both the hypothetical and the final size of the other axis, and alternating between two entries would
+    /// otherwise mean laying out its whole subtree again on each query.
     #[inline]
     fn compute_cache_slot(known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>) -> usize {
         use AvailableSpace::{Definite, MaxContent, MinContent};
@@ -93,20 +183,62 @@ impl Cache {
             return 3 + (available_space.width == MinContent) as usize;
         }
 
-        // Slots 5-8: Neither known_dimensions were set and:
+        // Slots 5-7: Neither known_dimensions were set and:
         match (available_space.width, available_space.height) {
+            // Slot 6: x-axis available space is Definite and y-axis available space is MinContent
+            (_, MinContent) => 6,
+            // Slot 7: x-axis available space is MinContent and y-axis available space is Definite
+            (MinContent, _) => 7,
             // Slot 5: x-axis available space is MaxContent or Definite and y-axis available space is MaxContent or Definite
             (MaxContent | Definite(_), MaxContent | Definite(_)) => 5,
-            // Slot 6: x-axis available space is MaxContent or Definite and y-axis available space is MinContent
-            (MaxContent | Definite(_), MinContent) => 6,
-            // Slot 7: x-axis available space is MinContent and y-axis available space is MaxContent or Definite
-            (MinContent, MaxContent | Definite(_)) => 7,
-            // Slot 8: x-axis available space is MinContent and y-axis available space is MinContent
-            (MinContent, MinContent) => 8,
         }
     }
 
+    /// Return the index of the intrinsic size entry to cache the current computed result in, or `None` if the result
+    /// is not an intrinsic size (because a known dimension or a definite available space was given)
+    #[inline]
+    fn intrinsic_size_index(
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> Option<usize> {
+        use AvailableSpace::{MaxContent, MinContent};
+
+        match (known_dimensions, available_space.width, available_space.height) {
+            (
+                Size { width: None, height: None },
+                width @ (MinContent | MaxContent),
+                height @ (MinContent | MaxContent),
+            ) => Some(2 * (width == MinContent) as usize + (height == MinContent) as usize),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the cache contains an entry which can be reused for the given inputs
+    ///
+    /// An entry computed for `entry_known_dimensions` and `entry_available_space` which produced a size of
+    /// `cached_size` matches the requested `known_dimensions` and `available_space` if, in each axis:
+    ///
+    ///   - The requested known dimension is the same as the entry's known dimension, or is equal to the size
+    ///     that was computed for the entry (requesting the size a node already has produces the same result).
+    ///   - If the requested known dimension is not set, the available space is roughly equal to the
+    ///     entry's available space (see [`AvailableSpace::is_roughly_equal`]).
+    ///
+    /// In [`RunMode::PerformLayout`] only the entry for the node's final layout is considered, and in
+    /// [`RunMode::ComputeSize`] any of the entries for the node's size measurements may match.
+    /// [`RunMode::PerformHiddenLayout`] never matches. This is the rule used by [`Cache::get`].
+    #[inline]
+    pub fn matches(
+        &self,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+    ) -> bool {
+        self.get(known_dimensions, available_space, run_mode).is_some()
+    }
+
     /// Try to retrieve a cached result from the cache
+    ///
+    /// See [`Cache::matches`] for the rules which determine whether a cached result can be reused.
     #[inline]
     pub fn get(
         &self,
@@ -117,37 +249,15 @@ impl Cache {
         match run_mode {
             RunMode::PerformLayout => self
                 .final_layout_entry
-                .filter(|entry| {
-                    let cached_size = entry.content.size;
-                    (known_dimensions.width == entry.known_dimensions.width
-                        || known_dimensions.width == Some(cached_size.width))
-                        && (known_dimensions.height == entry.known_dimensions.height
-                            || known_dimensions.height == Some(cached_size.height))
-                        && (known_dimensions.width.is_some()
-                            || entry.available_space.width.is_roughly_equal(available_space.width))
-                        && (known_dimensions.height.is_some()
-                            || entry.available_space.height.is_roughly_equal(available_space.height))
-                })
+                .filter(|entry| entry.matches(known_dimensions, available_space, entry.content.size))
                 .map(|e| e.content),
-            RunMode::ComputeSize => {
-                for entry in self.measure_entries.iter().flatten() {
-                    let cached_size = entry.content;
-
-                    if (known_dimensions.width == entry.known_dimensions.width
-                        || known_dimensions.width == Some(cached_size.width))
-                        && (known_dimensions.height == entry.known_dimensions.height
-                            || known_dimensions.height == Some(cached_size.height))
-                        && (known_dimensions.width.is_some()
-                            || entry.available_space.width.is_roughly_equal(available_space.width))
-                        && (known_dimensions.height.is_some()
-                            || entry.available_space.height.is_roughly_equal(available_space.height))
-                    {
-                        return Some(LayoutOutput::from_outer_size(cached_size));
-                    }
-                }
-
-                None
-            }
+            RunMode::ComputeSize => self
+                .measure_entries
+                .as_deref()?
+                .iter()
+                .flatten()
+                .find(|entry| entry.matches(known_dimensions, available_space, entry.content))
+                .map(|entry| LayoutOutput::from_outer_size(entry.content)),
             RunMode::PerformHiddenLayout => None,
         }
     }
@@ -163,18 +273,37 @@ impl Cache {
         match run_mode {
             RunMode::PerformLayout => {
                 self.is_empty = false;
-                self.final_layout_entry = Some(CacheEntry { known_dimensions, available_space, content: layout_output })
+                self.final_layout_entry = Some(CacheEntry::new(known_dimensions, available_space, layout_output))
             }
             RunMode::ComputeSize => {
                 self.is_empty = false;
+                let entries = self.measure_entries.get_or_insert_with(|| Box::new([None; MEASURE_ENTRIES]));
+                let entry = Some(CacheEntry::new(known_dimensions, available_space, layout_output.size));
+                if let Some(index) = Self::intrinsic_size_index(known_dimensions, available_space) {
+                    entries[INTRINSIC_ENTRIES_START + index] = entry;
+                    return;
+                }
                 let cache_slot = Self::compute_cache_slot(known_dimensions, available_space);
-                self.measure_entries[cache_slot] =
-                    Some(CacheEntry { known_dimensions, available_space, content: layout_output.size });
+                let previous_entry = core::mem::replace(&mut entries[cache_slot], entry);
+                if (1..=ONE_KNOWN_DIMENSION_SLOTS).contains(&cache_slot) {
+                    entries[PREVIOUS_ENTRIES_START + cache_slot - 1] = previous_entry;
+                }
             }
             RunMode::PerformHiddenLayout => {}
         }
     }
 
+    /// The entry for the node's final layout, which can be put back with [`Cache::set_final_layout_entry`]
+    pub(crate) fn final_layout_entry(&self) -> Option<CacheEntry<LayoutOutput>> {
+        self.final_layout_entry
+    }
+
+    /// Replace the entry for the node's final layout with one returned by [`Cache::final_layout_entry`]
+    pub(crate) fn set_final_layout_entry(&mut self, entry: Option<CacheEntry<LayoutOutput>>) {
+        self.is_empty &= entry.is_none();
+        self.final_layout_entry = entry;
+    }
+
     /// Clear all cache entries and reports clear operation outcome ([`ClearState`])
     pub fn clear(&mut self) -> ClearState {
         if self.is_empty {
@@ -182,13 +311,16 @@ impl Cache {
         }
         self.is_empty = true;
         self.final_layout_entry = None;
-        self.measure_entries = [None; CACHE_SIZE];
+        if let Some(entries) = &mut self.measure_entries {
+            **entries = [None; MEASURE_ENTRIES];
+        }
         ClearState::Cleared
     }
 
     /// Returns true if all cache entries are None, else false
     pub fn is_empty(&self) -> bool {
-        self.final_layout_entry.is_none() && !self.measure_entries.iter().any(|entry| entry.is_some())
+        self.final_layout_entry.is_none()
+            && self.measure_entries.as_deref().map_or(true, |entries| entries.iter().all(Option::is_none))
     }
 }
 
@@ -199,3 +331,80 @@ pub enum ClearState {
     /// Everything was already cleared
     AlreadyEmpty,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Cache;
+    use crate::geometry::Size;
+    use crate::style::AvailableSpace;
+    use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
+    use crate::tree::{LayoutOutput, RunMode};
+
+    #[test]
+    fn matches_computed_size_as_known_dimension() {
+        let mut cache = Cache::new();
+        let output = LayoutOutput::from_outer_size(Size { width: 50.0, height: 20.0 });
+        cache.store(Size::NONE, Size::MAX_CONTENT, RunMode::ComputeSize, output);
+
+        assert!(cache.matches(Size::NONE, Size::MAX_CONTENT, RunMode::ComputeSize));
+        assert!(cache.matches(Size { width: Some(50.0), height: None }, Size::MAX_CONTENT, RunMode::ComputeSize));
+        assert!(!cache.matches(Size { width: Some(40.0), height: None }, Size::MAX_CONTENT, RunMode::ComputeSize));
+        assert!(!cache.matches(Size::NONE, Size::MIN_CONTENT, RunMode::ComputeSize));
+        assert!(!cache.matches(Size::NONE, Size::MAX_CONTENT, RunMode::PerformLayout));
+    }
+
+    #[test]
+    fn keeps_previous_entry_when_one_dimension_is_known() {
+        let mut cache = Cache::new();
+        let narrow = Size { width: Some(50.0), height: None };
+        let wide = Size { width: Some(100.0), height: None };
+        let output = |width| LayoutOutput::from_outer_size(Size { width, height: 20.0 });
+        cache.store(narrow, Size::MAX_CONTENT, RunMode::ComputeSize, output(50.0));
+        cache.store(wide, Size::MAX_CONTENT, RunMode::ComputeSize, output(100.0));
+
+        assert!(cache.matches(narrow, Size::MAX_CONTENT, RunMode::ComputeSize));
+        assert!(cache.matches(wide, Size::MAX_CONTENT, RunMode::ComputeSize));
+
+        let widest = Size { width: Some(150.0), height: None };
+        cache.store(widest, Size::MAX_CONTENT, RunMode::ComputeSize, output(150.0));
+        assert!(!cache.matches(narrow, Size::MAX_CONTENT, RunMode::ComputeSize));
+    }
+
+    #[test]
+    fn intrinsic_sizes_are_not_evicted_by_definite_sizes() {
+        let mut cache = Cache::new();
+        let definite = Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::MaxContent };
+        cache.store(Size::NONE, Size::MAX_CONTENT, RunMode::ComputeSize, LayoutOutput::from_outer_size(Size::ZERO));
+        cache.store(Size::NONE, Size::MIN_CONTENT, RunMode::ComputeSize, LayoutOutput::from_outer_size(Size::ZERO));
+        cache.store(Size::NONE, definite, RunMode::ComputeSize, LayoutOutput::from_outer_size(Size::ZERO));
+
+        assert!(cache.matches(Size::NONE, Size::MAX_CONTENT, RunMode::ComputeSize));
+        assert!(cache.matches(Size::NONE, Size::MIN_CONTENT, RunMode::ComputeSize));
+        assert!(cache.matches(Size::NONE, definite, RunMode::ComputeSize));
+    }
+
+    #[test]
+    fn hidden_layout_never_matches() {
+        let mut cache = Cache::new();
+        let available_space = Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::Definite(100.0) };
+        cache.store(Size::NONE, available_space, RunMode::PerformLayout, LayoutOutput::HIDDEN);
+
+        assert!(cache.matches(Size::NONE, available_space, RunMode::PerformLayout));
+        assert!(!cache.matches(Size::NONE, available_space, RunMode::PerformHiddenLayout));
+    }
+
+    #[test]
+    fn measure_entries_are_allocated_when_first_measured() {
+        let mut cache = Cache::new();
+        cache.store(Size::NONE, Size::MAX_CONTENT, RunMode::PerformLayout, LayoutOutput::HIDDEN);
+        assert!(cache.measure_entries.is_none());
+
+        cache.store(Size::NONE, Size::MAX_CONTENT, RunMode::ComputeSize, LayoutOutput::HIDDEN);
+        assert!(cache.measure_entries.is_some());
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert!(cache.measure_entries.is_some());
+        assert!(!cache.matches(Size::NONE, Size::MAX_CONTENT, RunMode::ComputeSize));
+    }
+}
@@ -0,0 +1,318 @@
+//! Capture a [`TaffyTree`] into a JSON fixture that can be saved and loaded again to reproduce a layout
+use crate::geometry::Size;
+use crate::style::{AvailableSpace, ResponsiveStyle, Style};
+use crate::tree::taffy_tree::TaffyConfig;
+use crate::tree::{LeafFallbackSize, NodeId, NodeKey, TaffyError, TaffyResult, TaffyTree};
+use crate::util::sys::{String, Vec};
+
+/// A node of a [`CapturedTree`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CapturedNode {
+    /// The style of the node
+    pub style: Style,
+    /// The responsive style of the node (see [`TaffyTree::set_responsive_style`])
+    #[serde(default)]
+    pub responsive_style: Option<ResponsiveStyle>,
+    /// The container query of the node (see [`TaffyTree::set_container_query`])
+    #[serde(default)]
+    pub container_query: Option<ResponsiveStyle>,
+    /// The static intrinsic size of the node (see [`TaffyTree::set_intrinsic_size`])
+    pub intrinsic_size: Size<Option<f32>>,
+    /// The indexes of the node's children in [`CapturedTree::nodes`]
+    pub children: Vec<usize>,
+    /// The persistent key of the node (see [`TaffyTree::set_node_key`]), which is given to the node again on load
+    #[serde(default)]
+    pub key: Option<NodeKey>,
+}
+
+/// The settings of a [`TaffyTree`] which affect how it is laid out, as captured in a [`CapturedTree`]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CapturedConfig {
+    /// Whether layout values are rounded (see [`TaffyTree::enable_rounding`])
+    pub use_rounding: bool,
+    /// How leaf nodes without a node context or intrinsic size are sized (see [`TaffyTree::set_leaf_fallback_size`])
+    pub leaf_fallback_size: LeafFallbackSize,
+    /// Whether containers resolve the percentage padding and border of their items against their width in both axes
+    /// (see [`TaffyTree::enable_inline_percentage_padding`])
+    pub inline_percentage_padding: bool,
+    /// The maximum number of layout passes used to resolve container queries
+    /// (see [`TaffyTree::set_max_container_query_passes`])
+    pub max_container_query_passes: usize,
+    /// Whether orphaned nodes are reported as warnings (see [`TaffyTree::enable_orphan_warnings`])
+    pub warn_about_orphans: bool,
+}
+
+impl Default for CapturedConfig {
+    fn default() -> Self {
+        TaffyConfig::default().into()
+    }
+}
+
+impl From<TaffyConfig> for CapturedConfig {
+    fn from(config: TaffyConfig) -> Self {
+        let TaffyConfig {
+            use_rounding,
+            leaf_fallback_size,
+            max_container_query_passes,
+            warn_about_orphans,
+            inline_percentage_padding,
+        } = config;
+        Self {
+            use_rounding,
+            leaf_fallback_size,
+            inline_percentage_padding,
+            max_container_query_passes,
+            warn_about_orphans,
+        }
+    }
+}
+
+/// A snapshot of the styles and hierarchy of a subtree of a [`TaffyTree`], along with the available space it was
+/// laid out in and the settings of the tree
+///
+/// This is intended for reproducing layouts outside of the application that produced them (e.g. when reporting a bug).
+/// [`TaffyTree::capture`] saves a subtree as a JSON fixture, which can be attached to a bug report and loaded again
+/// elsewhere with [`CapturedTree::parse`] and [`CapturedTree::load`].
+///
+/// Node contexts are not captured, as the measure function that uses them is not part of the tree. To reproduce the
+/// size of measured leaf nodes, give them an intrinsic size using [`TaffyTree::set_intrinsic_size`] before capturing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CapturedTree {
+    /// The available space passed to `compute_layout` for the root node
+    pub available_space: Size<AvailableSpace>,
+    /// The settings of the tree which affect how it is laid out
+    #[serde(default)]
+    pub config: CapturedConfig,
+    /// The nodes of the tree in depth-first order. The root node is always the first node.
+    pub nodes: Vec<CapturedNode>,
+}
+
+impl<NodeContext> TaffyTree<NodeContext> {
+    /// Captures the styles and hierarchy of `root` and its descendants as a JSON fixture, from which the layout can
+    /// be reproduced elsewhere using [`CapturedTree::parse`] and [`CapturedTree::load`]
+    pub fn capture(&self, root: NodeId, available_space: Size<AvailableSpace>) -> TaffyResult<String> {
+        let mut nodes: Vec<CapturedNode> = Vec::new();
+
+        // Each node is captured before its descendants, and the children of a node are pushed in reverse so that
+        // they're captured in order
+        let mut stack: Vec<(NodeId, Option<usize>)> = Vec::from([(root, None)]);
+        while let Some((node, parent)) = stack.pop() {
+            let index = nodes.len();
+            nodes.push(CapturedNode {
+                style: self.style(node)?.clone(),
+                responsive_style: self.responsive_style(node).cloned(),
+                container_query: self.container_query(node).cloned(),
+                intrinsic_size: self.intrinsic_size(node)?,
+                children: Vec::new(),
+                key: self.node_key(node)?,
+            });
+            if let Some(parent) = parent {
+                nodes[parent].children.push(index);
+            }
+            stack.extend(self.children(node)?.into_iter().rev().map(|child| (child, Some(index))));
+        }
+
+        let captured = CapturedTree { available_space, config: (*self.config()).into(), nodes };
+        Ok(serde_json::to_string_pretty(&captured).expect("captured trees can always be serialized"))
+    }
+}
+
+impl CapturedTree {
+    /// Parses a fixture saved by [`TaffyTree::capture`]
+    ///
+    /// Fails with [`TaffyError::InvalidCapture`] if the fixture isn't a valid captured tree.
+    pub fn parse(fixture: &str) -> TaffyResult<Self> {
+        serde_json::from_str(fixture).map_err(|error| TaffyError::InvalidCapture(error.to_string()))
+    }
+
+    /// Rebuilds the captured tree, returning the new tree and its root node
+    ///
+    /// The new tree has the captured [`CapturedTree::config`]. The layout is not computed. Pass
+    /// [`CapturedTree::available_space`] to [`TaffyTree::compute_layout`] to reproduce the captured layout.
+    ///
+    /// Nodes which were captured with a [`NodeKey`] can be found in the new tree using [`TaffyTree::node_by_key`].
+    /// Fails with [`TaffyError::DuplicateNodeKey`] if two nodes have the same key, and with
+    /// [`TaffyError::InvalidCapturedNode`] if the child indexes of the captured nodes do not describe a tree rooted at
+    /// the first node.
+    pub fn load(&self) -> TaffyResult<(TaffyTree, NodeId)> {
+        let mut tree = TaffyTree::with_capacity(self.nodes.len());
+        let CapturedConfig {
+            use_rounding,
+            leaf_fallback_size,
+            inline_percentage_padding,
+            max_container_query_passes,
+            warn_about_orphans,
+        } = self.config;
+        if !use_rounding {
+            tree.disable_rounding();
+        }
+        tree.set_leaf_fallback_size(leaf_fallback_size);
+        if !inline_percentage_padding {
+            tree.disable_inline_percentage_padding();
+        }
+        tree.set_max_container_query_passes(max_container_query_passes);
+        if warn_about_orphans {
+            tree.enable_orphan_warnings();
+        }
+
+        // Children always come after their parent in depth-first order, so build the nodes in reverse
+        let mut ids: Vec<Option<NodeId>> = self.nodes.iter().map(|_| None).collect();
+        for (index, node) in self.nodes.iter().enumerate().rev() {
+            let mut children = Vec::with_capacity(node.children.len());
+            for &child in &node.children {
+                // The child must already have been built, and must not have been given to another parent
+                match ids.get(child).copied().flatten() {
+                    Some(child) if tree.parent(child).is_none() && !children.contains(&child) => children.push(child),
+                    _ => return Err(TaffyError::InvalidCapturedNode(index)),
+                }
+            }
+            let id = tree.new_with_children(node.style.clone(), &children)?;
+            tree.set_intrinsic_size(id, node.intrinsic_size)?;
+            tree.set_node_key(id, node.key)?;
+            tree.set_responsive_style(id, node.responsive_style.clone())?;
+            tree.set_container_query(id, node.container_query.clone())?;
+            ids[index] = Some(id);
+        }
+
+        // Every node other than the root must be the child of another node
+        let root = ids.first().copied().flatten().ok_or(TaffyError::InvalidCapturedNode(0))?;
+        if let Some(index) = ids.iter().skip(1).position(|&id| id.and_then(|id| tree.parent(id)).is_none()) {
+            return Err(TaffyError::InvalidCapturedNode(index + 1));
+        }
+        Ok((tree, root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CapturedConfig, CapturedNode, CapturedTree};
+    use crate::prelude::*;
+    use crate::style::{PartialStyle, ResponsiveStyle};
+    use crate::tree::{LeafFallbackSize, NodeKey, TaffyError};
+
+    #[test]
+    fn load_reproduces_captured_layout() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap();
+        taffy.set_intrinsic_size(leaf, Size { width: Some(30.0), height: Some(10.0) }).unwrap();
+        let fixed = taffy.new_leaf(Style { size: Size::from_lengths(20.0, 20.0), ..Default::default() }).unwrap();
+        let inner = taffy.new_with_children(Style { padding: length(5.0), ..Default::default() }, &[fixed]).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_lengths(200.0, 50.0), ..Default::default() }, &[leaf, inner])
+            .unwrap();
+        let available_space = Size::MAX_CONTENT;
+        taffy.compute_layout(root, available_space).unwrap();
+
+        taffy.set_node_key(inner, Some(NodeKey(7))).unwrap();
+        let captured = CapturedTree::parse(&taffy.capture(root, available_space).unwrap()).unwrap();
+        assert_eq!(captured.nodes.len(), 4);
+        assert_eq!(captured.nodes[0].children, vec![1, 2]);
+        assert_eq!(captured.nodes[2].children, vec![3]);
+
+        let (mut loaded, loaded_root) = captured.load().unwrap();
+        loaded.compute_layout(loaded_root, captured.available_space).unwrap();
+        assert_eq!(loaded.node_by_key(NodeKey(7)), Some(descendants(&loaded, loaded_root)[2]));
+        let original_nodes = [root, leaf, inner, fixed];
+        for (original, loaded_node) in original_nodes.into_iter().zip(descendants(&loaded, loaded_root)) {
+            assert_eq!(taffy.layout(original).unwrap(), loaded.layout(loaded_node).unwrap());
+        }
+    }
+
+    #[test]
+    fn load_restores_the_captured_config() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        taffy.disable_rounding();
+        taffy.set_leaf_fallback_size(LeafFallbackSize::Stretch);
+        taffy.disable_inline_percentage_padding();
+        taffy.set_max_container_query_passes(2);
+        taffy.enable_orphan_warnings();
+        let leaf = taffy.new_leaf(Style { padding: percent(0.1), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_lengths(100.5, 50.0), ..Default::default() }, &[leaf])
+            .unwrap();
+        let available_space = Size::MAX_CONTENT;
+        taffy.compute_layout(root, available_space).unwrap();
+
+        let captured = CapturedTree::parse(&taffy.capture(root, available_space).unwrap()).unwrap();
+        assert_eq!(captured.config, (*taffy.config()).into());
+        let (mut loaded, loaded_root) = captured.load().unwrap();
+        assert_eq!(CapturedConfig::from(*loaded.config()), captured.config);
+
+        loaded.compute_layout(loaded_root, available_space).unwrap();
+        let loaded_leaf = loaded.child_at_index(loaded_root, 0).unwrap();
+        assert_eq!(taffy.layout(leaf).unwrap(), loaded.layout(loaded_leaf).unwrap());
+    }
+
+    #[test]
+    fn load_restores_responsive_styles_and_container_queries() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let column = PartialStyle { flex_direction: Some(FlexDirection::Column), ..Default::default() };
+        let responsive_style =
+            ResponsiveStyle::new(Style { size: Size::from_lengths(200.0, 50.0), ..Default::default() })
+                .with_breakpoint(None, Some(300.0), column);
+        let sized = PartialStyle { size: Some(Size::from_lengths(10.0, 10.0)), ..Default::default() };
+        let container_query = ResponsiveStyle::new(Style::default()).with_breakpoint(Some(100.0), None, sized);
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        taffy.set_container_query(leaf, Some(container_query.clone())).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        taffy.set_responsive_style(root, Some(responsive_style.clone())).unwrap();
+        let available_space = Size { width: AvailableSpace::Definite(200.0), height: AvailableSpace::MaxContent };
+        taffy.compute_layout(root, available_space).unwrap();
+
+        let (mut loaded, loaded_root) =
+            CapturedTree::parse(&taffy.capture(root, available_space).unwrap()).unwrap().load().unwrap();
+        let loaded_leaf = loaded.child_at_index(loaded_root, 0).unwrap();
+        assert_eq!(loaded.responsive_style(loaded_root), Some(&responsive_style));
+        assert_eq!(loaded.container_query(loaded_leaf), Some(&container_query));
+
+        loaded.compute_layout(loaded_root, available_space).unwrap();
+        assert_eq!(loaded.style(loaded_root).unwrap().flex_direction, FlexDirection::Column);
+        assert_eq!(taffy.layout(leaf).unwrap(), loaded.layout(loaded_leaf).unwrap());
+        assert_eq!(loaded.layout(loaded_leaf).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn parse_rejects_invalid_fixtures() {
+        assert!(matches!(CapturedTree::parse("{}"), Err(TaffyError::InvalidCapture(_))));
+        assert!(matches!(CapturedTree::parse("not json"), Err(TaffyError::InvalidCapture(_))));
+    }
+
+    #[test]
+    fn load_rejects_invalid_child_indexes() {
+        let node = |children: Vec<usize>| CapturedNode {
+            style: Style::default(),
+            responsive_style: None,
+            container_query: None,
+            intrinsic_size: Size::NONE,
+            children,
+            key: None,
+        };
+        let load = |nodes: Vec<CapturedNode>| {
+            let captured =
+                CapturedTree { available_space: Size::MAX_CONTENT, config: CapturedConfig::default(), nodes };
+            captured.load().map(|_| ())
+        };
+
+        assert_eq!(load(vec![]), Err(TaffyError::InvalidCapturedNode(0)));
+        // Out of bounds
+        assert_eq!(load(vec![node(vec![1])]), Err(TaffyError::InvalidCapturedNode(0)));
+        // Before its parent
+        assert_eq!(load(vec![node(vec![1]), node(vec![0])]), Err(TaffyError::InvalidCapturedNode(1)));
+        // The child of two nodes
+        assert_eq!(load(vec![node(vec![1, 2]), node(vec![2]), node(vec![])]), Err(TaffyError::InvalidCapturedNode(0)));
+        assert_eq!(load(vec![node(vec![1, 1]), node(vec![])]), Err(TaffyError::InvalidCapturedNode(0)));
+        // Not the child of any node
+        assert_eq!(load(vec![node(vec![]), node(vec![])]), Err(TaffyError::InvalidCapturedNode(1)));
+        assert_eq!(load(vec![node(vec![1]), node(vec![])]), Ok(()));
+    }
+
+    /// The nodes of the tree in depth-first order
+    fn descendants(tree: &TaffyTree, node: NodeId) -> Vec<NodeId> {
+        let mut nodes = vec![node];
+        for child in tree.children(node).unwrap() {
+            nodes.extend(descendants(tree, child));
+        }
+        nodes
+    }
+}
@@ -0,0 +1,95 @@
+//! Finding the nodes of a [`TaffyTree`] which are visible within a viewport
+use crate::geometry::Rect;
+use crate::style::Overflow;
+use crate::tree::{NodeId, TaffyResult, TaffyTree};
+use crate::util::sys::Vec;
+
+/// Whether two rectangles overlap by a non-zero area
+fn intersects(a: Rect<f32>, b: Rect<f32>) -> bool {
+    a.left < b.right && b.left < a.right && a.top < b.bottom && b.top < a.bottom
+}
+
+impl<NodeContext> TaffyTree<NodeContext> {
+    /// Returns `root` and each of its descendants which intersect `viewport`, in depth-first order
+    ///
+    /// `viewport` is relative to the top left corner of `root`. Nodes whose `overflow` is not [`Overflow::Visible`]
+    /// clip their descendants to their padding box in that axis: descendants are only returned if the part of them
+    /// inside the clip rect intersects the viewport, and the subtrees of clipped-out nodes are not visited at all.
+    /// Nodes with a zero width or height never intersect the viewport.
+    pub fn visible_nodes(&self, root: NodeId, viewport: Rect<f32>) -> TaffyResult<Vec<NodeId>> {
+        let mut visible = Vec::new();
+        // The nodes still to be visited, with the absolute position of their parent and the rect they are clipped to
+        let mut stack = Vec::new();
+        stack.push((root, 0.0, 0.0, viewport));
+        while let Some((node, parent_x, parent_y, clip)) = stack.pop() {
+            let layout = self.layout(node)?;
+            let left = parent_x + layout.location.x;
+            let top = parent_y + layout.location.y;
+            let rect = Rect { left, right: left + layout.size.width, top, bottom: top + layout.size.height };
+            if intersects(rect, clip) {
+                visible.push(node);
+            }
+
+            // Descendants of nodes which clip their contents are clipped to the node's padding box as well
+            let overflow = self.style(node)?.overflow;
+            let mut child_clip = clip;
+            if overflow.x != Overflow::Visible {
+                child_clip.left = child_clip.left.max(rect.left + layout.border.left);
+                child_clip.right = child_clip.right.min(rect.right - layout.border.right);
+            }
+            if overflow.y != Overflow::Visible {
+                child_clip.top = child_clip.top.max(rect.top + layout.border.top);
+                child_clip.bottom = child_clip.bottom.min(rect.bottom - layout.border.bottom);
+            }
+            if child_clip.left >= child_clip.right || child_clip.top >= child_clip.bottom {
+                continue;
+            }
+
+            // Push the children in reverse so that they are visited in order
+            let children = self.children(node)?;
+            stack.extend(children.into_iter().rev().map(|child| (child, left, top, child_clip)));
+        }
+        Ok(visible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Point, Rect, Size};
+    use crate::style::{FlexDirection, Overflow, Style};
+    use crate::style_helpers::{length, TaffyMaxContent};
+    use crate::tree::TaffyTree;
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn clipped_nodes_are_not_visible() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let items: Vec<_> = (0..10)
+            .map(|_| {
+                taffy
+                    .new_leaf(Style { size: Size::from_lengths(20.0, 10.0), flex_shrink: 0.0, ..Default::default() })
+                    .unwrap()
+            })
+            .collect();
+        let list = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                    scrollbar_width: 0.0,
+                    size: Size::from_lengths(20.0, 30.0),
+                    ..Default::default()
+                },
+                &items,
+            )
+            .unwrap();
+        let root = taffy.new_with_children(Style { padding: length(5.0), ..Default::default() }, &[list]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let everything = Rect { left: 0.0, right: 1000.0, top: 0.0, bottom: 1000.0 };
+        assert_eq!(taffy.visible_nodes(root, everything).unwrap(), vec![root, list, items[0], items[1], items[2]]);
+
+        let bottom_of_list = Rect { left: 0.0, right: 1000.0, top: 26.0, bottom: 1000.0 };
+        assert_eq!(taffy.visible_nodes(root, bottom_of_list).unwrap(), vec![root, list, items[2]]);
+    }
+}
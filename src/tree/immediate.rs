@@ -0,0 +1,222 @@
+//! An immediate-mode interface to a [`TaffyTree`], which rebuilds the tree from a closure on every frame
+use crate::geometry::Size;
+use crate::style::{AvailableSpace, Style};
+#[cfg(feature = "flexbox")]
+use crate::style::{Display, FlexDirection};
+use crate::tree::{Layout, NodeId, TaffyResult, TaffyTree, TraversePartialTree};
+
+/// A [`TaffyTree`] which is described by a closure on every frame, rather than by creating and removing nodes
+///
+/// Each call to [`ImmediateTree::frame`] describes the whole tree. Nodes are identified by their position among
+/// their siblings, so a node is reused from the previous frame if it is called for in the same place, and is only
+/// marked dirty if its style (or the number of its children) changed. Nodes which are not called for are removed.
+/// This suits tools and debug UIs which are already written in immediate mode.
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// # use taffy::ImmediateTree;
+/// let mut tree = ImmediateTree::new();
+/// let mut sidebar = None;
+/// tree.frame(Style { size: Size::from_lengths(400.0, 300.0), ..Default::default() }, |ui| {
+///     sidebar = Some(ui.leaf(Style { size: Size::from_lengths(100.0, 300.0), ..Default::default() }));
+///     ui.column(|ui| {
+///         ui.leaf(Style { size: Size::from_lengths(50.0, 50.0), ..Default::default() });
+///     });
+/// })
+/// .unwrap();
+/// tree.compute_layout(Size::MAX_CONTENT).unwrap();
+/// assert_eq!(tree.tree().layout(sidebar.unwrap()).unwrap().size.width, 100.0);
+/// ```
+#[derive(Debug)]
+pub struct ImmediateTree {
+    /// The retained tree which each frame is diffed against
+    tree: TaffyTree,
+    /// The root node of the tree
+    root: NodeId,
+}
+
+impl Default for ImmediateTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImmediateTree {
+    /// Create an empty immediate-mode tree
+    #[must_use]
+    pub fn new() -> Self {
+        let mut tree = TaffyTree::new();
+        let root = tree.new_leaf(Style::default()).expect("creating a node can't fail");
+        Self { tree, root }
+    }
+
+    /// Describe the tree for this frame: the root node has `style` and the children added by `build`
+    pub fn frame(&mut self, style: Style, build: impl FnOnce(&mut ImmediateUi)) -> TaffyResult<()> {
+        update_style(&mut self.tree, self.root, style)?;
+        let mut ui = ImmediateUi { tree: &mut self.tree, parent: self.root, child_count: 0, error: None };
+        build(&mut ui);
+        ui.finish()
+    }
+
+    /// Lay out the tree described by the most recent frame
+    pub fn compute_layout(&mut self, available_space: Size<AvailableSpace>) -> TaffyResult<()> {
+        self.tree.compute_layout(self.root, available_space)
+    }
+
+    /// The root node of the tree
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    /// The retained tree, for reading layouts and traversing the nodes
+    pub fn tree(&self) -> &TaffyTree {
+        &self.tree
+    }
+
+    /// The layout of `node`, as of the most recent call to [`ImmediateTree::compute_layout`]
+    pub fn layout(&self, node: NodeId) -> TaffyResult<&Layout> {
+        self.tree.layout(node)
+    }
+}
+
+/// Adds the children of a node during an [`ImmediateTree::frame`]
+///
+/// Every method returns the [`NodeId`] of the node it adds, which stays the same from frame to frame for as long as
+/// the node is added in the same place.
+#[derive(Debug)]
+pub struct ImmediateUi<'a> {
+    /// The tree being built
+    tree: &'a mut TaffyTree,
+    /// The node whose children are being added
+    parent: NodeId,
+    /// The number of children added to `parent` so far in this frame
+    child_count: usize,
+    /// The first error encountered while adding children, which is returned from the frame
+    error: Option<crate::TaffyError>,
+}
+
+impl ImmediateUi<'_> {
+    /// Add a node with `style` and no children
+    pub fn leaf(&mut self, style: Style) -> NodeId {
+        self.node(style, |_| {})
+    }
+
+    /// Add a node with `style` and the children added by `children`
+    ///
+    /// If the node can't be added then `children` isn't called, the frame returns the error, and the parent's id is
+    /// returned in place of the node's.
+    pub fn node(&mut self, style: Style, children: impl FnOnce(&mut ImmediateUi)) -> NodeId {
+        let Some(child) = self.next_child(style) else {
+            return self.parent;
+        };
+        let mut ui = ImmediateUi { tree: self.tree, parent: child, child_count: 0, error: None };
+        children(&mut ui);
+        if let Err(error) = ui.finish() {
+            self.error.get_or_insert(error);
+        }
+        child
+    }
+
+    /// Add a flexbox row containing the children added by `children`
+    #[cfg(feature = "flexbox")]
+    pub fn row(&mut self, children: impl FnOnce(&mut ImmediateUi)) -> NodeId {
+        self.node(Style { display: Display::Flex, flex_direction: FlexDirection::Row, ..Default::default() }, children)
+    }
+
+    /// Add a flexbox column containing the children added by `children`
+    #[cfg(feature = "flexbox")]
+    pub fn column(&mut self, children: impl FnOnce(&mut ImmediateUi)) -> NodeId {
+        let style = Style { display: Display::Flex, flex_direction: FlexDirection::Column, ..Default::default() };
+        self.node(style, children)
+    }
+
+    /// Reuse the child of the parent at the next index if there is one, or else create it, and set its style
+    ///
+    /// Returns `None` and records the error if the child can't be created or updated.
+    fn next_child(&mut self, style: Style) -> Option<NodeId> {
+        let index = self.child_count;
+        self.child_count += 1;
+        let result = match self.tree.child_at_index(self.parent, index) {
+            Ok(child) => update_style(self.tree, child, style).map(|_| child),
+            Err(_) => {
+                self.tree.new_leaf(style).and_then(|child| self.tree.add_child(self.parent, child).map(|_| child))
+            }
+        };
+        match result {
+            Ok(child) => Some(child),
+            Err(error) => {
+                self.error.get_or_insert(error);
+                None
+            }
+        }
+    }
+
+    /// Remove the children of the parent which weren't added in this frame, along with their descendants
+    fn finish(self) -> TaffyResult<()> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let child_count = self.tree.child_count(self.parent);
+        if child_count > self.child_count {
+            let removed = self.tree.children(self.parent)?.split_off(self.child_count);
+            self.tree.remove_children_range(self.parent, self.child_count..child_count)?;
+            let mut stack = removed;
+            while let Some(node) = stack.pop() {
+                stack.extend(self.tree.children(node)?);
+                self.tree.remove(node)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sets the style of `node`, unless it is unchanged (so that the node isn't marked dirty)
+fn update_style(tree: &mut TaffyTree, node: NodeId, style: Style) -> TaffyResult<()> {
+    if *tree.style(node)? != style {
+        tree.set_style(node, style)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImmediateTree;
+    use crate::geometry::Size;
+    use crate::style::Style;
+    use crate::style_helpers::TaffyMaxContent;
+    use crate::NodeId;
+
+    /// A frame containing a leaf and a container holding another leaf, returning the ids of all three
+    fn build(tree: &mut ImmediateTree) -> [NodeId; 3] {
+        let leaf_style = Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() };
+        let mut leaves = [None, None];
+        let mut container = None;
+        tree.frame(Style::default(), |ui| {
+            leaves[0] = Some(ui.leaf(leaf_style.clone()));
+            container = Some(ui.node(Style::default(), |ui| leaves[1] = Some(ui.leaf(leaf_style.clone()))));
+        })
+        .unwrap();
+        [leaves[0].unwrap(), container.unwrap(), leaves[1].unwrap()]
+    }
+
+    #[test]
+    fn nodes_are_reused_between_frames_and_removed_when_not_added() {
+        let mut tree = ImmediateTree::new();
+        let ids = build(&mut tree);
+        tree.compute_layout(Size::MAX_CONTENT).unwrap();
+        assert_eq!(tree.tree().total_node_count(), 4);
+
+        // The same frame again reuses every node and leaves the layout clean
+        assert_eq!(build(&mut tree), ids);
+        assert!(!tree.tree().dirty(tree.root()).unwrap());
+
+        // Dropping the container removes it along with its child
+        tree.frame(Style::default(), |ui| {
+            ui.leaf(Style { size: Size::from_lengths(20.0, 10.0), ..Default::default() });
+        })
+        .unwrap();
+        tree.compute_layout(Size::MAX_CONTENT).unwrap();
+        assert_eq!(tree.tree().total_node_count(), 2);
+        assert_eq!(tree.layout(ids[0]).unwrap().size.width, 20.0);
+    }
+}
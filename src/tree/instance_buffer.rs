@@ -0,0 +1,87 @@
+//! Packing of a [`LayoutSnapshot`] into a buffer of quads, for renderers which draw nodes using instancing
+use crate::tree::{LayoutSnapshot, NodeId};
+use crate::util::sys::Vec;
+
+/// The border box of each node in a [`LayoutSnapshot`], packed as `[x, y, width, height]` for upload to the GPU
+///
+/// Instance `i` is made up of the floats `data()[i * 4..i * 4 + 4]` and is the quad of node `node_ids()[i]`.
+/// Positions are relative to the top left corner of the node's root, as in the snapshot.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InstanceBuffer {
+    /// The packed `[x, y, width, height]` of each instance
+    data: Vec<f32>,
+    /// The node that each instance was generated from
+    node_ids: Vec<NodeId>,
+}
+
+impl InstanceBuffer {
+    /// The number of floats in each instance
+    pub const STRIDE: usize = 4;
+
+    /// Creates a new empty [`InstanceBuffer`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an [`InstanceBuffer`] containing every node in `snapshot`
+    pub fn from_snapshot(snapshot: &LayoutSnapshot) -> Self {
+        let mut buffer = Self::new();
+        buffer.fill(snapshot);
+        buffer
+    }
+
+    /// Replaces the contents of the buffer with every node in `snapshot`, reusing its allocations
+    ///
+    /// Instances are in the same order as the nodes of the snapshot.
+    pub fn fill(&mut self, snapshot: &LayoutSnapshot) {
+        self.data.clear();
+        self.node_ids.clear();
+        self.data.reserve(snapshot.len() * Self::STRIDE);
+        for rect in snapshot.rects() {
+            self.data.extend([rect.left, rect.top, rect.right - rect.left, rect.bottom - rect.top]);
+        }
+        self.node_ids.extend_from_slice(snapshot.node_ids());
+    }
+
+    /// The number of instances in the buffer
+    pub fn len(&self) -> usize {
+        self.node_ids.len()
+    }
+
+    /// Whether the buffer contains no instances
+    pub fn is_empty(&self) -> bool {
+        self.node_ids.is_empty()
+    }
+
+    /// The packed `[x, y, width, height]` of each instance
+    pub fn data(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// The node that each instance was generated from
+    pub fn node_ids(&self) -> &[NodeId] {
+        &self.node_ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InstanceBuffer;
+    use crate::geometry::Size;
+    use crate::style::Style;
+    use crate::style_helpers::{length, TaffyMaxContent};
+    use crate::tree::TaffyTree;
+
+    #[test]
+    fn packs_absolute_rects() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 5.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style { padding: length(3.0), ..Default::default() }, &[leaf]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let buffer = InstanceBuffer::from_snapshot(&taffy.snapshot());
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.node_ids(), &[root, leaf]);
+        assert_eq!(buffer.data(), &[0.0, 0.0, 16.0, 11.0, 3.0, 3.0, 10.0, 5.0]);
+    }
+}
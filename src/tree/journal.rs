@@ -0,0 +1,144 @@
+//! A log of the changes made to the structure and styles of a [`TaffyTree`], for implementing undo and redo
+use crate::style::Style;
+use crate::tree::{NodeId, TaffyResult, TaffyTree};
+
+/// A single change to a [`TaffyTree`], as recorded by [`TaffyTree::enable_journal`]
+///
+/// Every edit records enough information to be reversed with [`TreeEdit::inverse`]. A sequence of edits is undone by
+/// applying the inverse of each edit in reverse order.
+///
+/// New variants may be added in minor releases, so matches on this enum must include a wildcard arm.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)] // Edits which change a style are by far the most common
+#[non_exhaustive]
+pub enum TreeEdit {
+    /// `child` was inserted into the children of `parent` at `child_index`
+    InsertChild {
+        /// The parent node
+        parent: NodeId,
+        /// The position of the child in the parent's list of children
+        child_index: usize,
+        /// The child node
+        child: NodeId,
+    },
+    /// `child` was removed from the children of `parent`, where it was at `child_index`
+    RemoveChild {
+        /// The parent node
+        parent: NodeId,
+        /// The position of the child in the parent's list of children before it was removed
+        child_index: usize,
+        /// The child node
+        child: NodeId,
+    },
+    /// The style of `node` was changed from `old_style` to `new_style`
+    SetStyle {
+        /// The node whose style was changed
+        node: NodeId,
+        /// The style of the node before the change
+        old_style: Style,
+        /// The style of the node after the change
+        new_style: Style,
+    },
+}
+
+impl TreeEdit {
+    /// Returns the edit which reverses this edit
+    pub fn inverse(&self) -> TreeEdit {
+        match self {
+            TreeEdit::InsertChild { parent, child_index, child } => {
+                TreeEdit::RemoveChild { parent: *parent, child_index: *child_index, child: *child }
+            }
+            TreeEdit::RemoveChild { parent, child_index, child } => {
+                TreeEdit::InsertChild { parent: *parent, child_index: *child_index, child: *child }
+            }
+            TreeEdit::SetStyle { node, old_style, new_style } => {
+                TreeEdit::SetStyle { node: *node, old_style: new_style.clone(), new_style: old_style.clone() }
+            }
+        }
+    }
+}
+
+impl<NodeContext> TaffyTree<NodeContext> {
+    /// Applies a recorded [`TreeEdit`] to the tree
+    ///
+    /// Use this with the edits returned by [`TaffyTree::take_journal`] to replay changes, or with their
+    /// [`inverse`](TreeEdit::inverse) to undo them. If the journal is enabled then the edit is recorded again, so
+    /// that an undo can itself be undone.
+    ///
+    /// Fails with [`TaffyError::ChildIndexOutOfBounds`](crate::TaffyError::ChildIndexOutOfBounds) if the index of a
+    /// child is out of range, and with [`TaffyError::InvalidChildNode`](crate::TaffyError::InvalidChildNode) if the
+    /// child to be removed is not at the recorded index.
+    pub fn apply_edit(&mut self, edit: &TreeEdit) -> TaffyResult<()> {
+        match edit {
+            TreeEdit::InsertChild { parent, child_index, child } => {
+                self.insert_child_at_index(*parent, *child_index, *child)
+            }
+            TreeEdit::RemoveChild { parent, child_index, child } => {
+                if self.child_at_index(*parent, *child_index)? != *child {
+                    return Err(crate::TaffyError::InvalidChildNode(*child));
+                }
+                self.remove_child_at_index(*parent, *child_index).map(|_| ())
+            }
+            TreeEdit::SetStyle { node, new_style, .. } => self.set_style(*node, new_style.clone()),
+        }
+    }
+
+    /// Reverses a sequence of recorded edits, by applying the inverse of each of them from last to first
+    pub fn undo_edits(&mut self, edits: &[TreeEdit]) -> TaffyResult<()> {
+        for edit in edits.iter().rev() {
+            self.apply_edit(&edit.inverse())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TreeEdit;
+    use crate::prelude::*;
+
+    #[test]
+    fn undo_and_redo_journaled_edits() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let a = taffy.new_leaf(Style::default()).unwrap();
+        let b = taffy.new_leaf(Style::default()).unwrap();
+        let c = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[a, b]).unwrap();
+
+        taffy.enable_journal();
+        taffy.add_child(root, c).unwrap();
+        taffy.remove_child(root, a).unwrap();
+        taffy.set_style(b, Style { aspect_ratio: Some(2.0), ..Default::default() }).unwrap();
+        taffy.set_children(root, &[c, a]).unwrap();
+        let edits = taffy.take_journal();
+
+        assert_eq!(edits[0], TreeEdit::InsertChild { parent: root, child_index: 2, child: c });
+        assert_eq!(edits[1], TreeEdit::RemoveChild { parent: root, child_index: 0, child: a });
+        assert_eq!(taffy.children(root).unwrap(), [c, a]);
+
+        taffy.undo_edits(&edits).unwrap();
+        assert_eq!(taffy.children(root).unwrap(), [a, b]);
+        assert_eq!(taffy.parent(c), None);
+        assert_eq!(taffy.style(b).unwrap().aspect_ratio, None);
+
+        for edit in &edits {
+            taffy.apply_edit(edit).unwrap();
+        }
+        assert_eq!(taffy.children(root).unwrap(), [c, a]);
+        assert_eq!(taffy.style(b).unwrap().aspect_ratio, Some(2.0));
+    }
+
+    #[test]
+    fn removing_a_node_records_its_removal_from_its_parent() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let a = taffy.new_leaf(Style::default()).unwrap();
+        let b = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[a, b]).unwrap();
+
+        taffy.enable_journal();
+        taffy.remove(b).unwrap();
+        assert_eq!(taffy.take_journal(), [TreeEdit::RemoveChild { parent: root, child_index: 1, child: b }]);
+        assert_eq!(taffy.children(root).unwrap(), [a]);
+    }
+}
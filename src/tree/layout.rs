@@ -222,7 +222,7 @@ impl LayoutOutput {
 
 /// The final result of a layout algorithm for a single node.
 #[derive(Debug, Copy, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Layout {
     /// The relative ordering of the node
     ///
@@ -293,6 +293,40 @@ impl Layout {
         }
     }
 
+    /// Creates a new [`Layout`] with the supplied `location` and `size`, and zero-sized borders, padding and margins.
+    ///
+    /// Useful for writing layouts computed (or saved) outside of Taffy with [`TaffyTree::set_layout`](crate::TaffyTree::set_layout).
+    #[must_use]
+    pub const fn from_location_and_size(location: Point<f32>, size: Size<f32>) -> Self {
+        Self { location, size, ..Self::new() }
+    }
+
+    /// Linearly interpolates between the layouts `a` and `b`, for animating a node between two computed layouts
+    ///
+    /// `t = 0.0` gives `a` and `t = 1.0` gives `b`. Every length is interpolated, while `order` is taken from `b`.
+    #[must_use]
+    pub fn lerp(a: &Layout, b: &Layout, t: f32) -> Layout {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        let lerp_size = |a: Size<f32>, b: Size<f32>| a.zip_map(b, lerp);
+        let lerp_rect = |a: Rect<f32>, b: Rect<f32>| Rect {
+            left: lerp(a.left, b.left),
+            right: lerp(a.right, b.right),
+            top: lerp(a.top, b.top),
+            bottom: lerp(a.bottom, b.bottom),
+        };
+        Layout {
+            order: b.order,
+            location: Point { x: lerp(a.location.x, b.location.x), y: lerp(a.location.y, b.location.y) },
+            size: lerp_size(a.size, b.size),
+            #[cfg(feature = "content_size")]
+            content_size: lerp_size(a.content_size, b.content_size),
+            scrollbar_size: lerp_size(a.scrollbar_size, b.scrollbar_size),
+            border: lerp_rect(a.border, b.border),
+            padding: lerp_rect(a.padding, b.padding),
+            margin: lerp_rect(a.margin, b.margin),
+        }
+    }
+
     /// Get the width of the node's content box
     #[inline]
     pub fn content_box_width(&self) -> f32 {
@@ -346,12 +380,18 @@ impl Layout {
 }
 
 /// The additional information from layout algorithm
+///
+/// New variants may be added in minor releases, so matches on this enum must include a wildcard arm.
 #[cfg(feature = "detailed_layout_info")]
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum DetailedLayoutInfo {
     /// Enum variant for [`DetailedGridInfo`](crate::compute::grid::DetailedGridInfo)
     #[cfg(feature = "grid")]
     Grid(Box<crate::compute::grid::DetailedGridInfo>),
+    /// Enum variant for [`DetailedFlexboxInfo`](crate::compute::flexbox::DetailedFlexboxInfo)
+    #[cfg(feature = "flexbox")]
+    Flexbox(Box<crate::compute::flexbox::DetailedFlexboxInfo>),
     /// For node that hasn't had any detailed information yet
     None,
 }
@@ -0,0 +1,129 @@
+//! Double-buffered layout output, for reading the layout of a [`TaffyTree`](crate::TaffyTree) from other threads
+use slotmap::{DefaultKey, SecondaryMap};
+use std::sync::{Arc, Mutex};
+
+use crate::tree::{Layout, NodeId, TraversePartialTree, TraverseTree};
+
+/// The final layout of every node in a [`TaffyTree`](crate::TaffyTree) as of the end of one layout pass
+///
+/// Frames are immutable once they have been published to a [`LayoutBuffer`]. As well as the layout of each node, a
+/// frame holds the parent and children of each node, so a renderer can walk the tree through a shared reference
+/// to the frame while the [`TaffyTree`](crate::TaffyTree) itself is being modified.
+#[derive(Debug, Clone, Default)]
+pub struct LayoutFrame {
+    /// The final layout of each node
+    layouts: SecondaryMap<DefaultKey, Layout>,
+    /// The parent of each node
+    parents: SecondaryMap<DefaultKey, Option<NodeId>>,
+    /// The children of each node
+    children: SecondaryMap<DefaultKey, Vec<NodeId>>,
+    /// The position of this frame in the sequence of frames published to the buffer
+    generation: u64,
+}
+
+impl LayoutFrame {
+    /// The final layout of `node`, or `None` if the node did not exist when the frame was published
+    pub fn layout(&self, node: NodeId) -> Option<&Layout> {
+        self.layouts.get(node.into())
+    }
+
+    /// The parent of `node`, or `None` if the node has no parent or did not exist when the frame was published
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.parents.get(node.into()).copied().flatten()
+    }
+
+    /// The children of `node`, or an empty list if the node did not exist when the frame was published
+    pub fn children(&self, node: NodeId) -> &[NodeId] {
+        self.children.get(node.into()).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// The position of this frame in the sequence of frames published to the buffer
+    ///
+    /// The empty frame which the buffer starts with has generation zero and each published frame has a generation one
+    /// greater than the frame before it.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+impl TraversePartialTree for LayoutFrame {
+    type ChildIter<'a> = core::iter::Copied<core::slice::Iter<'a, NodeId>>;
+
+    fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
+        self.children(parent_node_id).iter().copied()
+    }
+
+    fn child_count(&self, parent_node_id: NodeId) -> usize {
+        self.children(parent_node_id).len()
+    }
+
+    fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
+        self.children(parent_node_id)[child_index]
+    }
+}
+
+impl TraverseTree for LayoutFrame {}
+
+/// A shared handle to the most recently completed layout of a [`TaffyTree`](crate::TaffyTree)
+///
+/// Returned by [`TaffyTree::enable_double_buffering`](crate::TaffyTree::enable_double_buffering). The handle can be
+/// cloned and sent to other threads. New layouts are written into a back buffer while they are computed, and the
+/// buffers are swapped only once a layout pass has completed, so [`LayoutBuffer::front`] never returns a
+/// half-updated tree.
+#[derive(Debug, Clone, Default)]
+pub struct LayoutBuffer(Arc<Mutex<Arc<LayoutFrame>>>);
+
+impl LayoutBuffer {
+    /// The most recently published frame. Holding on to it does not block the publishing of new frames.
+    pub fn front(&self) -> Arc<LayoutFrame> {
+        Arc::clone(&self.0.lock().unwrap_or_else(|err| err.into_inner()))
+    }
+}
+
+/// The writing side of a [`LayoutBuffer`], owned by the tree
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DoubleBuffer {
+    /// The buffer shared with readers
+    pub(crate) shared: LayoutBuffer,
+    /// The frame which will be written to by the next publish
+    back: Arc<LayoutFrame>,
+}
+
+impl DoubleBuffer {
+    /// Write the layout, parent and children of each node into the back frame and swap it with the front frame
+    pub(crate) fn publish<'a>(
+        &mut self,
+        nodes: impl Iterator<Item = (DefaultKey, Layout, Option<NodeId>, &'a [NodeId])>,
+    ) {
+        // Reuse the allocation of the back frame unless a reader is still holding on to it
+        if Arc::get_mut(&mut self.back).is_none() {
+            self.back = Arc::default();
+        }
+        let generation = self.shared.front().generation + 1;
+        if let Some(frame) = Arc::get_mut(&mut self.back) {
+            let LayoutFrame { layouts, parents, children: child_lists, .. } = frame;
+            layouts.clear();
+            parents.clear();
+            for (key, layout, parent, children) in nodes {
+                layouts.insert(key, layout);
+                parents.insert(key, parent);
+                // Reuse the child list which the frame already holds for the node
+                match child_lists.get_mut(key) {
+                    Some(child_list) => {
+                        child_list.clear();
+                        child_list.extend_from_slice(children);
+                    }
+                    None => {
+                        child_lists.insert(key, children.to_vec());
+                    }
+                }
+            }
+            // Drop the child lists of nodes which have been removed since the frame was last written
+            child_lists.retain(|key, _| layouts.contains_key(key));
+            frame.generation = generation;
+        }
+
+        let mut front = self.shared.0.lock().unwrap_or_else(|err| err.into_inner());
+        core::mem::swap(&mut *front, &mut self.back);
+    }
+}
@@ -8,12 +8,14 @@ mod cache;
 mod layout;
 mod node;
 pub mod traits;
+mod warning;
 
 pub use cache::{Cache, ClearState};
 pub use layout::{CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode};
-pub use node::NodeId;
+pub use node::{NodeId, NodeKey};
 pub(crate) use traits::LayoutPartialTreeExt;
 pub use traits::{LayoutPartialTree, PrintTree, RoundTree, TraversePartialTree, TraverseTree};
+pub use warning::LayoutWarning;
 
 #[cfg(feature = "flexbox")]
 pub use traits::LayoutFlexboxContainer;
@@ -24,10 +26,43 @@ pub use traits::LayoutGridContainer;
 #[cfg(feature = "block_layout")]
 pub use traits::LayoutBlockContainer;
 
+#[cfg(feature = "capture")]
+mod capture;
+#[cfg(feature = "taffy_tree")]
+mod culling;
+#[cfg(feature = "taffy_tree")]
+mod immediate;
+#[cfg(feature = "instance_buffer")]
+mod instance_buffer;
+#[cfg(feature = "taffy_tree")]
+mod journal;
+#[cfg(all(feature = "taffy_tree", feature = "std"))]
+mod layout_buffer;
+#[cfg(feature = "taffy_tree")]
+mod repair;
+#[cfg(feature = "taffy_tree")]
+mod snapshot;
 #[cfg(feature = "taffy_tree")]
 mod taffy_tree;
+#[cfg(feature = "capture")]
+pub use capture::{CapturedConfig, CapturedNode, CapturedTree};
+#[cfg(feature = "taffy_tree")]
+pub use immediate::{ImmediateTree, ImmediateUi};
+#[cfg(feature = "instance_buffer")]
+pub use instance_buffer::InstanceBuffer;
+#[cfg(feature = "taffy_tree")]
+pub use journal::TreeEdit;
+#[cfg(all(feature = "taffy_tree", feature = "std"))]
+pub use layout_buffer::{LayoutBuffer, LayoutFrame};
+#[cfg(feature = "taffy_tree")]
+pub use repair::TreeRepair;
+#[cfg(feature = "taffy_tree")]
+pub use snapshot::LayoutSnapshot;
+#[cfg(feature = "taffy_tree")]
+#[allow(deprecated)]
+pub use taffy_tree::Taffy;
 #[cfg(feature = "taffy_tree")]
-pub use taffy_tree::{TaffyError, TaffyResult, TaffyTree};
+pub use taffy_tree::{LeafFallbackSize, TaffyError, TaffyResult, TaffyTree};
 
 #[cfg(feature = "detailed_layout_info")]
 pub use layout::DetailedLayoutInfo;
@@ -43,6 +43,16 @@ impl From<NodeId> for usize {
     }
 }
 
+/// A persistent identifier for a node, chosen by the application
+///
+/// Unlike a [`NodeId`], which is allocated by the tree and is only meaningful for the tree that created it, a `NodeKey`
+/// is saved along with the node when a tree is captured (see [`TaffyTree::capture`](crate::TaffyTree::capture)).
+/// External references (e.g. handles held by scripts) which use keys therefore stay valid when a saved tree is
+/// loaded again, even though the loaded nodes have new ids. See [`TaffyTree::set_node_key`](crate::TaffyTree::set_node_key).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NodeKey(pub u64);
+
 #[cfg(feature = "taffy_tree")]
 impl From<DefaultKey> for NodeId {
     #[inline]
@@ -0,0 +1,32 @@
+//! The fixes made by [`TaffyTree::repair`](crate::TaffyTree::repair)
+use crate::tree::NodeId;
+
+/// A fix made by [`TaffyTree::repair`](crate::TaffyTree::repair) to make the parent and child links of a tree
+/// consistent with each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeRepair {
+    /// `child` was removed from the children of `parent` because it is not a node in the tree
+    RemovedMissingChild {
+        /// The parent node
+        parent: NodeId,
+        /// The child which doesn't exist
+        child: NodeId,
+    },
+    /// `child` was removed from the children of `parent` because it was also a child elsewhere: either earlier in the
+    /// children of `parent`, or in the children of the node which its parent link points to
+    RemovedDuplicateChild {
+        /// The parent node
+        parent: NodeId,
+        /// The child which appeared more than once
+        child: NodeId,
+    },
+    /// The parent link of `node` was changed to the node which has it as a child, or to `None` if no node does
+    SetParent {
+        /// The node whose parent link was changed
+        node: NodeId,
+        /// The parent link before the repair
+        old_parent: Option<NodeId>,
+        /// The parent link after the repair
+        new_parent: Option<NodeId>,
+    },
+}
@@ -0,0 +1,216 @@
+//! A read-only copy of the computed layout of a [`TaffyTree`], which can be handed to other threads
+use crate::geometry::Rect;
+use crate::tree::{Layout, NodeId, PrintTree, TaffyResult, TaffyTree, TraversePartialTree};
+use crate::util::sys::Vec;
+use slotmap::{DefaultKey, SecondaryMap};
+
+/// The absolute position and size of every node in a [`TaffyTree`], as of the time [`TaffyTree::snapshot`] was called
+///
+/// The snapshot is stored as flat arrays which share an index: the node at index `i` is `node_ids()[i]`, its
+/// rectangle is `rects()[i]` and the index of its parent is `parents()[i]`. Parents always come before their
+/// children. It doesn't borrow the tree, so it can be sent to a render or network thread while the tree is modified.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LayoutSnapshot {
+    /// The id of each node
+    node_ids: Vec<NodeId>,
+    /// The border box of each node relative to the top left corner of its root node
+    rects: Vec<Rect<f32>>,
+    /// The index of the parent of each node, or `None` for root nodes
+    parents: Vec<Option<usize>>,
+}
+
+impl LayoutSnapshot {
+    /// The number of nodes in the snapshot
+    pub fn len(&self) -> usize {
+        self.node_ids.len()
+    }
+
+    /// Whether the snapshot contains no nodes
+    pub fn is_empty(&self) -> bool {
+        self.node_ids.is_empty()
+    }
+
+    /// The id of each node
+    pub fn node_ids(&self) -> &[NodeId] {
+        &self.node_ids
+    }
+
+    /// The border box of each node, relative to the top left corner of its root node
+    pub fn rects(&self) -> &[Rect<f32>] {
+        &self.rects
+    }
+
+    /// The index of the parent of each node, or `None` for root nodes
+    pub fn parents(&self) -> &[Option<usize>] {
+        &self.parents
+    }
+}
+
+impl<NodeContext> TaffyTree<NodeContext> {
+    /// Copies the computed layout of every node in the tree into a [`LayoutSnapshot`]
+    ///
+    /// Nodes are listed in depth-first order starting from each root (node without a parent) in turn.
+    pub fn snapshot(&self) -> LayoutSnapshot {
+        let mut snapshot = LayoutSnapshot::default();
+        // The nodes still to be visited, with the index of their parent
+        let mut stack: Vec<(NodeId, Option<usize>)> = Vec::new();
+        for root in self.roots() {
+            stack.push((root, None));
+            while let Some((node, parent)) = stack.pop() {
+                let layout = self.get_final_layout(node);
+                let (x, y) = match parent {
+                    Some(parent) => (snapshot.rects[parent].left, snapshot.rects[parent].top),
+                    None => (0.0, 0.0),
+                };
+                let left = x + layout.location.x;
+                let top = y + layout.location.y;
+
+                let index = snapshot.node_ids.len();
+                snapshot.node_ids.push(node);
+                snapshot.rects.push(Rect {
+                    left,
+                    right: left + layout.size.width,
+                    top,
+                    bottom: top + layout.size.height,
+                });
+                snapshot.parents.push(parent);
+
+                // Push the children in reverse so that they are visited in order
+                let children = (0..self.child_count(node)).rev().map(|i| self.get_child_id(node, i));
+                stack.extend(children.map(|child| (child, Some(index))));
+            }
+        }
+        snapshot
+    }
+
+    /// Takes a [`LayoutSnapshot`] of the tree in which each node is linearly interpolated between its rectangle in
+    /// `previous` and its current computed layout
+    ///
+    /// `t = 0.0` gives the rectangles in `previous` and `t = 1.0` gives the current layout, so a UI can take a snapshot
+    /// before a change, compute the layout once, and then animate between the two without recomputing the layout on
+    /// each frame. Nodes which are not in `previous` appear at their current position.
+    pub fn interpolate_layouts(&self, previous: &LayoutSnapshot, t: f32) -> LayoutSnapshot {
+        let previous_rects: SecondaryMap<DefaultKey, Rect<f32>> =
+            previous.node_ids.iter().map(|&node| node.into()).zip(previous.rects.iter().copied()).collect();
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+
+        let mut snapshot = self.snapshot();
+        for (node, rect) in snapshot.node_ids.iter().zip(snapshot.rects.iter_mut()) {
+            if let Some(previous) = previous_rects.get((*node).into()) {
+                *rect = Rect {
+                    left: lerp(previous.left, rect.left),
+                    right: lerp(previous.right, rect.right),
+                    top: lerp(previous.top, rect.top),
+                    bottom: lerp(previous.bottom, rect.bottom),
+                };
+            }
+        }
+        snapshot
+    }
+
+    /// Replaces the contents of `buffer` with the id and layout of `root` and each of its descendants, in paint order
+    ///
+    /// Nodes are listed depth-first with each node before its children and siblings in ascending [`Layout::order`],
+    /// so drawing them in sequence paints each node on top of those before it. Layouts are relative to the parent node,
+    /// as returned by [`TaffyTree::layout`]. Reusing the same buffer avoids an allocation (and a call to
+    /// [`TaffyTree::layout`]) per node.
+    pub fn export_layout(&self, root: NodeId, buffer: &mut Vec<(NodeId, Layout)>) -> TaffyResult<()> {
+        buffer.clear();
+        let mut stack = Vec::new();
+        stack.push(root);
+        let mut children = Vec::new();
+        while let Some(node) = stack.pop() {
+            buffer.push((node, *self.layout(node)?));
+
+            children.clear();
+            children.extend((0..self.child_count(node)).map(|i| self.get_child_id(node, i)));
+            children.sort_by_key(|&child| self.get_final_layout(child).order);
+            // Push the children in reverse so that they are visited in order
+            stack.extend(children.iter().rev());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Point, Rect, Size};
+    use crate::style::Style;
+    use crate::style_helpers::{length, TaffyMaxContent};
+    use crate::tree::{Layout, TaffyTree};
+
+    #[test]
+    fn snapshot_contains_absolute_rects() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 5.0), ..Default::default() }).unwrap();
+        let inner = taffy.new_with_children(Style { padding: length(2.0), ..Default::default() }, &[leaf]).unwrap();
+        let root = taffy.new_with_children(Style { padding: length(3.0), ..Default::default() }, &[inner]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let snapshot = std::thread::spawn({
+            let snapshot = taffy.snapshot();
+            move || snapshot
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(snapshot.node_ids(), &[root, inner, leaf]);
+        assert_eq!(snapshot.parents(), &[None, Some(0), Some(1)]);
+        assert_eq!(snapshot.rects()[2], Rect { left: 5.0, right: 15.0, top: 5.0, bottom: 10.0 });
+    }
+
+    #[test]
+    fn interpolate_layouts_blends_from_the_previous_snapshot() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let previous = taffy.snapshot();
+
+        taffy.set_style(leaf, Style { size: Size::from_lengths(30.0, 20.0), ..Default::default() }).unwrap();
+        let added = taffy.new_leaf(Style { size: Size::from_lengths(4.0, 4.0), ..Default::default() }).unwrap();
+        taffy.add_child(root, added).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let halfway = taffy.interpolate_layouts(&previous, 0.5);
+        assert_eq!(halfway.node_ids(), &[root, leaf, added]);
+        assert_eq!(halfway.rects()[1], Rect { left: 0.0, right: 20.0, top: 0.0, bottom: 15.0 });
+        assert_eq!(halfway.rects()[2], Rect { left: 30.0, right: 34.0, top: 0.0, bottom: 4.0 });
+        assert_eq!(taffy.interpolate_layouts(&previous, 0.0).rects()[1], previous.rects()[1]);
+    }
+
+    #[test]
+    fn layout_lerp_interpolates_lengths() {
+        let a = Layout {
+            order: 1,
+            ..Layout::from_location_and_size(Point { x: 0.0, y: 10.0 }, Size { width: 10.0, height: 10.0 })
+        };
+        let b = Layout {
+            order: 2,
+            padding: Rect::new(4.0, 4.0, 4.0, 4.0),
+            ..Layout::from_location_and_size(Point { x: 20.0, y: 10.0 }, Size { width: 30.0, height: 10.0 })
+        };
+
+        let layout = Layout::lerp(&a, &b, 0.25);
+        assert_eq!(layout.order, 2);
+        assert_eq!(layout.location, Point { x: 5.0, y: 10.0 });
+        assert_eq!(layout.size, Size { width: 15.0, height: 10.0 });
+        assert_eq!(layout.padding, Rect::new(1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn export_layout_lists_nodes_in_paint_order() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaves: Vec<_> = (0..3).map(|_| taffy.new_leaf(Style::default()).unwrap()).collect();
+        let inner = taffy.new_with_children(Style::default(), &leaves[..2]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[inner, leaves[2]]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let mut buffer = vec![(root, Layout::new())];
+        taffy.export_layout(root, &mut buffer).unwrap();
+
+        let ids: Vec<_> = buffer.iter().map(|(node, _)| *node).collect();
+        assert_eq!(ids, vec![root, inner, leaves[0], leaves[1], leaves[2]]);
+        assert_eq!(buffer[1].1, *taffy.layout(inner).unwrap());
+    }
+}
@@ -5,16 +5,24 @@ use slotmap::SecondaryMap;
 use slotmap::SparseSecondaryMap as SecondaryMap;
 use slotmap::{DefaultKey, SlotMap};
 
-use crate::geometry::Size;
-use crate::style::{AvailableSpace, Display, Style};
+use super::journal::TreeEdit;
+#[cfg(feature = "std")]
+use super::layout_buffer::{DoubleBuffer, LayoutBuffer};
+use super::repair::TreeRepair;
+
+use crate::geometry::{Line, Point, Size};
+use crate::style::{
+    AvailableSpace, CompactLength, Dimension, Display, Position, ResponsiveStyle, RoundingOrigin, Style,
+};
 use crate::sys::DefaultCheapStr;
 use crate::tree::{
-    Cache, ClearState, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, PrintTree, RoundTree, RunMode,
-    TraversePartialTree, TraverseTree,
+    Cache, ClearState, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutWarning, NodeId, NodeKey, PrintTree,
+    RequestedAxis, RoundTree, RunMode, SizingMode, TraversePartialTree, TraverseTree,
 };
-use crate::util::debug::{debug_log, debug_log_node};
-use crate::util::sys::{new_vec_with_capacity, ChildrenVec, Vec};
+use crate::util::debug::{debug_log, debug_log_node, with_panic_context};
+use crate::util::sys::{format, new_vec_with_capacity, ChildrenVec, Map, Set, Vec};
 
+use crate::compute::work_stack;
 use crate::compute::{
     compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
 };
@@ -26,6 +34,8 @@ use crate::{compute::compute_flexbox_layout, LayoutFlexboxContainer};
 #[cfg(feature = "grid")]
 use crate::{compute::compute_grid_layout, LayoutGridContainer};
 
+#[cfg(all(feature = "detailed_layout_info", feature = "flexbox"))]
+use crate::compute::flexbox::DetailedFlexboxInfo;
 #[cfg(all(feature = "detailed_layout_info", feature = "grid"))]
 use crate::compute::grid::DetailedGridInfo;
 #[cfg(feature = "detailed_layout_info")]
@@ -35,7 +45,10 @@ use crate::tree::layout::DetailedLayoutInfo;
 pub type TaffyResult<T> = Result<T, TaffyError>;
 
 /// An error that occurs while trying to access or modify a node's children by index.
+///
+/// New variants may be added in minor releases, so matches on this enum must include a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TaffyError {
     /// The parent node does not have a child at `child_index`. It only has `child_count` children
     ChildIndexOutOfBounds {
@@ -52,6 +65,18 @@ pub enum TaffyError {
     InvalidChildNode(NodeId),
     /// The supplied node was not found in the [`TaffyTree`](crate::TaffyTree) instance.
     InvalidInputNode(NodeId),
+    /// The supplied [`NodeKey`] is already the key of another node in the [`TaffyTree`](crate::TaffyTree) instance.
+    DuplicateNodeKey(NodeKey),
+    /// The supplied node is (or would become) its own ancestor
+    Cycle(NodeId),
+    /// The node at the supplied index of a [`CapturedTree`](crate::CapturedTree) is missing, has a child index which
+    /// doesn't refer to a later node or refers to the child of another node, or isn't the child of any node
+    #[cfg(feature = "capture")]
+    InvalidCapturedNode(usize),
+    /// The fixture passed to [`CapturedTree::parse`](crate::CapturedTree::parse) isn't a valid captured tree. Contains
+    /// the error from the JSON parser.
+    #[cfg(feature = "capture")]
+    InvalidCapture(crate::util::sys::String),
 }
 
 impl core::fmt::Display for TaffyError {
@@ -65,6 +90,16 @@ impl core::fmt::Display for TaffyError {
             }
             TaffyError::InvalidChildNode(child) => write!(f, "Child Node {child:?} is not in the TaffyTree instance"),
             TaffyError::InvalidInputNode(node) => write!(f, "Supplied Node {node:?} is not in the TaffyTree instance"),
+            TaffyError::DuplicateNodeKey(key) => {
+                write!(f, "Key {key:?} is already used by another node in the TaffyTree instance")
+            }
+            TaffyError::Cycle(node) => write!(f, "Node {node:?} is its own ancestor"),
+            #[cfg(feature = "capture")]
+            TaffyError::InvalidCapturedNode(index) => {
+                write!(f, "Captured node {index} is missing or is not part of a valid tree")
+            }
+            #[cfg(feature = "capture")]
+            TaffyError::InvalidCapture(error) => write!(f, "Invalid captured tree: {error}"),
         }
     }
 }
@@ -72,16 +107,43 @@ impl core::fmt::Display for TaffyError {
 #[cfg(feature = "std")]
 impl std::error::Error for TaffyError {}
 
+/// How the content size of a leaf node without a node context or intrinsic size is determined
+///
+/// The node's size styles, padding and border are applied as usual on top of this content size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LeafFallbackSize {
+    /// The content size collapses to zero, so that the node's size is determined by its padding and border (the CSS behaviour)
+    #[default]
+    Collapse,
+    /// The content size stretches to fill the available space in each axis where the available space is definite
+    Stretch,
+}
+
 /// Global configuration values for a TaffyTree instance
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct TaffyConfig {
     /// Whether to round layout values
     pub(crate) use_rounding: bool,
+    /// How leaf nodes without a node context or intrinsic size are sized
+    pub(crate) leaf_fallback_size: LeafFallbackSize,
+    /// The maximum number of times a tree is laid out in a single layout computation to resolve container queries
+    pub(crate) max_container_query_passes: usize,
+    /// Whether to report a warning for each orphaned node which a layout computation doesn't reach
+    pub(crate) warn_about_orphans: bool,
+    /// Whether percentage padding and border of items resolve against the width of their containing block in both axes
+    pub(crate) inline_percentage_padding: bool,
 }
 
 impl Default for TaffyConfig {
     fn default() -> Self {
-        Self { use_rounding: true }
+        Self {
+            use_rounding: true,
+            leaf_fallback_size: LeafFallbackSize::Collapse,
+            max_container_query_passes: 4,
+            warn_about_orphans: false,
+            inline_percentage_padding: true,
+        }
     }
 }
 
@@ -104,9 +166,25 @@ struct NodeData {
     /// Whether the node has context data associated with it or not
     pub(crate) has_context: bool,
 
+    /// A static content size which is used in place of the measure function for each axis where it is set
+    pub(crate) intrinsic_size: Size<Option<f32>>,
+
+    /// The persistent key of the node, if it has one
+    pub(crate) key: Option<NodeKey>,
+
+    /// Whether the node is a root which is expected to have no parent (see [`TaffyTree::register_root`])
+    pub(crate) is_registered_root: bool,
+
+    /// The position of the node in a pre-order traversal of its tree (see [`TaffyTree::paint_order`])
+    pub(crate) paint_order: u32,
+
     /// The cached results of the layout computation
     pub(crate) cache: Cache,
 
+    /// Whether the node has been modified since its layout was last set. Unlike an empty cache, this is not cleared
+    /// by passes which only measure the node (such as [`TaffyTree::measure`]).
+    pub(crate) needs_layout: bool,
+
     /// The computation result from layout algorithm
     #[cfg(feature = "detailed_layout_info")]
     pub(crate) detailed_layout_info: DetailedLayoutInfo,
@@ -119,9 +197,14 @@ impl NodeData {
         Self {
             style,
             cache: Cache::new(),
+            needs_layout: true,
             unrounded_layout: Layout::new(),
             final_layout: Layout::new(),
             has_context: false,
+            intrinsic_size: Size::NONE,
+            key: None,
+            is_registered_root: false,
+            paint_order: 0,
             #[cfg(feature = "detailed_layout_info")]
             detailed_layout_info: DetailedLayoutInfo::None,
         }
@@ -133,6 +216,7 @@ impl NodeData {
     /// If the node was already marked as dirty, returns true
     #[inline]
     pub fn mark_dirty(&mut self) -> ClearState {
+        self.needs_layout = true;
         self.cache.clear()
     }
 }
@@ -158,10 +242,60 @@ pub struct TaffyTree<NodeContext = ()> {
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     parents: SlotMap<DefaultKey, Option<NodeId>>,
 
+    /// The children of each node that has `Display::Contents` children, with those children replaced by their own children
+    ///
+    /// This is the list of children presented to layout algorithms. It is rebuilt each time the node's layout is computed.
+    layout_children: SecondaryMap<DefaultKey, ChildrenVec<NodeId>>,
+
+    /// The responsive style of each node that has one, from which its style is resolved at the start of each layout
+    responsive_styles: SecondaryMap<DefaultKey, ResponsiveStyle>,
+
+    /// The container query of each node that has one, from which its style is resolved based on its containing block
+    container_queries: SecondaryMap<DefaultKey, ResponsiveStyle>,
+
+    /// The anchor of each scroll container that has one
+    scroll_anchors: SecondaryMap<DefaultKey, ScrollAnchor>,
+
+    /// The node with each [`NodeKey`]
+    keys: Map<NodeKey, NodeId>,
+
+    /// The warnings reported during the most recent layout computation
+    warnings: Vec<(NodeId, LayoutWarning)>,
+
+    /// The node and [`LayoutWarning::kind`] of the warnings in `warnings` which are reported at most once per node
+    reported_once: Set<(u64, u8)>,
+
+    /// The stack used to number nodes in paint order, kept between layout passes to reuse its allocation
+    paint_order_stack: Vec<NodeId>,
+
     /// Layout mode configuration
     config: TaffyConfig,
+
+    /// The edits made to the tree since the journal was enabled or last taken, if journaling is enabled
+    journal: Option<Vec<TreeEdit>>,
+
+    /// The buffer which completed layouts are published to, if double buffering is enabled
+    #[cfg(feature = "std")]
+    double_buffer: Option<DoubleBuffer>,
 }
 
+/// The anchor of a scroll container, see [`TaffyTree::set_scroll_anchor`]
+#[derive(Debug, Clone, Copy)]
+struct ScrollAnchor {
+    /// The descendant of the container which should stay visually fixed
+    node: NodeId,
+    /// The offset of the anchor from the container in the most recent layout, if it is a descendant of the container
+    offset: Option<Point<f32>>,
+    /// How far the anchor moved relative to the container in the most recent layout
+    adjustment: Point<f32>,
+}
+
+/// An alias for [`TaffyTree`], which was previously called `Taffy`
+///
+/// Provided so that code written against the older name continues to compile. New code should use [`TaffyTree`].
+#[deprecated(note = "use TaffyTree")]
+pub type Taffy<NodeContext = ()> = TaffyTree<NodeContext>;
+
 impl Default for TaffyTree {
     fn default() -> TaffyTree<()> {
         TaffyTree::new()
@@ -229,7 +363,7 @@ impl<NodeContext> CacheTree for TaffyTree<NodeContext> {
     }
 
     fn cache_clear(&mut self, node_id: NodeId) {
-        self.nodes[node_id.into()].cache.clear();
+        self.nodes[node_id.into()].mark_dirty();
     }
 }
 
@@ -243,6 +377,7 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
 
         match (num_children, display) {
             (_, Display::None) => "NONE",
+            (_, Display::Contents) => "CONTENTS",
             (0, _) => "LEAF",
             #[cfg(feature = "block_layout")]
             (_, Display::Block) => "BLOCK",
@@ -269,26 +404,429 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
     }
 }
 
-/// View over the Taffy tree that holds the tree itself along with a reference to the context
-/// and implements LayoutTree. This allows the context to be stored outside of the TaffyTree struct
-/// which makes the lifetimes of the context much more flexible.
-pub(crate) struct TaffyView<'t, NodeContext, MeasureFunction>
+/// How a [`TaffyView`] accesses the tree it lays out: where the results of layout are written, and how leaves are
+/// measured
+pub(crate) trait TreeAccess {
+    /// The type of the context attached to the nodes of the tree
+    type NodeContext;
+
+    /// The tree being laid out
+    fn taffy(&self) -> &TaffyTree<Self::NodeContext>;
+    /// The flattened children of the nodes which have `Display::Contents` children
+    fn layout_children(&self) -> &SecondaryMap<DefaultKey, ChildrenVec<NodeId>>;
+    /// Mutable access to the flattened children of the nodes which have `Display::Contents` children
+    fn layout_children_mut(&mut self) -> &mut SecondaryMap<DefaultKey, ChildrenVec<NodeId>>;
+    /// Lays out a leaf, passing its context to the measure function
+    fn compute_leaf_layout(&mut self, node: NodeId, inputs: LayoutInput) -> LayoutOutput;
+    /// Gives a `Display::Contents` node, which doesn't generate a box, an empty layout at the origin of its parent
+    fn set_contents_layout(&mut self, node: NodeId, order: u32);
+    /// Sets the unrounded layout of the node
+    fn set_unrounded_layout(&mut self, node: NodeId, layout: &Layout);
+    /// Sets the detailed layout info of the node
+    #[cfg(feature = "detailed_layout_info")]
+    fn set_detailed_layout_info(&mut self, node: NodeId, detailed_layout_info: DetailedLayoutInfo);
+    /// See [`CacheTree::cache_get`]
+    fn cache_get(
+        &self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+    ) -> Option<LayoutOutput>;
+    /// See [`CacheTree::cache_store`]
+    fn cache_store(
+        &mut self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+        layout_output: LayoutOutput,
+    );
+    /// See [`CacheTree::cache_clear`]
+    fn cache_clear(&mut self, node: NodeId);
+    /// Records a warning about the node
+    fn report_warning(&mut self, node: NodeId, warning: LayoutWarning);
+    /// Whether a warning which is reported at most once for each node (see [`LayoutWarning::is_reported_once`]) should
+    /// be reported, which is only the case the first time this is called for the node and kind during a layout
+    fn report_once(&mut self, node: NodeId, warning: &LayoutWarning) -> bool;
+}
+
+/// Lays out a leaf of the tree, calling the measure function with `node_context` unless the node's size is known
+fn compute_taffy_leaf_layout<NodeContext, MeasureFunction>(
+    node_data: &NodeData,
+    config: &TaffyConfig,
+    node: NodeId,
+    inputs: LayoutInput,
+    node_context: Option<NodeContext>,
+    measure_function: MeasureFunction,
+) -> LayoutOutput
+where
+    MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<NodeContext>, &Style) -> Size<f32>,
+{
+    let style = &node_data.style;
+    let intrinsic_size = node_data.intrinsic_size;
+    let stretch_if_unmeasured = !node_data.has_context
+        && intrinsic_size == Size::NONE
+        && config.leaf_fallback_size == LeafFallbackSize::Stretch;
+    let measure_function = |known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>| {
+        // A static intrinsic size takes the place of the measure function in each axis where it is set
+        let known_dimensions = known_dimensions.or(intrinsic_size);
+        if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+            return Size { width, height };
+        }
+        if stretch_if_unmeasured {
+            return known_dimensions.or(available_space.into_options()).unwrap_or(Size::ZERO);
+        }
+        let measured_size = measure_function(known_dimensions, available_space, node, node_context, style);
+        known_dimensions.unwrap_or(measured_size)
+    };
+    // TODO: implement calc() in high-level API
+    compute_leaf_layout(inputs, style, |_, _| 0.0, measure_function)
+}
+
+/// Exclusive access to the tree, which stores the results of layout in the tree itself
+pub(crate) struct ExclusiveAccess<'t, NodeContext, MeasureFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
 {
     /// A reference to the TaffyTree
-    pub(crate) taffy: &'t mut TaffyTree<NodeContext>,
-    /// The context provided for passing to measure functions if layout is run over this struct
-    pub(crate) measure_function: MeasureFunction,
+    taffy: &'t mut TaffyTree<NodeContext>,
+    /// The function used to measure leaf nodes, which is given mutable access to their context
+    measure_function: MeasureFunction,
 }
 
-// TraversePartialTree impl for TaffyView
-impl<NodeContext, MeasureFunction> TraversePartialTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction> TreeAccess for ExclusiveAccess<'_, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+{
+    type NodeContext = NodeContext;
+
+    #[inline(always)]
+    fn taffy(&self) -> &TaffyTree<NodeContext> {
+        self.taffy
+    }
+
+    #[inline(always)]
+    fn layout_children(&self) -> &SecondaryMap<DefaultKey, ChildrenVec<NodeId>> {
+        &self.taffy.layout_children
+    }
+
+    #[inline(always)]
+    fn layout_children_mut(&mut self) -> &mut SecondaryMap<DefaultKey, ChildrenVec<NodeId>> {
+        &mut self.taffy.layout_children
+    }
+
+    fn compute_leaf_layout(&mut self, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+        let node_key = node.into();
+        let node_data = &self.taffy.nodes[node_key];
+        let node_context = node_data.has_context.then(|| self.taffy.node_context_data.get_mut(node_key)).flatten();
+        compute_taffy_leaf_layout(node_data, &self.taffy.config, node, inputs, node_context, &mut self.measure_function)
+    }
+
+    #[inline(always)]
+    fn set_contents_layout(&mut self, node: NodeId, order: u32) {
+        let node_data = &mut self.taffy.nodes[node.into()];
+        node_data.unrounded_layout = Layout::with_order(order);
+        node_data.final_layout = Layout::with_order(order);
+        node_data.needs_layout = false;
+    }
+
+    #[inline(always)]
+    fn set_unrounded_layout(&mut self, node: NodeId, layout: &Layout) {
+        let node_data = &mut self.taffy.nodes[node.into()];
+        node_data.unrounded_layout = *layout;
+        node_data.needs_layout = false;
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "detailed_layout_info")]
+    fn set_detailed_layout_info(&mut self, node: NodeId, detailed_layout_info: DetailedLayoutInfo) {
+        self.taffy.nodes[node.into()].detailed_layout_info = detailed_layout_info;
+    }
+
+    fn cache_get(
+        &self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+    ) -> Option<LayoutOutput> {
+        self.taffy.nodes[node.into()].cache.get(known_dimensions, available_space, run_mode)
+    }
+
+    fn cache_store(
+        &mut self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+        layout_output: LayoutOutput,
+    ) {
+        self.taffy.nodes[node.into()].cache.store(known_dimensions, available_space, run_mode, layout_output)
+    }
+
+    fn cache_clear(&mut self, node: NodeId) {
+        self.taffy.nodes[node.into()].mark_dirty();
+    }
+
+    #[inline(always)]
+    fn report_warning(&mut self, node: NodeId, warning: LayoutWarning) {
+        self.taffy.warnings.push((node, warning));
+    }
+
+    #[inline(always)]
+    fn report_once(&mut self, node: NodeId, warning: &LayoutWarning) -> bool {
+        self.taffy.reported_once.insert((node.into(), warning.kind()))
+    }
+}
+
+/// Shared access to the tree, used to compute sizes without modifying it
+///
+/// Layouts and cache entries which would be written to the tree are kept in scratch storage instead, which is dropped
+/// along with the view, and warnings are discarded. Cache entries already stored in the tree are still reused, as they
+/// can only be present if the node hasn't been modified since they were computed.
+pub(crate) struct SharedAccess<'t, NodeContext, MeasureFunction>
+where
+    MeasureFunction: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&NodeContext>, &Style) -> Size<f32>,
+{
+    /// A reference to the TaffyTree
+    taffy: &'t TaffyTree<NodeContext>,
+    /// The function used to measure leaf nodes, which is given shared access to their context
+    measure_function: MeasureFunction,
+    /// The caches of the nodes which have been laid out through this access
+    caches: SecondaryMap<DefaultKey, Cache>,
+    /// The flattened children of the nodes which have `Display::Contents` children
+    layout_children: SecondaryMap<DefaultKey, ChildrenVec<NodeId>>,
+}
+
+impl<NodeContext, MeasureFunction> TreeAccess for SharedAccess<'_, NodeContext, MeasureFunction>
+where
+    MeasureFunction: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&NodeContext>, &Style) -> Size<f32>,
+{
+    type NodeContext = NodeContext;
+
+    #[inline(always)]
+    fn taffy(&self) -> &TaffyTree<NodeContext> {
+        self.taffy
+    }
+
+    #[inline(always)]
+    fn layout_children(&self) -> &SecondaryMap<DefaultKey, ChildrenVec<NodeId>> {
+        &self.layout_children
+    }
+
+    #[inline(always)]
+    fn layout_children_mut(&mut self) -> &mut SecondaryMap<DefaultKey, ChildrenVec<NodeId>> {
+        &mut self.layout_children
+    }
+
+    fn compute_leaf_layout(&mut self, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+        let node_key = node.into();
+        let node_data = &self.taffy.nodes[node_key];
+        let node_context = node_data.has_context.then(|| self.taffy.node_context_data.get(node_key)).flatten();
+        compute_taffy_leaf_layout(node_data, &self.taffy.config, node, inputs, node_context, &mut self.measure_function)
+    }
+
+    #[inline(always)]
+    fn set_contents_layout(&mut self, _node: NodeId, _order: u32) {}
+
+    #[inline(always)]
+    fn set_unrounded_layout(&mut self, _node: NodeId, _layout: &Layout) {}
+
+    #[inline(always)]
+    #[cfg(feature = "detailed_layout_info")]
+    fn set_detailed_layout_info(&mut self, _node: NodeId, _detailed_layout_info: DetailedLayoutInfo) {}
+
+    fn cache_get(
+        &self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+    ) -> Option<LayoutOutput> {
+        let scratch_output =
+            self.caches.get(node.into()).and_then(|cache| cache.get(known_dimensions, available_space, run_mode));
+        scratch_output.or_else(|| self.taffy.nodes[node.into()].cache.get(known_dimensions, available_space, run_mode))
+    }
+
+    fn cache_store(
+        &mut self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+        layout_output: LayoutOutput,
+    ) {
+        if !self.caches.contains_key(node.into()) {
+            self.caches.insert(node.into(), Cache::new());
+        }
+        self.caches[node.into()].store(known_dimensions, available_space, run_mode, layout_output)
+    }
+
+    fn cache_clear(&mut self, node: NodeId) {
+        self.caches.remove(node.into());
+    }
+
+    #[inline(always)]
+    fn report_warning(&mut self, _node: NodeId, _warning: LayoutWarning) {}
+
+    #[inline(always)]
+    fn report_once(&mut self, _node: NodeId, _warning: &LayoutWarning) -> bool {
+        false
+    }
+}
+
+/// View over the Taffy tree which implements the layout traits, with exclusive or shared access to the tree (see
+/// [`TreeAccess`]). This allows the context to be stored outside of the TaffyTree struct, which makes the lifetimes of
+/// the context much more flexible.
+pub(crate) struct TaffyView<Access: TreeAccess> {
+    /// The access to the tree being laid out
+    access: Access,
+}
+
+impl<'t, NodeContext, MeasureFunction> TaffyView<ExclusiveAccess<'t, NodeContext, MeasureFunction>>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
 {
+    /// A view which stores the results of layout in the tree
+    #[inline(always)]
+    pub(crate) fn exclusive(taffy: &'t mut TaffyTree<NodeContext>, measure_function: MeasureFunction) -> Self {
+        Self { access: ExclusiveAccess { taffy, measure_function } }
+    }
+}
+
+impl<'t, NodeContext, MeasureFunction> TaffyView<SharedAccess<'t, NodeContext, MeasureFunction>>
+where
+    MeasureFunction: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&NodeContext>, &Style) -> Size<f32>,
+{
+    /// A view which keeps the results of layout in scratch storage instead of modifying the tree
+    #[inline(always)]
+    pub(crate) fn shared(taffy: &'t TaffyTree<NodeContext>, measure_function: MeasureFunction) -> Self {
+        Self {
+            access: SharedAccess {
+                taffy,
+                measure_function,
+                caches: SecondaryMap::new(),
+                layout_children: SecondaryMap::new(),
+            },
+        }
+    }
+}
+
+impl<Access: TreeAccess> TaffyView<Access> {
+    /// The children of the node as presented to layout algorithms (with `Display::Contents` children flattened)
+    #[inline(always)]
+    fn layout_children(&self, node: NodeId) -> &[NodeId] {
+        let layout_children = self.access.layout_children();
+        if !layout_children.is_empty() {
+            if let Some(children) = layout_children.get(node.into()) {
+                return children;
+            }
+        }
+        &self.access.taffy().children[node.into()]
+    }
+
+    /// Rebuild the flattened list of children of the node if any of its children are `Display::Contents`
+    fn update_layout_children(&mut self, node: NodeId) {
+        let key = node.into();
+        let taffy = self.access.taffy();
+        let is_contents = |child: &NodeId| taffy.nodes[(*child).into()].style.display == Display::Contents;
+        if !taffy.children[key].iter().any(is_contents) {
+            let layout_children = self.access.layout_children_mut();
+            if !layout_children.is_empty() {
+                layout_children.remove(key);
+            }
+            return;
+        }
+
+        let mut layout_children = self.access.layout_children_mut().remove(key).unwrap_or_default();
+        layout_children.clear();
+        let taffy = self.access.taffy();
+        let is_contents = |child: &NodeId| taffy.nodes[(*child).into()].style.display == Display::Contents;
+
+        // Replace each `Display::Contents` child with its own children, remembering its position among its siblings
+        let mut contents_nodes = Vec::new();
+        let with_order = |children: &ChildrenVec<NodeId>| {
+            children.iter().enumerate().rev().map(|(order, &child)| (child, order as u32)).collect::<Vec<_>>()
+        };
+        let mut stack = with_order(&taffy.children[key]);
+        while let Some((child, order)) = stack.pop() {
+            if is_contents(&child) {
+                contents_nodes.push((child, order));
+                stack.extend(with_order(&taffy.children[child.into()]));
+            } else {
+                layout_children.push(child);
+            }
+        }
+
+        for (contents_node, order) in contents_nodes {
+            self.access.set_contents_layout(contents_node, order);
+        }
+        self.access.layout_children_mut().insert(key, layout_children);
+    }
+
+    /// Dispatches to the layout algorithm for the node's display style, for a layout which isn't in the cache
+    fn compute_uncached_child_layout(&mut self, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+        let display_mode = self.access.taffy().nodes[node.into()].style.display;
+        self.update_layout_children(node);
+        let has_children = self.child_count(node) > 0;
+
+        debug_log!(display_mode);
+        debug_log_node!(
+            inputs.known_dimensions,
+            inputs.parent_size,
+            inputs.available_space,
+            inputs.run_mode,
+            inputs.sizing_mode
+        );
+
+        // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
+        let output = match (display_mode, has_children) {
+            (Display::None | Display::Contents, _) => {
+                self.access.layout_children_mut().remove(node.into());
+                compute_hidden_layout(self, node)
+            }
+            #[cfg(feature = "block_layout")]
+            (Display::Block, true) => compute_block_layout(self, node, inputs),
+            #[cfg(feature = "flexbox")]
+            (Display::Flex, true) => compute_flexbox_layout(self, node, inputs),
+            #[cfg(feature = "grid")]
+            (Display::Grid, true) => compute_grid_layout(self, node, inputs),
+            (_, false) => self.access.compute_leaf_layout(node, inputs),
+        };
+
+        self.report_layout_warnings(node, inputs, &output);
+        output
+    }
+
+    /// Reports warnings about the node which can be detected from its style and the result of laying it out
+    fn report_layout_warnings(&mut self, node: NodeId, inputs: LayoutInput, output: &LayoutOutput) {
+        if work_stack::is_abandoned() {
+            return;
+        }
+        if inputs.run_mode == RunMode::PerformLayout
+            && (!output.size.width.is_finite() || !output.size.height.is_finite())
+        {
+            self.report_warning(node, LayoutWarning::NonFiniteSize);
+        }
+
+        // Percentages are usually resolved while the node is being sized (which may happen several times) rather than
+        // during its final layout
+        let size = self.access.taffy().nodes[node.into()].style.size;
+        let is_percent = |dimension: Dimension| dimension.into_raw().tag() == CompactLength::PERCENT_TAG;
+        if (is_percent(size.width) && inputs.parent_size.width.is_none())
+            || (is_percent(size.height) && inputs.parent_size.height.is_none())
+        {
+            self.report_warning(node, LayoutWarning::PercentageOfIndefiniteSize);
+        }
+    }
+}
+
+// TraversePartialTree impl for TaffyView
+impl<Access: TreeAccess> TraversePartialTree for TaffyView<Access> {
     type ChildIter<'a>
         = TaffyTreeChildIter<'a>
     where
@@ -296,33 +834,25 @@ where
 
     #[inline(always)]
     fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
-        self.taffy.child_ids(parent_node_id)
+        TaffyTreeChildIter(self.layout_children(parent_node_id).iter())
     }
 
     #[inline(always)]
     fn child_count(&self, parent_node_id: NodeId) -> usize {
-        self.taffy.child_count(parent_node_id)
+        self.layout_children(parent_node_id).len()
     }
 
     #[inline(always)]
     fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
-        self.taffy.get_child_id(parent_node_id, child_index)
+        self.layout_children(parent_node_id)[child_index]
     }
 }
 
 // TraverseTree impl for TaffyView
-impl<NodeContext, MeasureFunction> TraverseTree for TaffyView<'_, NodeContext, MeasureFunction> where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>
-{
-}
+impl<Access: TreeAccess> TraverseTree for TaffyView<Access> {}
 
 // LayoutPartialTree impl for TaffyView
-impl<NodeContext, MeasureFunction> LayoutPartialTree for TaffyView<'_, NodeContext, MeasureFunction>
-where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
-{
+impl<Access: TreeAccess> LayoutPartialTree for TaffyView<Access> {
     type CoreContainerStyle<'a>
         = &'a Style
     where
@@ -332,12 +862,12 @@ where
 
     #[inline(always)]
     fn get_core_container_style(&self, node_id: NodeId) -> Self::CoreContainerStyle<'_> {
-        &self.taffy.nodes[node_id.into()].style
+        &self.access.taffy().nodes[node_id.into()].style
     }
 
     #[inline(always)]
     fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
-        self.taffy.nodes[node_id.into()].unrounded_layout = *layout;
+        self.access.set_unrounded_layout(node_id, layout);
     }
 
     #[inline(always)]
@@ -345,12 +875,31 @@ where
         0.0
     }
 
+    #[inline(always)]
+    fn resolve_percentage_padding_against_inline_size(&self) -> bool {
+        self.access.taffy().config.inline_percentage_padding
+    }
+
+    #[inline(always)]
+    fn report_warning(&mut self, node_id: NodeId, warning: LayoutWarning) {
+        // Layouts which are finished with placeholders for deferred layouts are run again, so their warnings are dropped
+        if work_stack::is_abandoned() {
+            return;
+        }
+        if warning.is_reported_once() && !self.access.report_once(node_id, &warning) {
+            return;
+        }
+        self.access.report_warning(node_id, warning);
+    }
+
     #[inline(always)]
     fn compute_child_layout(&mut self, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
         // If RunMode is PerformHiddenLayout then this indicates that an ancestor node is `Display::None`
         // and thus that we should lay out this node using hidden layout regardless of it's own display style.
         if inputs.run_mode == RunMode::PerformHiddenLayout {
             debug_log!("HIDDEN");
+            // Hide `Display::Contents` children themselves as well as their children
+            self.access.layout_children_mut().remove(node.into());
             return compute_hidden_layout(self, node);
         }
 
@@ -360,48 +909,23 @@ where
         //
         // If there was no cache match and a new result needs to be computed then that result will be added to the cache
         compute_cached_layout(self, node, inputs, |tree, node, inputs| {
-            let display_mode = tree.taffy.nodes[node.into()].style.display;
-            let has_children = tree.child_count(node) > 0;
-
-            debug_log!(display_mode);
-            debug_log_node!(
-                inputs.known_dimensions,
-                inputs.parent_size,
-                inputs.available_space,
-                inputs.run_mode,
-                inputs.sizing_mode
-            );
-
-            // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
-            match (display_mode, has_children) {
-                (Display::None, _) => compute_hidden_layout(tree, node),
-                #[cfg(feature = "block_layout")]
-                (Display::Block, true) => compute_block_layout(tree, node, inputs),
-                #[cfg(feature = "flexbox")]
-                (Display::Flex, true) => compute_flexbox_layout(tree, node, inputs),
-                #[cfg(feature = "grid")]
-                (Display::Grid, true) => compute_grid_layout(tree, node, inputs),
-                (_, false) => {
-                    let node_key = node.into();
-                    let style = &tree.taffy.nodes[node_key].style;
-                    let has_context = tree.taffy.nodes[node_key].has_context;
-                    let node_context = has_context.then(|| tree.taffy.node_context_data.get_mut(node_key)).flatten();
-                    let measure_function = |known_dimensions, available_space| {
-                        (tree.measure_function)(known_dimensions, available_space, node, node_context, style)
-                    };
-                    // TODO: implement calc() in high-level API
-                    compute_leaf_layout(inputs, style, |_, _| 0.0, measure_function)
-                }
-            }
+            with_panic_context(
+                tree,
+                |tree| tree.compute_uncached_child_layout(node, inputs),
+                |tree| {
+                    let label = tree.access.taffy().get_debug_label(node);
+                    tree.report_warning(node, LayoutWarning::LayoutPanicked { label, inputs });
+                    format!(
+                        "layout of node {node:?} ({label}) panicked in run mode {:?} with inputs {inputs:?}",
+                        inputs.run_mode
+                    )
+                },
+            )
         })
     }
 }
 
-impl<NodeContext, MeasureFunction> CacheTree for TaffyView<'_, NodeContext, MeasureFunction>
-where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
-{
+impl<Access: TreeAccess> CacheTree for TaffyView<Access> {
     fn cache_get(
         &self,
         node_id: NodeId,
@@ -409,7 +933,7 @@ where
         available_space: Size<AvailableSpace>,
         run_mode: RunMode,
     ) -> Option<LayoutOutput> {
-        self.taffy.nodes[node_id.into()].cache.get(known_dimensions, available_space, run_mode)
+        self.access.cache_get(node_id, known_dimensions, available_space, run_mode)
     }
 
     fn cache_store(
@@ -420,20 +944,16 @@ where
         run_mode: RunMode,
         layout_output: LayoutOutput,
     ) {
-        self.taffy.nodes[node_id.into()].cache.store(known_dimensions, available_space, run_mode, layout_output)
+        self.access.cache_store(node_id, known_dimensions, available_space, run_mode, layout_output)
     }
 
     fn cache_clear(&mut self, node_id: NodeId) {
-        self.taffy.nodes[node_id.into()].cache.clear();
+        self.access.cache_clear(node_id);
     }
 }
 
 #[cfg(feature = "block_layout")]
-impl<NodeContext, MeasureFunction> LayoutBlockContainer for TaffyView<'_, NodeContext, MeasureFunction>
-where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
-{
+impl<Access: TreeAccess> LayoutBlockContainer for TaffyView<Access> {
     type BlockContainerStyle<'a>
         = &'a Style
     where
@@ -455,11 +975,7 @@ where
 }
 
 #[cfg(feature = "flexbox")]
-impl<NodeContext, MeasureFunction> LayoutFlexboxContainer for TaffyView<'_, NodeContext, MeasureFunction>
-where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
-{
+impl<Access: TreeAccess> LayoutFlexboxContainer for TaffyView<Access> {
     type FlexboxContainerStyle<'a>
         = &'a Style
     where
@@ -471,21 +987,23 @@ where
 
     #[inline(always)]
     fn get_flexbox_container_style(&self, node_id: NodeId) -> Self::FlexboxContainerStyle<'_> {
-        &self.taffy.nodes[node_id.into()].style
+        &self.access.taffy().nodes[node_id.into()].style
     }
 
     #[inline(always)]
     fn get_flexbox_child_style(&self, child_node_id: NodeId) -> Self::FlexboxItemStyle<'_> {
-        &self.taffy.nodes[child_node_id.into()].style
+        &self.access.taffy().nodes[child_node_id.into()].style
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "detailed_layout_info")]
+    fn set_detailed_flexbox_info(&mut self, node_id: NodeId, detailed_flexbox_info: DetailedFlexboxInfo) {
+        self.access.set_detailed_layout_info(node_id, DetailedLayoutInfo::Flexbox(Box::new(detailed_flexbox_info)));
     }
 }
 
 #[cfg(feature = "grid")]
-impl<NodeContext, MeasureFunction> LayoutGridContainer for TaffyView<'_, NodeContext, MeasureFunction>
-where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
-{
+impl<Access: TreeAccess> LayoutGridContainer for TaffyView<Access> {
     type GridContainerStyle<'a>
         = &'a Style
     where
@@ -497,35 +1015,40 @@ where
 
     #[inline(always)]
     fn get_grid_container_style(&self, node_id: NodeId) -> Self::GridContainerStyle<'_> {
-        &self.taffy.nodes[node_id.into()].style
+        &self.access.taffy().nodes[node_id.into()].style
     }
 
     #[inline(always)]
     fn get_grid_child_style(&self, child_node_id: NodeId) -> Self::GridItemStyle<'_> {
-        &self.taffy.nodes[child_node_id.into()].style
+        &self.access.taffy().nodes[child_node_id.into()].style
     }
 
     #[inline(always)]
     #[cfg(feature = "detailed_layout_info")]
     fn set_detailed_grid_info(&mut self, node_id: NodeId, detailed_grid_info: DetailedGridInfo) {
-        self.taffy.nodes[node_id.into()].detailed_layout_info = DetailedLayoutInfo::Grid(Box::new(detailed_grid_info));
+        self.access.set_detailed_layout_info(node_id, DetailedLayoutInfo::Grid(Box::new(detailed_grid_info)));
     }
 }
 
 // RoundTree impl for TaffyView
-impl<NodeContext, MeasureFunction> RoundTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction> RoundTree for TaffyView<ExclusiveAccess<'_, NodeContext, MeasureFunction>>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
 {
     #[inline(always)]
     fn get_unrounded_layout(&self, node: NodeId) -> Layout {
-        self.taffy.nodes[node.into()].unrounded_layout
+        self.access.taffy.nodes[node.into()].unrounded_layout
     }
 
     #[inline(always)]
     fn set_final_layout(&mut self, node_id: NodeId, layout: &Layout) {
-        self.taffy.nodes[node_id.into()].final_layout = *layout;
+        self.access.taffy.nodes[node_id.into()].final_layout = *layout;
+    }
+
+    #[inline(always)]
+    fn get_rounding_origin(&self, node_id: NodeId) -> RoundingOrigin {
+        self.access.taffy.nodes[node_id.into()].style.rounding_origin
     }
 }
 
@@ -549,10 +1072,27 @@ impl<NodeContext> TaffyTree<NodeContext> {
             children: SlotMap::with_capacity(capacity),
             parents: SlotMap::with_capacity(capacity),
             node_context_data: SecondaryMap::with_capacity(capacity),
+            layout_children: SecondaryMap::new(),
+            responsive_styles: SecondaryMap::new(),
+            container_queries: SecondaryMap::new(),
+            scroll_anchors: SecondaryMap::new(),
+            keys: Map::new(),
+            warnings: Vec::new(),
+            reported_once: Set::new(),
+            paint_order_stack: Vec::new(),
             config: TaffyConfig::default(),
+            journal: None,
+            #[cfg(feature = "std")]
+            double_buffer: None,
         }
     }
 
+    /// The configuration of the tree, which determines how it is laid out
+    #[cfg(feature = "capture")]
+    pub(crate) fn config(&self) -> &TaffyConfig {
+        &self.config
+    }
+
     /// Enable rounding of layout values. Rounding is enabled by default.
     pub fn enable_rounding(&mut self) {
         self.config.use_rounding = true;
@@ -563,16 +1103,129 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.config.use_rounding = false;
     }
 
-    /// Creates and adds a new unattached leaf node to the tree, and returns the node of the new node
-    pub fn new_leaf(&mut self, layout: Style) -> TaffyResult<NodeId> {
-        let id = self.nodes.insert(NodeData::new(layout));
-        let _ = self.children.insert(new_vec_with_capacity(0));
-        let _ = self.parents.insert(None);
-
-        Ok(id.into())
+    /// Resolve percentage padding and border against the width of the containing block in both axes when block, flex
+    /// and grid containers size their items, as CSS does. This is the default.
+    ///
+    /// A node always resolves its own percentage padding and border against the width of its containing block, so
+    /// with this setting containers size their items with the same padding and border that the items lay themselves
+    /// out with. It covers the padding and border which a container resolves for an item while sizing it: block
+    /// items, the stretched cross size of flex items, and grid items.
+    pub fn enable_inline_percentage_padding(&mut self) {
+        self.config.inline_percentage_padding = true;
     }
 
-    /// Creates and adds a new unattached leaf node to the tree, and returns the [`NodeId`] of the new node
+    /// Resolve the vertical percentage padding and border of items against the height of the container when block,
+    /// flex and grid containers size their items, as earlier versions of Taffy did
+    ///
+    /// This only exists for compatibility: an item whose vertical padding or border is a percentage may then be sized
+    /// differently from how it lays itself out. See [`TaffyTree::enable_inline_percentage_padding`] for which padding
+    /// and border this covers.
+    pub fn disable_inline_percentage_padding(&mut self) {
+        self.config.inline_percentage_padding = false;
+    }
+
+    /// Enable double buffering of layout output, and return the buffer which completed layouts are published to
+    ///
+    /// While double buffering is enabled, the final layout of every node is published to the returned
+    /// [`LayoutBuffer`] at the end of each call to [`TaffyTree::compute_layout`] (or one of its variants). Readers on
+    /// other threads see each layout pass all at once and never observe a tree which is only partly updated.
+    /// If double buffering is already enabled then the existing buffer is returned.
+    #[cfg(feature = "std")]
+    pub fn enable_double_buffering(&mut self) -> LayoutBuffer {
+        self.double_buffer.get_or_insert_with(DoubleBuffer::default).shared.clone()
+    }
+
+    /// Disable double buffering of layout output. Double buffering is disabled by default.
+    ///
+    /// Existing [`LayoutBuffer`]s keep the last layout that was published to them.
+    #[cfg(feature = "std")]
+    pub fn disable_double_buffering(&mut self) {
+        self.double_buffer = None;
+    }
+
+    /// Publish the layout of every node (as returned by [`TaffyTree::layout`]) to the double buffer, if double
+    /// buffering is enabled
+    fn publish_layouts(&mut self) {
+        #[cfg(feature = "std")]
+        if let Some(double_buffer) = &mut self.double_buffer {
+            let use_rounding = self.config.use_rounding;
+            double_buffer.publish(self.nodes.iter().map(|(key, node)| {
+                let layout = if use_rounding { node.final_layout } else { node.unrounded_layout };
+                (key, layout, self.parents[key], self.children[key].as_slice())
+            }));
+        }
+    }
+
+    /// Enable journaling of edits to the tree. Journaling is disabled by default.
+    ///
+    /// While journaling is enabled, every change to the children of a node and every call to
+    /// [`TaffyTree::set_style`] is recorded as a [`TreeEdit`], which can later be retrieved with
+    /// [`TaffyTree::take_journal`] and undone with [`TaffyTree::undo_edits`]. Creating and removing nodes is not
+    /// recorded, other than the removal of a node from the children of its parent: edits which refer to a node which
+    /// has since been removed from the tree can no longer be applied.
+    pub fn enable_journal(&mut self) {
+        self.journal.get_or_insert_with(Vec::new);
+    }
+
+    /// Disable journaling of edits to the tree, discarding any edits which have not been taken
+    pub fn disable_journal(&mut self) {
+        self.journal = None;
+    }
+
+    /// Returns the edits recorded since journaling was enabled or the journal was last taken, oldest first
+    ///
+    /// Returns an empty list if journaling is disabled.
+    pub fn take_journal(&mut self) -> Vec<TreeEdit> {
+        self.journal.as_mut().map(core::mem::take).unwrap_or_default()
+    }
+
+    /// Record an edit in the journal, if journaling is enabled
+    #[inline]
+    fn record_edit(&mut self, edit: TreeEdit) {
+        if let Some(journal) = &mut self.journal {
+            journal.push(edit);
+        }
+    }
+
+    /// Enable reporting a [`LayoutWarning::Orphan`] for each orphaned node (see [`TaffyTree::orphans`]) which
+    /// [`TaffyTree::compute_layout`] doesn't reach, to help find leaked nodes
+    pub fn enable_orphan_warnings(&mut self) {
+        self.config.warn_about_orphans = true;
+    }
+
+    /// Disable reporting warnings for orphaned nodes. This is the default.
+    pub fn disable_orphan_warnings(&mut self) {
+        self.config.warn_about_orphans = false;
+    }
+
+    /// Sets the maximum number of times a tree is laid out by a single call to [`TaffyTree::compute_layout`] in order to
+    /// resolve container queries. Defaults to 4.
+    ///
+    /// See [`TaffyTree::set_container_query`]. Values less than 1 are treated as 1.
+    pub fn set_max_container_query_passes(&mut self, max_container_query_passes: usize) {
+        self.config.max_container_query_passes = max_container_query_passes.max(1);
+    }
+
+    /// Sets how leaf nodes that have neither a node context nor an intrinsic size are sized. Defaults to [`LeafFallbackSize::Collapse`].
+    ///
+    /// With [`LeafFallbackSize::Stretch`] the measure function is not called for such nodes.
+    pub fn set_leaf_fallback_size(&mut self, leaf_fallback_size: LeafFallbackSize) {
+        self.config.leaf_fallback_size = leaf_fallback_size;
+        for node in self.nodes.values_mut() {
+            node.mark_dirty();
+        }
+    }
+
+    /// Creates and adds a new unattached leaf node to the tree, and returns the node of the new node
+    pub fn new_leaf(&mut self, layout: Style) -> TaffyResult<NodeId> {
+        let id = self.nodes.insert(NodeData::new(layout));
+        let _ = self.children.insert(new_vec_with_capacity(0));
+        let _ = self.parents.insert(None);
+
+        Ok(id.into())
+    }
+
+    /// Creates and adds a new unattached leaf node to the tree, and returns the [`NodeId`] of the new node
     ///
     /// Creates and adds a new leaf node with a supplied context
     pub fn new_leaf_with_context(&mut self, layout: Style, context: NodeContext) -> TaffyResult<NodeId> {
@@ -607,6 +1260,12 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        self.layout_children.clear();
+        self.responsive_styles.clear();
+        self.container_queries.clear();
+        self.scroll_anchors.clear();
+        self.keys.clear();
+        self.clear_warnings();
     }
 
     /// Remove a specific node from the tree and drop it
@@ -616,7 +1275,10 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let key = node.into();
         if let Some(parent) = self.parents[key] {
             if let Some(children) = self.children.get_mut(parent.into()) {
-                children.retain(|f| *f != node);
+                if let Some(child_index) = children.iter().position(|&child| child == node) {
+                    children.remove(child_index);
+                    self.record_edit(TreeEdit::RemoveChild { parent, child_index, child: node });
+                }
             }
         }
 
@@ -629,11 +1291,51 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
         let _ = self.children.remove(key);
         let _ = self.parents.remove(key);
-        let _ = self.nodes.remove(key);
+        if let Some(node_key) = self.nodes.remove(key).and_then(|data| data.key) {
+            self.keys.remove(&node_key);
+        }
+        let _ = self.layout_children.remove(key);
+        let _ = self.responsive_styles.remove(key);
+        let _ = self.container_queries.remove(key);
+        let _ = self.scroll_anchors.remove(key);
+        self.scroll_anchors.retain(|_, anchor| anchor.node != node);
 
         Ok(node)
     }
 
+    /// Sets the persistent [`NodeKey`] of the node, or removes its key if `key` is `None`
+    ///
+    /// Fails with [`TaffyError::DuplicateNodeKey`] if another node already has the key.
+    pub fn set_node_key(&mut self, node: NodeId, key: Option<NodeKey>) -> TaffyResult<()> {
+        if let Some(key) = key {
+            match self.keys.get(&key) {
+                Some(&existing) if existing != node => return Err(TaffyError::DuplicateNodeKey(key)),
+                _ => {}
+            }
+        }
+
+        let node_data = &mut self.nodes[node.into()];
+        if let Some(old_key) = core::mem::replace(&mut node_data.key, key) {
+            self.keys.remove(&old_key);
+        }
+        if let Some(key) = key {
+            self.keys.insert(key, node);
+        }
+        Ok(())
+    }
+
+    /// Gets the persistent [`NodeKey`] of the node, if it has one
+    #[inline]
+    pub fn node_key(&self, node: NodeId) -> TaffyResult<Option<NodeKey>> {
+        Ok(self.nodes[node.into()].key)
+    }
+
+    /// Finds the node with the given persistent [`NodeKey`]
+    #[inline]
+    pub fn node_by_key(&self, key: NodeKey) -> Option<NodeId> {
+        self.keys.get(&key).copied()
+    }
+
     /// Sets the context data associated with the node
     #[inline]
     pub fn set_node_context(&mut self, node: NodeId, measure: Option<NodeContext>) -> TaffyResult<()> {
@@ -651,6 +1353,24 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(())
     }
 
+    /// Sets a static intrinsic (content) size for the node
+    ///
+    /// This is a lightweight alternative to a measure function for nodes with fixed content such as icons or sprites.
+    /// In each axis where the intrinsic size is set it is used in place of the result of the measure function. In axes
+    /// where it is `None` the measure function is called as usual (with the set axes passed as known dimensions).
+    /// Pass [`Size::NONE`] to clear the override.
+    pub fn set_intrinsic_size(&mut self, node: NodeId, intrinsic_size: Size<Option<f32>>) -> TaffyResult<()> {
+        self.nodes[node.into()].intrinsic_size = intrinsic_size;
+        self.mark_dirty(node)?;
+        Ok(())
+    }
+
+    /// Gets the static intrinsic (content) size of the node set by [`TaffyTree::set_intrinsic_size`]
+    #[inline]
+    pub fn intrinsic_size(&self, node: NodeId) -> TaffyResult<Size<Option<f32>>> {
+        Ok(self.nodes[node.into()].intrinsic_size)
+    }
+
     /// Gets a reference to the the context data associated with the node
     #[inline]
     pub fn get_node_context(&self, node: NodeId) -> Option<&NodeContext> {
@@ -671,18 +1391,33 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.node_context_data.get_disjoint_mut(keys.map(|k| k.into()))
     }
 
+    /// Returns an error if adding `child` under `parent` would make `child` its own ancestor
+    fn check_for_cycle(&self, parent: NodeId, child: NodeId) -> TaffyResult<()> {
+        if self.is_in_subtree(parent, child) {
+            return Err(TaffyError::Cycle(child));
+        }
+        Ok(())
+    }
+
     /// Adds a `child` node under the supplied `parent`
+    ///
+    /// Fails with [`TaffyError::Cycle`] if `child` is `parent` or one of its ancestors.
     pub fn add_child(&mut self, parent: NodeId, child: NodeId) -> TaffyResult<()> {
+        self.check_for_cycle(parent, child)?;
         let parent_key = parent.into();
         let child_key = child.into();
         self.parents[child_key] = Some(parent);
         self.children[parent_key].push(child);
+        let child_index = self.children[parent_key].len() - 1;
+        self.record_edit(TreeEdit::InsertChild { parent, child_index, child });
         self.mark_dirty(parent)?;
 
         Ok(())
     }
 
     /// Inserts a `child` node at the given `child_index` under the supplied `parent`, shifting all children after it to the right.
+    ///
+    /// Fails with [`TaffyError::Cycle`] if `child` is `parent` or one of its ancestors.
     pub fn insert_child_at_index(&mut self, parent: NodeId, child_index: usize, child: NodeId) -> TaffyResult<()> {
         let parent_key = parent.into();
 
@@ -690,22 +1425,38 @@ impl<NodeContext> TaffyTree<NodeContext> {
         if child_index > child_count {
             return Err(TaffyError::ChildIndexOutOfBounds { parent, child_index, child_count });
         }
+        self.check_for_cycle(parent, child)?;
 
         self.parents[child.into()] = Some(parent);
         self.children[parent_key].insert(child_index, child);
+        self.record_edit(TreeEdit::InsertChild { parent, child_index, child });
         self.mark_dirty(parent)?;
 
         Ok(())
     }
 
     /// Directly sets the `children` of the supplied `parent`
+    ///
+    /// Fails with [`TaffyError::Cycle`] (leaving the children unchanged) if any child is `parent` or one of its
+    /// ancestors.
     pub fn set_children(&mut self, parent: NodeId, children: &[NodeId]) -> TaffyResult<()> {
+        for &child in children {
+            self.check_for_cycle(parent, child)?;
+        }
         let parent_key = parent.into();
 
         // Remove node as parent from all its current children.
         for child in &self.children[parent_key] {
             self.parents[(*child).into()] = None;
         }
+        if let Some(journal) = &mut self.journal {
+            let old_children = self.children[parent_key].iter().enumerate().rev();
+            journal.extend(old_children.map(|(child_index, &child)| TreeEdit::RemoveChild {
+                parent,
+                child_index,
+                child,
+            }));
+        }
 
         // Build up relation node <-> child
         for &child in children {
@@ -719,12 +1470,74 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let parent_children = &mut self.children[parent_key];
         parent_children.clear();
         children.iter().for_each(|child| parent_children.push(*child));
+        if let Some(journal) = &mut self.journal {
+            let new_children = children.iter().enumerate();
+            journal.extend(new_children.map(|(child_index, &child)| TreeEdit::InsertChild {
+                parent,
+                child_index,
+                child,
+            }));
+        }
 
         self.mark_dirty(parent)?;
 
         Ok(())
     }
 
+    /// Updates the children of `parent` to be the nodes with the given persistent `keys`, in order
+    ///
+    /// This is intended for retained-mode frameworks which describe their children by key on every update:
+    ///
+    /// - The node with each key is reused if one exists, and moved here if it currently has another parent.
+    /// - `spawn` is called to create a node for each key that no node has yet. The key is set on the new node.
+    /// - Current children that aren't given (including children without a key) are detached from `parent` and then
+    ///   passed to `despawn`, which will typically [remove](TaffyTree::remove) them.
+    ///
+    /// `parent` is only marked dirty (once) if its children change, and the children that are kept are not marked
+    /// dirty. Fails with [`TaffyError::DuplicateNodeKey`] if a key is given more than once.
+    pub fn reconcile_children(
+        &mut self,
+        parent: NodeId,
+        keys: &[NodeKey],
+        mut spawn: impl FnMut(&mut Self, NodeKey) -> TaffyResult<NodeId>,
+        mut despawn: impl FnMut(&mut Self, NodeId) -> TaffyResult<()>,
+    ) -> TaffyResult<()> {
+        // Check for duplicates up front so that no node is spawned if the keys are rejected
+        let mut seen_keys: Set<NodeKey> = Set::new();
+        if let Some(&key) = keys.iter().find(|&&key| !seen_keys.insert(key)) {
+            return Err(TaffyError::DuplicateNodeKey(key));
+        }
+
+        let mut children: Vec<NodeId> = Vec::with_capacity(keys.len());
+        let mut is_child: SecondaryMap<DefaultKey, ()> = SecondaryMap::new();
+        for &key in keys {
+            let child = match self.node_by_key(key) {
+                Some(child) => child,
+                None => {
+                    let child = spawn(self, key)?;
+                    self.set_node_key(child, Some(key))?;
+                    child
+                }
+            };
+            is_child.insert(child.into(), ());
+            children.push(child);
+        }
+
+        if self.children[parent.into()].as_slice() == children.as_slice() {
+            return Ok(());
+        }
+        let removed: Vec<NodeId> = self.children[parent.into()]
+            .iter()
+            .copied()
+            .filter(|&child| !is_child.contains_key(child.into()))
+            .collect();
+        self.set_children(parent, &children)?;
+        for child in removed {
+            despawn(self, child)?;
+        }
+        Ok(())
+    }
+
     /// Removes the `child` of the parent `node`
     ///
     /// The child is not removed from the tree entirely, it is simply no longer attached to its previous parent.
@@ -745,6 +1558,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
         let child = self.children[parent_key].remove(child_index);
         self.parents[child.into()] = None;
+        self.record_edit(TreeEdit::RemoveChild { parent, child_index, child });
 
         self.mark_dirty(parent)?;
 
@@ -761,8 +1575,16 @@ impl<NodeContext> TaffyTree<NodeContext> {
         R: core::ops::RangeBounds<usize>,
     {
         let parent_key = parent.into();
+        let child_index = match range.start_bound() {
+            core::ops::Bound::Included(&start) => start,
+            core::ops::Bound::Excluded(&start) => start + 1,
+            core::ops::Bound::Unbounded => 0,
+        };
         for child in self.children[parent_key].drain(range) {
             self.parents[child.into()] = None;
+            if let Some(journal) = &mut self.journal {
+                journal.push(TreeEdit::RemoveChild { parent, child_index, child });
+            }
         }
 
         self.mark_dirty(parent)?;
@@ -772,6 +1594,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
     /// Replaces the child at the given `child_index` from the `parent` node with the new `child` node
     ///
     /// The child is not removed from the tree entirely, it is simply no longer attached to its previous parent.
+    /// Fails with [`TaffyError::Cycle`] if `new_child` is `parent` or one of its ancestors.
     pub fn replace_child_at_index(
         &mut self,
         parent: NodeId,
@@ -784,10 +1607,13 @@ impl<NodeContext> TaffyTree<NodeContext> {
         if child_index >= child_count {
             return Err(TaffyError::ChildIndexOutOfBounds { parent, child_index, child_count });
         }
+        self.check_for_cycle(parent, new_child)?;
 
         self.parents[new_child.into()] = Some(parent);
         let old_child = core::mem::replace(&mut self.children[parent_key][child_index], new_child);
         self.parents[old_child.into()] = None;
+        self.record_edit(TreeEdit::RemoveChild { parent, child_index, child: old_child });
+        self.record_edit(TreeEdit::InsertChild { parent, child_index, child: new_child });
 
         self.mark_dirty(parent)?;
 
@@ -827,10 +1653,26 @@ impl<NodeContext> TaffyTree<NodeContext> {
     }
 
     /// Sets the [`Style`] of the provided `node`
+    ///
+    /// If only the `inset` of the node has changed (e.g. when animating the position of an absolutely positioned
+    /// node) then the node's own cached layout is kept and only its ancestors are marked dirty, as the insets are
+    /// only used by the parent when placing the node. The node's subtree will not be laid out again unless its
+    /// size changes as a result.
     #[inline]
     pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
-        self.nodes[node.into()].style = style;
-        self.mark_dirty(node)?;
+        let node_data = &mut self.nodes[node.into()];
+        if let Some(journal) = &mut self.journal {
+            journal.push(TreeEdit::SetStyle { node, old_style: node_data.style.clone(), new_style: style.clone() });
+        }
+        node_data.style.inset = style.inset;
+        let only_inset_changed = node_data.style == style;
+        node_data.style = style;
+
+        if !only_inset_changed {
+            self.mark_dirty(node)?;
+        } else if let Some(parent) = self.parents[node.into()] {
+            self.mark_dirty(parent)?;
+        }
         Ok(())
     }
 
@@ -840,6 +1682,242 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(&self.nodes[node.into()].style)
     }
 
+    /// Sets the [`ResponsiveStyle`] of the provided `node`, or removes it if `responsive_style` is `None`
+    ///
+    /// While a node has a responsive style, its [`Style`] is replaced at the start of each call to
+    /// [`TaffyTree::compute_layout`] by the style resolved for the width available to the root of its tree. Changes
+    /// made with [`TaffyTree::set_style`] are overwritten at that point, so edit the responsive style instead.
+    pub fn set_responsive_style(&mut self, node: NodeId, responsive_style: Option<ResponsiveStyle>) -> TaffyResult<()> {
+        match responsive_style {
+            Some(responsive_style) => self.responsive_styles.insert(node.into(), responsive_style),
+            None => self.responsive_styles.remove(node.into()),
+        };
+        self.mark_dirty(node)
+    }
+
+    /// Gets the [`ResponsiveStyle`] of the provided `node`, if it has one
+    #[inline]
+    pub fn responsive_style(&self, node: NodeId) -> Option<&ResponsiveStyle> {
+        self.responsive_styles.get(node.into())
+    }
+
+    /// Sets the style of each node which has a responsive style to the style resolved for `available_width`
+    ///
+    /// If `root` is set then only `root` and its descendants are updated. Nodes whose style changes are
+    /// marked dirty.
+    fn resolve_responsive_styles(&mut self, root: Option<NodeId>, available_width: AvailableSpace) -> TaffyResult<()> {
+        if self.responsive_styles.is_empty() {
+            return Ok(());
+        }
+
+        let nodes: Vec<NodeId> = self
+            .responsive_styles
+            .keys()
+            .map(NodeId::from)
+            .filter(|&node| root.map_or(true, |root| self.is_in_subtree(node, root)))
+            .collect();
+        for node in nodes {
+            self.update_resolved_style(node, available_width)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the container query of the provided `node`, or removes it if `container_query` is `None`
+    ///
+    /// A container query is a [`ResponsiveStyle`] whose breakpoints are matched against the width of the node's
+    /// containing block (the content box of its parent, or the padding box if the node is absolutely positioned)
+    /// rather than the width available to the root. This allows, for example, a card to switch from a row to a column
+    /// when its parent is narrow.
+    ///
+    /// The width of the containing block is only known once the parent has been laid out, so the tree is laid out
+    /// again whenever the style of a node with a container query changes, up to the limit set by
+    /// [`TaffyTree::set_max_container_query_passes`]. Overrides which change the width of their own containing block
+    /// may therefore not settle. As with responsive styles, changes made with [`TaffyTree::set_style`] are overwritten.
+    ///
+    /// If the node also has a responsive style, the overrides of the matching breakpoints of the container query are
+    /// applied on top of the resolved responsive style, and the base style of the container query is ignored.
+    pub fn set_container_query(&mut self, node: NodeId, container_query: Option<ResponsiveStyle>) -> TaffyResult<()> {
+        match container_query {
+            Some(container_query) => self.container_queries.insert(node.into(), container_query),
+            None => self.container_queries.remove(node.into()),
+        };
+        self.mark_dirty(node)
+    }
+
+    /// Gets the container query of the provided `node`, if it has one
+    #[inline]
+    pub fn container_query(&self, node: NodeId) -> Option<&ResponsiveStyle> {
+        self.container_queries.get(node.into())
+    }
+
+    /// Sets the style of `root` and each of its descendants which has a container query to the style resolved for
+    /// the width of its containing block in the most recent layout, returning whether any style changed
+    ///
+    /// The containing block is the nearest ancestor which generates a box, even if that ancestor is outside the
+    /// subtree being laid out. Only nodes which have no such ancestor are matched against `available_width`. Nodes
+    /// whose style changes are marked dirty.
+    fn resolve_container_queries(&mut self, root: NodeId, available_width: AvailableSpace) -> TaffyResult<bool> {
+        if self.container_queries.is_empty() {
+            return Ok(false);
+        }
+
+        let nodes: Vec<NodeId> =
+            self.container_queries.keys().map(NodeId::from).filter(|&node| self.is_in_subtree(node, root)).collect();
+        let mut changed = false;
+        for node in nodes {
+            changed |= self.update_resolved_style(node, available_width)?;
+        }
+        Ok(changed)
+    }
+
+    /// Sets the style of `node` to the style resolved from its responsive style and container query, returning
+    /// whether it changed
+    ///
+    /// The responsive style is resolved for `available_width` first, then the overrides of the matching breakpoints
+    /// of the container query are applied on top. The base style of the container query is only used if the node has
+    /// no responsive style. The node is marked dirty if its style changes.
+    fn update_resolved_style(&mut self, node: NodeId, available_width: AvailableSpace) -> TaffyResult<bool> {
+        let available_width = match available_width {
+            AvailableSpace::Definite(width) => width,
+            AvailableSpace::MinContent => 0.0,
+            AvailableSpace::MaxContent => f32::INFINITY,
+        };
+        let container_query = self.container_queries.get(node.into());
+        let mut style = match (self.responsive_styles.get(node.into()), container_query) {
+            (Some(responsive_style), _) => responsive_style.resolve(available_width),
+            (None, Some(container_query)) => container_query.base.clone(),
+            (None, None) => return Ok(false),
+        };
+        if let Some(container_query) = container_query {
+            let containing_block_width = match self.containing_block(node) {
+                Some(parent) => {
+                    let layout = &self.nodes[parent.into()].unrounded_layout;
+                    match self.nodes[node.into()].style.position {
+                        Position::Absolute => layout.size.width - layout.border.left - layout.border.right,
+                        Position::Relative => layout.content_box_width(),
+                    }
+                }
+                None => available_width,
+            };
+            container_query.apply_overrides(&mut style, containing_block_width);
+        }
+
+        if self.nodes[node.into()].style == style {
+            return Ok(false);
+        }
+        self.nodes[node.into()].style = style;
+        self.mark_dirty(node)?;
+        Ok(true)
+    }
+
+    /// Sets the anchor of the scroll container `container` to its descendant `anchor`, or removes it if `anchor` is `None`
+    ///
+    /// After each layout, [`TaffyTree::scroll_anchor_adjustment`] reports how far the anchor moved relative to the
+    /// container. Adding that to the container's scroll offset keeps the anchor visually fixed, so that (for example)
+    /// a chat log doesn't jump when messages above the visible ones are added or resized.
+    ///
+    /// The anchor's movement is measured from its position when this is called, so set the anchor after the container
+    /// has been laid out at least once.
+    pub fn set_scroll_anchor(&mut self, container: NodeId, anchor: Option<NodeId>) -> TaffyResult<()> {
+        match anchor {
+            Some(node) => {
+                let offset = self.scroll_anchor_offset(container, node);
+                self.scroll_anchors.insert(container.into(), ScrollAnchor { node, offset, adjustment: Point::ZERO })
+            }
+            None => self.scroll_anchors.remove(container.into()),
+        };
+        Ok(())
+    }
+
+    /// Gets the anchor of the scroll container `container`, if it has one
+    #[inline]
+    pub fn scroll_anchor(&self, container: NodeId) -> Option<NodeId> {
+        self.scroll_anchors.get(container.into()).map(|anchor| anchor.node)
+    }
+
+    /// The amount to add to the scroll offset of `container` to keep its anchor visually fixed after the most recent
+    /// layout of its tree
+    ///
+    /// This is zero if the container has no anchor, or if the anchor is not a descendant of the container.
+    #[inline]
+    pub fn scroll_anchor_adjustment(&self, container: NodeId) -> Point<f32> {
+        self.scroll_anchors.get(container.into()).map_or(Point::ZERO, |anchor| anchor.adjustment)
+    }
+
+    /// The location of `anchor` relative to the border box of `container`, or `None` if `anchor` is not a descendant
+    /// of `container`
+    fn scroll_anchor_offset(&self, container: NodeId, anchor: NodeId) -> Option<Point<f32>> {
+        let mut offset = Point::ZERO;
+        let mut node = anchor;
+        while node != container {
+            let location = self.layout(node).ok()?.location;
+            offset = Point { x: offset.x + location.x, y: offset.y + location.y };
+            node = self.parents[node.into()]?;
+        }
+        Some(offset)
+    }
+
+    /// Measures how far the anchor of each scroll container in the tree rooted at `root` moved in the latest layout
+    fn update_scroll_anchors(&mut self, root: NodeId) {
+        let containers: Vec<NodeId> =
+            self.scroll_anchors.keys().map(NodeId::from).filter(|&node| self.is_in_subtree(node, root)).collect();
+        for container in containers {
+            let anchor = self.scroll_anchors[container.into()];
+            let offset = self.scroll_anchor_offset(container, anchor.node);
+            let adjustment = match (anchor.offset, offset) {
+                (Some(previous), Some(current)) => Point { x: current.x - previous.x, y: current.y - previous.y },
+                _ => Point::ZERO,
+            };
+            self.scroll_anchors[container.into()] = ScrollAnchor { offset, adjustment, ..anchor };
+        }
+    }
+
+    /// The nearest ancestor of `node` which generates a box (is not `Display::Contents`)
+    fn containing_block(&self, node: NodeId) -> Option<NodeId> {
+        let mut ancestor = self.parents[node.into()];
+        while let Some(parent) = ancestor {
+            if self.nodes[parent.into()].style.display != Display::Contents {
+                return Some(parent);
+            }
+            ancestor = self.parents[parent.into()];
+        }
+        None
+    }
+
+    /// Whether `node` is `root` or one of its descendants
+    ///
+    /// The walk is bounded by the number of nodes so that it terminates even if the tree already contains a cycle.
+    fn is_in_subtree(&self, node: NodeId, root: NodeId) -> bool {
+        let mut ancestor = Some(node);
+        for _ in 0..=self.nodes.len() {
+            let Some(current) = ancestor else { return false };
+            if current == root {
+                return true;
+            }
+            ancestor = self.parents[current.into()];
+        }
+        false
+    }
+
+    /// The root of the tree which contains `node`
+    fn root_of(&self, mut node: NodeId) -> NodeId {
+        while let Some(parent) = self.parents[node.into()] {
+            node = parent;
+        }
+        node
+    }
+
+    /// The position of `node` in a pre-order (parent before children) traversal of the tree it was laid out in
+    ///
+    /// Assigned by [`TaffyTree::compute_layout`], starting from zero at the root and visiting children in the order
+    /// they were added to their parent, so a renderer can sort its draw calls by this value without traversing the
+    /// tree again. The whole tree is renumbered on every layout pass: the value of a node is stable across relayouts
+    /// as long as no nodes are added, removed or reordered before it in the traversal.
+    #[inline]
+    pub fn paint_order(&self, node: NodeId) -> TaffyResult<u32> {
+        Ok(self.nodes[node.into()].paint_order)
+    }
+
     /// Return this node layout relative to its parent
     #[inline]
     pub fn layout(&self, node: NodeId) -> TaffyResult<&Layout> {
@@ -850,6 +1928,32 @@ impl<NodeContext> TaffyTree<NodeContext> {
         }
     }
 
+    /// Sets the layout of the provided `node`, for example to restore a saved layout or one computed elsewhere
+    ///
+    /// The layout is stored as both the rounded and the unrounded layout of the node, and the node is marked clean by
+    /// caching the layout as the result of laying the node out at exactly its size. A later call to
+    /// [`TaffyTree::compute_layout`] keeps it (and the layouts of the node's descendants) as long as neither the node
+    /// nor its descendants are modified and its parent lays it out at that size, although the parent still sets its
+    /// location. The node's ancestors are marked dirty.
+    pub fn set_layout(&mut self, node: NodeId, layout: Layout) -> TaffyResult<()> {
+        if let Some(parent) = self.parents[node.into()] {
+            self.mark_dirty(parent)?;
+        }
+
+        let node_data = &mut self.nodes[node.into()];
+        node_data.unrounded_layout = layout;
+        node_data.final_layout = layout;
+        node_data.needs_layout = false;
+
+        // Cache entries which produced a size match any inputs whose known dimensions are that size
+        let available_space = layout.size.map(AvailableSpace::Definite);
+        let output = LayoutOutput::from_outer_size(layout.size);
+        node_data.cache.clear();
+        node_data.cache.store(Size::NONE, available_space, RunMode::PerformLayout, output);
+        node_data.cache.store(Size::NONE, available_space, RunMode::ComputeSize, output);
+        Ok(())
+    }
+
     /// Returns this node layout with unrounded values relative to its parent.
     #[inline]
     pub fn unrounded_layout(&self, node: NodeId) -> &Layout {
@@ -858,47 +1962,211 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Get the "detailed layout info" for a node.
     ///
-    /// Currently this is only implemented for CSS Grid containers where it contains
-    /// the computed size of each grid track and the computed placement of each grid item
+    /// This is implemented for CSS Grid containers, where it contains the computed size of each grid track and the
+    /// computed placement of each grid item, and for Flexbox containers, where it contains the flex lines
     #[cfg(feature = "detailed_layout_info")]
     #[inline]
     pub fn detailed_layout_info(&self, node_id: NodeId) -> &DetailedLayoutInfo {
         &self.nodes[node_id.into()].detailed_layout_info
     }
 
+    /// Get the size and position of each row and column of a CSS Grid container, and the placement of its items
+    ///
+    /// Returns `None` if the node is not a grid container or has not been laid out since it became one.
+    #[cfg(all(feature = "detailed_layout_info", feature = "grid"))]
+    #[inline]
+    pub fn grid_info(&self, node_id: NodeId) -> Option<&DetailedGridInfo> {
+        let node = &self.nodes[node_id.into()];
+        match &node.detailed_layout_info {
+            DetailedLayoutInfo::Grid(info) if node.style.display == Display::Grid => Some(info),
+            _ => None,
+        }
+    }
+
+    /// Get the items, size and position of each flex line of a Flexbox container
+    ///
+    /// Returns `None` if the node is not a flex container or has not been laid out since it became one.
+    #[cfg(all(feature = "detailed_layout_info", feature = "flexbox"))]
+    #[inline]
+    pub fn flexbox_info(&self, node_id: NodeId) -> Option<&DetailedFlexboxInfo> {
+        let node = &self.nodes[node_id.into()];
+        match &node.detailed_layout_info {
+            DetailedLayoutInfo::Flexbox(info) if node.style.display == Display::Flex => Some(info),
+            _ => None,
+        }
+    }
+
+    /// The nodes which don't have a parent, in storage order (which is stable, but reuses the slots of removed nodes)
+    pub(crate) fn roots(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.parents.iter().filter(|(_, parent)| parent.is_none()).map(|(key, _)| NodeId::from(key))
+    }
+
+    /// Registers the node as a root, which is expected to have no parent and so isn't one of [`TaffyTree::orphans`]
+    pub fn register_root(&mut self, node: NodeId) -> TaffyResult<()> {
+        self.nodes[node.into()].is_registered_root = true;
+        Ok(())
+    }
+
+    /// Stops treating the node as a root (see [`TaffyTree::register_root`])
+    pub fn unregister_root(&mut self, node: NodeId) -> TaffyResult<()> {
+        self.nodes[node.into()].is_registered_root = false;
+        Ok(())
+    }
+
+    /// Whether the node is registered as a root (see [`TaffyTree::register_root`])
+    pub fn is_registered_root(&self, node: NodeId) -> TaffyResult<bool> {
+        Ok(self.nodes[node.into()].is_registered_root)
+    }
+
+    /// The nodes which have no parent and aren't registered roots, in storage order
+    ///
+    /// These are usually nodes which were detached from the tree (or never attached to it) and then forgotten, so
+    /// they are never laid out but are kept until they are removed. Only the topmost node of each detached subtree
+    /// is returned. A tree which is used for a long time can be checked for such leaks by registering its roots
+    /// with [`TaffyTree::register_root`] and checking that this is empty.
+    pub fn orphans(&self) -> Vec<NodeId> {
+        self.roots().filter(|&root| !self.nodes[root.into()].is_registered_root).collect()
+    }
+
+    /// Fixes any inconsistencies between the parent links and the lists of children of the nodes, returning the fixes
+    /// which were made
+    ///
+    /// The lists of children are taken as the source of truth. Children which don't exist are removed, as are
+    /// children which are listed more than once: a node listed by several parents is kept as a child of the one its
+    /// parent link points to (or else of the first one to list it), and only its first occurrence in that list is
+    /// kept. Then the parent link of every node is set to the node which lists it, or `None` if no node does.
+    ///
+    /// Nodes whose children or parent changed are marked dirty. The fixes aren't recorded in the journal.
+    pub fn repair(&mut self) -> Vec<TreeRepair> {
+        // The parent which keeps each child
+        let mut owners: SecondaryMap<DefaultKey, NodeId> = SecondaryMap::new();
+        for (parent_key, children) in self.children.iter() {
+            let parent = NodeId::from(parent_key);
+            for &child in children.iter() {
+                let child_key = child.into();
+                let is_linked = self.parents.get(child_key) == Some(&Some(parent));
+                if self.nodes.contains_key(child_key) && (is_linked || !owners.contains_key(child_key)) {
+                    owners.insert(child_key, parent);
+                }
+            }
+        }
+
+        let mut repairs = Vec::new();
+        let mut kept: SecondaryMap<DefaultKey, ()> = SecondaryMap::new();
+        for (parent_key, children) in self.children.iter_mut() {
+            let parent = NodeId::from(parent_key);
+            children.retain(|child| {
+                let child = *child;
+                let child_key = child.into();
+                if !self.nodes.contains_key(child_key) {
+                    repairs.push(TreeRepair::RemovedMissingChild { parent, child });
+                    false
+                } else if owners.get(child_key) != Some(&parent) || kept.insert(child_key, ()).is_some() {
+                    repairs.push(TreeRepair::RemovedDuplicateChild { parent, child });
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+
+        for (key, parent) in self.parents.iter_mut() {
+            let new_parent = owners.get(key).copied();
+            if *parent != new_parent {
+                repairs.push(TreeRepair::SetParent { node: key.into(), old_parent: *parent, new_parent });
+                *parent = new_parent;
+            }
+        }
+
+        for repair in &repairs {
+            let node = match *repair {
+                TreeRepair::RemovedMissingChild { parent, .. } | TreeRepair::RemovedDuplicateChild { parent, .. } => {
+                    parent
+                }
+                TreeRepair::SetParent { node, .. } => node,
+            };
+            let _ = self.mark_dirty(node);
+        }
+
+        repairs
+    }
+
     /// Marks the layout of this node and its ancestors as outdated
+    ///
+    /// Ancestors are walked iteratively (without allocating), stopping at the first one which is already dirty.
+    /// Fails with [`TaffyError::Cycle`] if the walk visits more nodes than there are in the tree, which can only
+    /// happen if the tree contains a cycle.
     pub fn mark_dirty(&mut self, node: NodeId) -> TaffyResult<()> {
-        fn mark_dirty_recursive(
-            nodes: &mut SlotMap<DefaultKey, NodeData>,
-            parents: &SlotMap<DefaultKey, Option<NodeId>>,
-            node_key: DefaultKey,
-        ) {
-            match nodes[node_key].mark_dirty() {
-                ClearState::AlreadyEmpty => {
+        let mut node_key: DefaultKey = node.into();
+        for _ in 0..=self.nodes.len() {
+            match self.nodes[node_key].mark_dirty() {
+                ClearState::AlreadyEmpty if self.nodes[node_key].style.display != Display::Contents => {
                     // Node was already marked as dirty.
                     // No need to visit ancestors
                     // as they should be marked as dirty already.
+                    return Ok(());
                 }
-                ClearState::Cleared => {
-                    if let Some(Some(node)) = parents.get(node_key) {
-                        mark_dirty_recursive(nodes, parents, (*node).into());
-                    }
-                }
+                // `Display::Contents` nodes are never laid out themselves so their cache is always empty,
+                // but the parent that lays out their children still needs to be marked as dirty
+                ClearState::AlreadyEmpty | ClearState::Cleared => match self.parents.get(node_key) {
+                    Some(Some(parent)) => node_key = (*parent).into(),
+                    _ => return Ok(()),
+                },
             }
         }
 
-        mark_dirty_recursive(&mut self.nodes, &self.parents, node.into());
+        Err(TaffyError::Cycle(node))
+    }
+
+    /// Marks the layout of each of these nodes and their ancestors as outdated
+    ///
+    /// Ancestors which are shared between the nodes are only visited once: each walk up the tree stops at the first
+    /// ancestor which has already been marked as dirty (by an earlier node in the list or otherwise).
+    pub fn mark_dirty_many(&mut self, nodes: &[NodeId]) -> TaffyResult<()> {
+        for &node in nodes {
+            self.mark_dirty(node)?;
+        }
 
         Ok(())
     }
 
+    /// The warnings reported while computing the most recent layout (see [`LayoutWarning`])
+    ///
+    /// Warnings are only reported for nodes which were laid out again (rather than reused from the cache), so this
+    /// is not necessarily a complete list of the warnings that apply to the whole tree.
+    pub fn warnings(&self) -> &[(NodeId, LayoutWarning)] {
+        &self.warnings
+    }
+
+    /// Forget the warnings reported during the previous layout computation
+    fn clear_warnings(&mut self) {
+        self.warnings.clear();
+        self.reported_once.clear();
+    }
+
     /// Indicates whether the layout of this node needs to be recomputed
     #[inline]
     pub fn dirty(&self, node: NodeId) -> TaffyResult<bool> {
-        Ok(self.nodes[node.into()].cache.is_empty())
+        Ok(self.nodes[node.into()].needs_layout)
     }
 
     /// Updates the stored layout of the provided `node` and its children
+    ///
+    /// The measure function can't lay out (or otherwise modify) the same tree while it is being laid out: the tree is
+    /// mutably borrowed for the whole layout pass, so attempting to do so is a compile error rather than a corrupted
+    /// cache. Content which needs its own layout to be measured should be kept in a separate tree.
+    ///
+    /// ```compile_fail
+    /// # use taffy::prelude::*;
+    /// let mut taffy: TaffyTree<()> = TaffyTree::new();
+    /// let root = taffy.new_leaf(Style::default()).unwrap();
+    /// taffy
+    ///     .compute_layout_with_measure(root, Size::MAX_CONTENT, |_, available_space, node, _, _| {
+    ///         taffy.compute_layout(node, available_space).unwrap();
+    ///         taffy.layout(node).unwrap().size
+    ///     })
+    ///     .unwrap();
+    /// ```
     pub fn compute_layout_with_measure<MeasureFunction>(
         &mut self,
         node_id: NodeId,
@@ -909,30 +2177,283 @@ impl<NodeContext> TaffyTree<NodeContext> {
         MeasureFunction:
             FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
     {
-        let use_rounding = self.config.use_rounding;
-        let mut taffy_view = TaffyView { taffy: self, measure_function };
-        compute_root_layout(&mut taffy_view, node_id, available_space);
-        if use_rounding {
-            round_layout(&mut taffy_view, node_id);
+        self.resolve_responsive_styles(Some(node_id), available_space.width)?;
+        self.clear_warnings();
+        self.compute_layout_without_publishing(node_id, available_space, measure_function)?;
+        if self.config.warn_about_orphans {
+            let root = self.root_of(node_id);
+            for orphan in self.orphans().into_iter().filter(|&orphan| orphan != root) {
+                self.warnings.push((orphan, LayoutWarning::Orphan));
+            }
         }
+        self.publish_layouts();
         Ok(())
     }
 
-    /// Updates the stored layout of the provided `node` and its children
-    pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
-        self.compute_layout_with_measure(node, available_space, |_, _, _, _, _| Size::ZERO)
+    /// Updates the stored layout of the provided `node` and its children, without publishing it to the double buffer
+    ///
+    /// The tree is laid out again for as long as doing so changes the style of a node with a container query, up to
+    /// the configured maximum number of passes.
+    fn compute_layout_without_publishing<MeasureFunction>(
+        &mut self,
+        node_id: NodeId,
+        available_space: Size<AvailableSpace>,
+        mut measure_function: MeasureFunction,
+    ) -> TaffyResult<()>
+    where
+        MeasureFunction:
+            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    {
+        self.resolve_container_queries(node_id, available_space.width)?;
+        let warning_count = self.warnings.len();
+        self.compute_layout_pass(node_id, available_space, &mut measure_function);
+        for _ in 1..self.config.max_container_query_passes {
+            if !self.resolve_container_queries(node_id, available_space.width)? {
+                break;
+            }
+            // Only keep the warnings from the final pass
+            for (node, warning) in self.warnings.drain(warning_count..) {
+                self.reported_once.remove(&(node.into(), warning.kind()));
+            }
+            self.compute_layout_pass(node_id, available_space, &mut measure_function);
+        }
+        self.update_scroll_anchors(node_id);
+        Ok(())
     }
 
-    /// Prints a debug representation of the tree's layout
-    #[cfg(feature = "std")]
-    pub fn print_tree(&mut self, root: NodeId) {
-        crate::util::print_tree(self, root)
-    }
+    /// Lays out the provided `node` and its children once
+    fn compute_layout_pass<MeasureFunction>(
+        &mut self,
+        node_id: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) where
+        MeasureFunction:
+            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    {
+        let use_rounding = self.config.use_rounding;
+        let mut taffy_view = TaffyView::exclusive(self, measure_function);
+        compute_root_layout(&mut taffy_view, node_id, available_space);
+        self.assign_paint_order(node_id);
+        if use_rounding {
+            let mut taffy_view = TaffyView::exclusive(self, |_, _, _, _, _| Size::ZERO);
+            round_layout(&mut taffy_view, node_id);
+        }
+    }
+
+    /// Number the nodes of the tree rooted at `root` in pre-order (see [`TaffyTree::paint_order`])
+    fn assign_paint_order(&mut self, root: NodeId) {
+        let mut stack = core::mem::take(&mut self.paint_order_stack);
+        stack.push(root);
+        let mut paint_order = 0;
+        while let Some(node) = stack.pop() {
+            self.nodes[node.into()].paint_order = paint_order;
+            paint_order += 1;
+            // Push the children in reverse so that the first child is visited next
+            stack.extend(self.children[node.into()].iter().rev().copied());
+        }
+        self.paint_order_stack = stack;
+    }
+
+    /// Returns the topmost node of the tree rooted at `root` whose hit area contains `point`
+    ///
+    /// `point` is in the same coordinate space as the [`Layout::location`] of `root` (the border box of its parent).
+    /// The hit area of a node is its border box inset by [`Style::hit_inset`], so it can extend beyond the node's
+    /// visual bounds. Children are on top of their parent and later siblings are on top of earlier ones, as with
+    /// [`TaffyTree::paint_order`]. Nodes with `Display::None` (and their descendants) are never hit.
+    ///
+    /// The result reflects the most recent call to [`TaffyTree::compute_layout`].
+    pub fn hit_test(&self, root: NodeId, point: Point<f32>) -> Option<NodeId> {
+        // Nodes are visited in reverse paint order: each node's children (last child first) are tested before the node
+        // itself. Each entry holds the point in the space of the node's parent, and whether the node's children have
+        // already been pushed.
+        let mut stack = Vec::new();
+        stack.push((root, point, false));
+        while let Some((node, parent_point, children_visited)) = stack.pop() {
+            let display = self.nodes[node.into()].style.display;
+            if display == Display::None {
+                continue;
+            }
+
+            let layout = self.layout(node).ok()?;
+            let inset = self.nodes[node.into()].style.hit_inset;
+            let point = Point { x: parent_point.x - layout.location.x, y: parent_point.y - layout.location.y };
+            if !children_visited {
+                stack.push((node, parent_point, true));
+                stack.extend(self.children[node.into()].iter().map(|&child| (child, point, false)));
+                continue;
+            }
+
+            // `Display::Contents` nodes don't generate a box, so only their children can be hit
+            let is_hit = display != Display::Contents
+                && point.x >= inset.left
+                && point.x < layout.size.width - inset.right
+                && point.y >= inset.top
+                && point.y < layout.size.height - inset.bottom;
+            if is_hit {
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    /// Updates the stored layout of the provided `node` and its children
+    pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
+        self.compute_layout_with_measure(node, available_space, |_, _, _, _, _| Size::ZERO)
+    }
+
+    /// Updates the stored layout of every root node (node without a parent) which is dirty, and of its descendants
+    ///
+    /// Marking a node dirty also marks all of its ancestors, so the dirty roots are exactly the trees which need to
+    /// be laid out again. Each of them is laid out once, in the order in which the roots are stored, and clean roots
+    /// are skipped. The warnings of all of the roots which were laid out are available from [`TaffyTree::warnings`].
+    /// If double buffering is enabled, the layouts are published once all of the roots have been laid out.
+    pub fn compute_layout_all_roots_with_measure<MeasureFunction>(
+        &mut self,
+        available_space: Size<AvailableSpace>,
+        mut measure_function: MeasureFunction,
+    ) -> Result<(), TaffyError>
+    where
+        MeasureFunction:
+            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    {
+        self.resolve_responsive_styles(None, available_space.width)?;
+        let dirty_roots: Vec<NodeId> = self.roots().filter(|&root| self.nodes[root.into()].needs_layout).collect();
+
+        self.clear_warnings();
+        for root in dirty_roots {
+            self.compute_layout_without_publishing(root, available_space, &mut measure_function)?;
+        }
+        self.publish_layouts();
+
+        Ok(())
+    }
+
+    /// Updates the stored layout of every root node which is dirty, and of its descendants.
+    /// See [`TaffyTree::compute_layout_all_roots_with_measure`].
+    pub fn compute_layout_all_roots(&mut self, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
+        self.compute_layout_all_roots_with_measure(available_space, |_, _, _, _, _| Size::ZERO)
+    }
+
+    /// Computes the size that `node` would have if it were laid out as a root in `available_space`
+    ///
+    /// This runs the sizing passes of the layout algorithms but does not change the stored layout of any node (or whether
+    /// it needs to be laid out), which is useful for deciding where to place content (such as a tooltip or popup) before
+    /// laying it out. The sizes computed are cached, so a following call to [`TaffyTree::compute_layout`] can reuse them.
+    ///
+    /// As with [`TaffyTree::compute_layout`], the responsive styles and container queries of `node` and its
+    /// descendants are resolved first, with container queries matched against the containing blocks of the most
+    /// recent layout. Nodes whose style changes as a result are marked dirty.
+    pub fn measure_with_measure_function<MeasureFunction>(
+        &mut self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) -> TaffyResult<Size<f32>>
+    where
+        MeasureFunction:
+            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    {
+        if !self.nodes.contains_key(node.into()) {
+            return Err(TaffyError::InvalidInputNode(node));
+        }
+
+        // Resolving the styles marks the nodes whose style changes dirty, so it must happen before the layouts are
+        // saved or their stale final layouts would be put back
+        self.resolve_responsive_styles(Some(node), available_space.width)?;
+        self.resolve_container_queries(node, available_space.width)?;
+
+        // Sizing some containers (such as block containers) performs the final layout of their children, so save the
+        // layouts of the subtree to put them back afterwards. The cached final layouts are put back too, as a cached
+        // final layout is only reused if the layouts of the node's children are still the ones it computed.
+        let mut saved_layouts = Vec::new();
+        let mut stack = Vec::new();
+        stack.push(node);
+        while let Some(node) = stack.pop() {
+            let node_data = &self.nodes[node.into()];
+            let final_layout_entry = node_data.cache.final_layout_entry();
+            saved_layouts.push((node, node_data.unrounded_layout, node_data.needs_layout, final_layout_entry));
+            stack.extend(self.children[node.into()].iter().copied());
+        }
+
+        let mut taffy_view = TaffyView::exclusive(self, measure_function);
+        let inputs = LayoutInput {
+            run_mode: RunMode::ComputeSize,
+            sizing_mode: SizingMode::InherentSize,
+            axis: RequestedAxis::Both,
+            known_dimensions: Size::NONE,
+            parent_size: available_space.into_options(),
+            available_space,
+            vertical_margins_are_collapsible: Line::FALSE,
+        };
+        let mut output = LayoutOutput::HIDDEN;
+        work_stack::run_pass(&mut taffy_view, |taffy_view| output = taffy_view.compute_child_layout(node, inputs));
+
+        for (node, unrounded_layout, needs_layout, final_layout_entry) in saved_layouts {
+            let node_data = &mut self.nodes[node.into()];
+            node_data.unrounded_layout = unrounded_layout;
+            node_data.needs_layout = needs_layout;
+            node_data.cache.set_final_layout_entry(final_layout_entry);
+        }
+        Ok(output.size)
+    }
+
+    /// Computes the size that `node` would have if it were laid out as a root in `available_space`, without changing
+    /// its layout. See [`TaffyTree::measure_with_measure_function`].
+    pub fn measure(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> TaffyResult<Size<f32>> {
+        self.measure_with_measure_function(node, available_space, |_, _, _, _, _| Size::ZERO)
+    }
+
+    /// Computes the size that `node` would have if it were laid out as a root in `available_space`, without modifying
+    /// the tree
+    ///
+    /// Unlike [`TaffyTree::measure_with_measure_function`] this only requires shared access to the tree, so sizes can
+    /// be computed while other code holds references to it (or from several threads at once). Sizes cached by earlier
+    /// layouts are reused, but the sizes computed are discarded rather than cached and no warnings are reported. The
+    /// measure function is given shared access to the context of each leaf.
+    pub fn measure_read_only_with_measure_function<MeasureFunction>(
+        &self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) -> TaffyResult<Size<f32>>
+    where
+        MeasureFunction:
+            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&NodeContext>, &Style) -> Size<f32>,
+    {
+        let mut view = TaffyView::shared(self, measure_function);
+        let inputs = LayoutInput {
+            run_mode: RunMode::ComputeSize,
+            sizing_mode: SizingMode::InherentSize,
+            axis: RequestedAxis::Both,
+            known_dimensions: Size::NONE,
+            parent_size: available_space.into_options(),
+            available_space,
+            vertical_margins_are_collapsible: Line::FALSE,
+        };
+        let mut output = LayoutOutput::HIDDEN;
+        work_stack::run_pass(&mut view, |view| output = view.compute_child_layout(node, inputs));
+        Ok(output.size)
+    }
+
+    /// Computes the size that `node` would have if it were laid out as a root in `available_space`, without modifying
+    /// the tree or calling a measure function
+    ///
+    /// See [`TaffyTree::measure_read_only_with_measure_function`].
+    pub fn measure_read_only(&self, node: NodeId, available_space: Size<AvailableSpace>) -> TaffyResult<Size<f32>> {
+        self.measure_read_only_with_measure_function(node, available_space, |_, _, _, _, _| Size::ZERO)
+    }
+
+    /// Prints a debug representation of the tree's layout
+    #[cfg(feature = "std")]
+    pub fn print_tree(&mut self, root: NodeId) {
+        crate::util::print_tree(self, root)
+    }
 
     /// Returns an instance of LayoutTree representing the TaffyTree
     #[cfg(test)]
-    pub(crate) fn as_layout_tree(&mut self) -> impl LayoutPartialTree + CacheTree + '_ {
-        TaffyView { taffy: self, measure_function: |_, _, _, _, _| Size::ZERO }
+    pub(crate) fn as_layout_tree(&mut self) -> impl LayoutPartialTree + CacheTree + RoundTree + '_ {
+        TaffyView::exclusive(self, |_, _, _, _, _| Size::ZERO)
     }
 }
 
@@ -940,7 +2461,8 @@ impl<NodeContext> TaffyTree<NodeContext> {
 mod tests {
 
     use super::*;
-    use crate::style::{Dimension, Display, FlexDirection};
+    use crate::geometry::Rect;
+    use crate::style::{Dimension, Display, FlexDirection, Overflow};
     use crate::style_helpers::*;
     use crate::util::sys;
 
@@ -1078,6 +2600,92 @@ mod tests {
         assert_eq!(taffy.layout(node).unwrap().size.width, 100.0);
     }
 
+    #[test]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn layout_panics_are_reported_with_the_context_of_the_node() {
+        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let leaf = taffy.new_leaf_with_context(Style::default(), Size { width: 10.0, height: 10.0 }).unwrap();
+        taffy.set_node_key(leaf, Some(NodeKey(7))).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, |_, _, _, _, _| panic!("measure failed"))
+        }));
+        // The panic is raised again with the context of the node
+        let message = result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.starts_with(&format!("layout of node {leaf:?} (LEAF) panicked in run mode ComputeSize")));
+        assert!(message.contains("LayoutInput {"));
+        assert!(message.ends_with(": measure failed"));
+        // Only the innermost node being laid out is reported
+        let panics: Vec<_> = taffy
+            .warnings()
+            .iter()
+            .filter_map(|&(node, warning)| match warning {
+                LayoutWarning::LayoutPanicked { label, inputs } => Some((node, label, inputs.run_mode)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(panics, [(leaf, "LEAF", RunMode::ComputeSize)]);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, |_, _, _, _, _| std::panic::panic_any(42_u32))
+        }));
+        assert!(result.unwrap_err().downcast::<String>().unwrap().ends_with(": Box<dyn Any>"));
+
+        // The panic context doesn't leak into later layouts
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert!(taffy.warnings().is_empty());
+        assert_eq!(taffy.layout(leaf).unwrap().size.width, 10.0);
+    }
+
+    #[test]
+    fn set_intrinsic_size() {
+        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let node = taffy.new_leaf(Style { padding: length(5.0), ..Default::default() }).unwrap();
+        taffy.set_intrinsic_size(node, Size::new(40.0, 30.0)).unwrap();
+        assert_eq!(taffy.dirty(node), Ok(true));
+
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 50.0, height: 40.0 });
+
+        taffy.set_intrinsic_size(node, Size::NONE).unwrap();
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn intrinsic_size_overrides_measure_per_axis() {
+        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let node = taffy.new_leaf_with_context(Style::default(), Size { width: 200.0, height: 100.0 }).unwrap();
+        taffy.set_intrinsic_size(node, Size { width: Some(20.0), height: None }).unwrap();
+        assert_eq!(taffy.intrinsic_size(node), Ok(Size { width: Some(20.0), height: None }));
+
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 20.0, height: 100.0 });
+    }
+
+    #[test]
+    fn leaf_fallback_size() {
+        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let unmeasured = taffy.new_leaf(Style { padding: length(5.0), ..Default::default() }).unwrap();
+        let measured = taffy.new_leaf_with_context(Style::default(), Size { width: 10.0, height: 10.0 }).unwrap();
+        let available_space = Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::Definite(80.0) };
+
+        taffy.compute_layout_with_measure(unmeasured, available_space, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(unmeasured).unwrap().size, Size { width: 10.0, height: 10.0 });
+
+        taffy.set_leaf_fallback_size(LeafFallbackSize::Stretch);
+        assert_eq!(taffy.dirty(unmeasured), Ok(true));
+        taffy.compute_layout_with_measure(unmeasured, available_space, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(unmeasured).unwrap().size, Size { width: 100.0, height: 80.0 });
+
+        // Nodes with a context are still measured, and indefinite axes still collapse
+        taffy.compute_layout_with_measure(measured, available_space, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(measured).unwrap().size, Size { width: 10.0, height: 10.0 });
+        taffy.compute_layout_with_measure(unmeasured, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(unmeasured).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
     /// Test that adding `add_child()` works
     #[test]
     fn add_child() {
@@ -1094,6 +2702,59 @@ mod tests {
         assert_eq!(taffy.child_count(node), 2);
     }
 
+    #[test]
+    fn adding_an_ancestor_as_a_child_is_an_error() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let parent = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[parent]).unwrap();
+
+        assert_eq!(taffy.add_child(leaf, leaf), Err(TaffyError::Cycle(leaf)));
+        assert_eq!(taffy.add_child(leaf, root), Err(TaffyError::Cycle(root)));
+        assert_eq!(taffy.insert_child_at_index(leaf, 0, parent), Err(TaffyError::Cycle(parent)));
+        assert_eq!(taffy.replace_child_at_index(parent, 0, root), Err(TaffyError::Cycle(root)));
+        assert_eq!(taffy.set_children(leaf, &[root]), Err(TaffyError::Cycle(root)));
+
+        // The tree is left unchanged
+        assert_eq!(taffy.children(root).unwrap(), [parent]);
+        assert_eq!(taffy.children(parent).unwrap(), [leaf]);
+        assert_eq!(taffy.child_count(leaf), 0);
+        assert_eq!(taffy.parent(root), None);
+
+        // Siblings and descendants can still be moved around
+        let other = taffy.new_leaf(Style::default()).unwrap();
+        taffy.add_child(root, other).unwrap();
+        taffy.add_child(other, leaf).unwrap();
+        assert_eq!(taffy.parent(leaf), Some(other));
+    }
+
+    #[test]
+    fn mark_dirty_fails_instead_of_looping_on_a_cycle() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let style = Style { display: Display::Contents, ..Default::default() };
+        let a = taffy.new_leaf(style.clone()).unwrap();
+        let b = taffy.new_with_children(style, &[a]).unwrap();
+        // Bypass the checks in `add_child` to create a cycle
+        taffy.children[a.into()].push(b);
+        taffy.parents[b.into()] = Some(a);
+
+        assert_eq!(taffy.mark_dirty(a), Err(TaffyError::Cycle(a)));
+    }
+
+    #[test]
+    fn cycle_check_terminates_on_a_cycle() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let a = taffy.new_leaf(Style::default()).unwrap();
+        let b = taffy.new_with_children(Style::default(), &[a]).unwrap();
+        // Bypass the checks in `add_child` to create a cycle
+        taffy.children[a.into()].push(b);
+        taffy.parents[b.into()] = Some(a);
+
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        assert_eq!(taffy.check_for_cycle(a, leaf), Ok(()));
+        assert_eq!(taffy.check_for_cycle(a, b), Err(TaffyError::Cycle(b)));
+    }
+
     #[test]
     fn insert_child_at_index() {
         let mut taffy: TaffyTree<()> = TaffyTree::new();
@@ -1328,6 +2989,721 @@ mod tests {
         assert_eq!(taffy.dirty(node), Ok(true));
     }
 
+    #[test]
+    fn mark_dirty_many_marks_each_node_and_their_ancestors() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaves: Vec<_> = (0..3).map(|_| taffy.new_leaf(Style::default()).unwrap()).collect();
+        let clean = taffy.new_leaf(Style::default()).unwrap();
+        let parent = taffy.new_with_children(Style::default(), &leaves).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[parent, clean]).unwrap();
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        taffy.mark_dirty_many(&leaves[..2]).unwrap();
+
+        assert_eq!(taffy.dirty(leaves[0]), Ok(true));
+        assert_eq!(taffy.dirty(leaves[1]), Ok(true));
+        assert_eq!(taffy.dirty(leaves[2]), Ok(false));
+        assert_eq!(taffy.dirty(clean), Ok(false));
+        assert_eq!(taffy.dirty(parent), Ok(true));
+        assert_eq!(taffy.dirty(node), Ok(true));
+    }
+
+    #[test]
+    fn orphans_are_parentless_nodes_which_are_not_registered_roots() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        let detached = taffy.new_leaf(Style::default()).unwrap();
+        let detached_child = taffy.new_leaf(Style::default()).unwrap();
+        taffy.add_child(detached, detached_child).unwrap();
+        assert_eq!(taffy.orphans(), vec![root, detached]);
+
+        taffy.register_root(root).unwrap();
+        assert_eq!(taffy.is_registered_root(root), Ok(true));
+        assert_eq!(taffy.orphans(), vec![detached]);
+
+        // Orphans are only reported as warnings when enabled, and never for the tree which was laid out
+        taffy.compute_layout(child, Size::MAX_CONTENT).unwrap();
+        assert!(taffy.warnings().is_empty());
+        taffy.enable_orphan_warnings();
+        taffy.unregister_root(root).unwrap();
+        taffy.compute_layout(child, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.warnings(), &[(detached, LayoutWarning::Orphan)]);
+
+        taffy.remove(detached).unwrap();
+        assert_eq!(taffy.orphans(), vec![root, detached_child]);
+    }
+
+    #[test]
+    fn repair_makes_parent_links_match_the_children() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let other_child = taffy.new_leaf(Style::default()).unwrap();
+        let first = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let removed = taffy.new_leaf(Style::default()).unwrap();
+        taffy.remove(removed).unwrap();
+        taffy.compute_layout(first, Size::MAX_CONTENT).unwrap();
+
+        // `child` is listed by both parents, `removed` no longer exists and `other_child` links to a parent which
+        // doesn't list it
+        taffy.add_child(second, child).unwrap();
+        taffy.children[second.into()].push(removed);
+        taffy.parents[other_child.into()] = Some(first);
+
+        let repairs = taffy.repair();
+        assert_eq!(
+            repairs,
+            vec![
+                TreeRepair::RemovedDuplicateChild { parent: first, child },
+                TreeRepair::RemovedMissingChild { parent: second, child: removed },
+                TreeRepair::SetParent { node: other_child, old_parent: Some(first), new_parent: None },
+            ]
+        );
+        assert_eq!(taffy.children(first).unwrap(), vec![]);
+        assert_eq!(taffy.children(second).unwrap(), vec![child]);
+        assert_eq!(taffy.parent(other_child), None);
+        assert!(taffy.dirty(first).unwrap());
+
+        assert_eq!(taffy.repair(), vec![]);
+    }
+
+    #[test]
+    fn compute_layout_all_roots_lays_out_only_dirty_roots() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf_a = taffy.new_leaf(Style::default()).unwrap();
+        let leaf_b = taffy.new_leaf(Style::default()).unwrap();
+        let root_a = taffy.new_with_children(Style::default(), &[leaf_a]).unwrap();
+        let root_b = taffy.new_with_children(Style::default(), &[leaf_b]).unwrap();
+
+        let ten = Size { width: 10.0, height: 10.0 };
+        taffy.compute_layout_all_roots_with_measure(Size::MAX_CONTENT, |_, _, _, _, _| ten).unwrap();
+        assert_eq!(taffy.layout(root_a).unwrap().size, ten);
+        assert_eq!(taffy.layout(root_b).unwrap().size, ten);
+
+        let mut measured = Vec::new();
+        taffy.mark_dirty(leaf_a).unwrap();
+        taffy
+            .compute_layout_all_roots_with_measure(Size::MAX_CONTENT, |_, _, node, _, _| {
+                measured.push(node);
+                ten
+            })
+            .unwrap();
+
+        assert!(!measured.is_empty());
+        assert!(measured.iter().all(|&node| node == leaf_a));
+        assert_eq!(taffy.dirty(root_a), Ok(false));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn double_buffered_layouts_are_published_after_each_pass() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let node = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let buffer = taffy.enable_double_buffering();
+        assert_eq!(buffer.front().layout(node), None);
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        let first = buffer.front();
+        assert_eq!(first.generation(), 1);
+        assert_eq!(first.layout(node).unwrap().size, Size { width: 10.0, height: 10.0 });
+
+        taffy.set_style(node, Style { size: Size::from_lengths(20.0, 20.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        let second = std::thread::spawn(move || buffer.front()).join().unwrap();
+        assert_eq!(second.generation(), 2);
+        assert_eq!(second.layout(node).unwrap().size, Size { width: 20.0, height: 20.0 });
+        assert_eq!(first.layout(node).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn double_buffered_layouts_are_unrounded_when_rounding_is_disabled() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        taffy.disable_rounding();
+        let node = taffy.new_leaf(Style { size: Size::from_lengths(10.5, 10.5), ..Default::default() }).unwrap();
+        let buffer = taffy.enable_double_buffering();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 10.5, height: 10.5 });
+        assert_eq!(buffer.front().layout(node).unwrap(), taffy.layout(node).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn double_buffered_frames_reuse_their_child_lists() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let removed = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child, removed]).unwrap();
+        let buffer = taffy.enable_double_buffering();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let first_children = buffer.front().children(root).as_ptr();
+
+        // Each publish writes into the frame published two passes earlier
+        taffy.remove(removed).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        taffy.mark_dirty(root).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let third = buffer.front();
+        assert_eq!(third.generation(), 3);
+        assert_eq!(third.children(root), [child]);
+        assert_eq!(third.children(root).as_ptr(), first_children);
+        assert_eq!(third.layout(removed), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn published_frames_can_be_traversed_while_the_tree_is_modified() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        let buffer = taffy.enable_double_buffering();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let reader = std::thread::spawn(move || {
+            let frame = buffer.front();
+            let children: Vec<NodeId> = frame.child_ids(root).collect();
+            (children, frame.parent(child), frame.layout(child).unwrap().size)
+        });
+        taffy.remove_child(root, child).unwrap();
+
+        let (children, parent, size) = reader.join().unwrap();
+        assert_eq!(children, [child]);
+        assert_eq!(parent, Some(root));
+        assert_eq!(size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn paint_order_is_assigned_in_pre_order() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let grandchild = taffy.new_leaf(Style::default()).unwrap();
+        let first = taffy.new_with_children(Style::default(), &[grandchild]).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[first, second]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let paint_order = |taffy: &TaffyTree<()>, node| taffy.paint_order(node).unwrap();
+        assert_eq!(paint_order(&taffy, root), 0);
+        assert_eq!(paint_order(&taffy, first), 1);
+        assert_eq!(paint_order(&taffy, grandchild), 2);
+        assert_eq!(paint_order(&taffy, second), 3);
+
+        taffy.set_children(root, &[second, first]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(paint_order(&taffy, second), 1);
+        assert_eq!(paint_order(&taffy, grandchild), 3);
+    }
+
+    #[test]
+    #[cfg(all(feature = "detailed_layout_info", feature = "grid"))]
+    fn grid_info_includes_track_offsets() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let grid = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![length(20.0), length(30.0)],
+                    justify_content: Some(crate::style::JustifyContent::SpaceBetween),
+                    gap: Size { width: length(10.0), height: zero() },
+                    padding: Rect { left: length(5.0), right: zero(), top: zero(), bottom: zero() },
+                    size: Size::from_lengths(105.0, 10.0),
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        taffy.compute_layout(grid, Size::MAX_CONTENT).unwrap();
+        taffy.compute_layout(leaf, Size::MAX_CONTENT).unwrap();
+
+        let columns = &taffy.grid_info(grid).unwrap().columns;
+        assert_eq!(columns.sizes, [20.0, 30.0]);
+        assert_eq!(columns.gutters, [0.0, 10.0, 0.0]);
+        assert_eq!(columns.offsets, [5.0, 75.0]);
+        assert!(taffy.grid_info(leaf).is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "detailed_layout_info", feature = "flexbox"))]
+    fn flexbox_info_includes_flex_lines() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let item_style = Style { size: Size::from_lengths(40.0, 10.0), ..Default::default() };
+        let a = taffy.new_leaf(item_style.clone()).unwrap();
+        let b = taffy.new_leaf(item_style.clone()).unwrap();
+        let c = taffy.new_leaf(Style { size: Size::from_lengths(40.0, 20.0), ..Default::default() }).unwrap();
+        let container = taffy
+            .new_with_children(
+                Style {
+                    flex_wrap: crate::style::FlexWrap::Wrap,
+                    gap: Size { width: zero(), height: length(5.0) },
+                    border: Rect { left: zero(), right: zero(), top: length(2.0), bottom: zero() },
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[a, b, c],
+            )
+            .unwrap();
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        let lines = &taffy.flexbox_info(container).unwrap().lines;
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].items, [a, b]);
+        assert_eq!(lines[1].items, [c]);
+        assert_eq!((lines[0].cross_offset, lines[0].cross_size), (2.0, 10.0));
+        assert_eq!((lines[1].cross_offset, lines[1].cross_size), (17.0, 20.0));
+        assert!(taffy.flexbox_info(a).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn responsive_styles_are_resolved_from_the_available_width() {
+        use crate::style::{PartialStyle, ResponsiveStyle};
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let item_style = Style { size: Size::from_lengths(100.0, 10.0), flex_shrink: 0.0, ..Default::default() };
+        let a = taffy.new_leaf(item_style.clone()).unwrap();
+        let b = taffy.new_leaf(item_style).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[a, b]).unwrap();
+        let column = PartialStyle { flex_direction: Some(FlexDirection::Column), ..Default::default() };
+        let responsive_style = ResponsiveStyle::new(Style::default()).with_breakpoint(None, Some(300.0), column);
+        taffy.set_responsive_style(root, Some(responsive_style)).unwrap();
+
+        let available_space =
+            |width: f32| Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent };
+        taffy.compute_layout(root, available_space(250.0)).unwrap();
+        assert_eq!(taffy.style(root).unwrap().flex_direction, FlexDirection::Column);
+        assert_eq!(taffy.layout(b).unwrap().location, Point { x: 0.0, y: 10.0 });
+
+        taffy.compute_layout(root, available_space(400.0)).unwrap();
+        assert_eq!(taffy.style(root).unwrap().flex_direction, FlexDirection::Row);
+        assert_eq!(taffy.layout(b).unwrap().location, Point { x: 100.0, y: 0.0 });
+
+        taffy.set_responsive_style(root, None).unwrap();
+        assert!(taffy.responsive_style(root).is_none());
+    }
+
+    #[test]
+    fn responsive_styles_are_resolved_when_laying_out_a_subtree() {
+        use crate::style::{PartialStyle, ResponsiveStyle};
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let subtree = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        let _root = taffy.new_with_children(Style::default(), &[subtree]).unwrap();
+        let sized = PartialStyle { size: Some(Size::from_lengths(10.0, 10.0)), ..Default::default() };
+        let responsive_style = ResponsiveStyle::new(Style::default()).with_breakpoint(None, None, sized);
+        taffy.set_responsive_style(leaf, Some(responsive_style)).unwrap();
+
+        taffy.compute_layout(subtree, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn reconcile_children_reuses_spawns_and_despawns_keyed_children() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let root = taffy.new_leaf(Style::default()).unwrap();
+        let spawn = |taffy: &mut TaffyTree<()>, _key| taffy.new_leaf(Style::default());
+        let despawn = |taffy: &mut TaffyTree<()>, node| taffy.remove(node).map(|_| ());
+        taffy.reconcile_children(root, &[NodeKey(1), NodeKey(2), NodeKey(3)], spawn, despawn).unwrap();
+        let [first, second, third] = [1, 2, 3].map(|key| taffy.node_by_key(NodeKey(key)).unwrap());
+        assert_eq!(taffy.children(root).unwrap(), vec![first, second, third]);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let mut spawned = Vec::new();
+        let spawn = |taffy: &mut TaffyTree<()>, key| {
+            spawned.push(key);
+            taffy.new_leaf(Style::default())
+        };
+        taffy.reconcile_children(root, &[NodeKey(3), NodeKey(1), NodeKey(4)], spawn, despawn).unwrap();
+        let fourth = taffy.node_by_key(NodeKey(4)).unwrap();
+        assert_eq!(spawned, vec![NodeKey(4)]);
+        assert_eq!(taffy.children(root).unwrap(), vec![third, first, fourth]);
+        assert!(!taffy.nodes.contains_key(second.into()));
+        assert!(taffy.dirty(root).unwrap());
+        assert!(!taffy.dirty(first).unwrap());
+
+        // Reconciling the same keys again leaves the tree untouched
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        taffy.reconcile_children(root, &[NodeKey(3), NodeKey(1), NodeKey(4)], spawn_unreachable, despawn).unwrap();
+        assert!(!taffy.dirty(root).unwrap());
+
+        let result = taffy.reconcile_children(root, &[NodeKey(1), NodeKey(1)], spawn_unreachable, despawn);
+        assert_eq!(result, Err(TaffyError::DuplicateNodeKey(NodeKey(1))));
+
+        // Duplicate keys are rejected before any node is spawned for them
+        let result = taffy.reconcile_children(root, &[NodeKey(5), NodeKey(5)], spawn_unreachable, despawn);
+        assert_eq!(result, Err(TaffyError::DuplicateNodeKey(NodeKey(5))));
+        assert_eq!(taffy.node_by_key(NodeKey(5)), None);
+    }
+
+    fn spawn_unreachable(_: &mut TaffyTree<()>, key: NodeKey) -> TaffyResult<NodeId> {
+        panic!("unexpected spawn of {key:?}")
+    }
+
+    #[test]
+    fn set_layout_stores_the_layout_and_marks_the_node_clean() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = Layout::from_location_and_size(Point { x: 5.0, y: 5.0 }, Size { width: 50.0, height: 20.0 });
+        taffy.set_layout(child, layout).unwrap();
+        assert_eq!(*taffy.layout(child).unwrap(), layout);
+        assert_eq!(*taffy.unrounded_layout(child), layout);
+        assert!(!taffy.dirty(child).unwrap());
+        assert!(taffy.dirty(root).unwrap());
+
+        // Laying the node out at the size of the stored layout reuses it without measuring the node
+        let mut measure_count = 0;
+        let available_space = Size { width: AvailableSpace::Definite(50.0), height: AvailableSpace::Definite(20.0) };
+        taffy
+            .compute_layout_with_measure(child, available_space, |_, _, _, _, _| {
+                measure_count += 1;
+                Size::ZERO
+            })
+            .unwrap();
+        assert_eq!(measure_count, 0);
+        assert_eq!(taffy.layout(child).unwrap().size, layout.size);
+
+        // Modifying the node discards the stored layout
+        taffy.mark_dirty(child).unwrap();
+        taffy.compute_layout(child, available_space).unwrap();
+        assert!(taffy.dirty(root).unwrap());
+        assert_eq!(taffy.layout(child).unwrap().size, Size::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn scroll_anchor_adjustment_is_the_distance_the_anchor_moved() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let above = taffy
+            .new_leaf(Style { flex_shrink: 0.0, size: Size::from_lengths(100.0, 50.0), ..Default::default() })
+            .unwrap();
+        let anchor = taffy
+            .new_leaf(Style { flex_shrink: 0.0, size: Size::from_lengths(100.0, 20.0), ..Default::default() })
+            .unwrap();
+        let container_style = Style {
+            flex_direction: FlexDirection::Column,
+            size: Size::from_lengths(100.0, 40.0),
+            overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+            ..Default::default()
+        };
+        let container = taffy.new_with_children(container_style, &[above, anchor]).unwrap();
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        taffy.set_scroll_anchor(container, Some(anchor)).unwrap();
+        assert_eq!(taffy.scroll_anchor(container), Some(anchor));
+
+        taffy
+            .set_style(above, Style { flex_shrink: 0.0, size: Size::from_lengths(100.0, 80.0), ..Default::default() })
+            .unwrap();
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.scroll_anchor_adjustment(container), Point { x: 0.0, y: 30.0 });
+
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.scroll_anchor_adjustment(container), Point::ZERO);
+
+        taffy.remove(anchor).unwrap();
+        assert_eq!(taffy.scroll_anchor(container), None);
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn container_queries_are_resolved_from_the_width_of_the_parent() {
+        use crate::style::{PartialStyle, ResponsiveStyle};
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let item_style = Style { size: Size::from_lengths(100.0, 10.0), flex_shrink: 0.0, ..Default::default() };
+        let a = taffy.new_leaf(item_style.clone()).unwrap();
+        let b = taffy.new_leaf(item_style).unwrap();
+        let card = taffy.new_with_children(Style::default(), &[a, b]).unwrap();
+        let column = PartialStyle { flex_direction: Some(FlexDirection::Column), ..Default::default() };
+        let container_query = ResponsiveStyle::new(Style::default()).with_breakpoint(None, Some(300.0), column);
+        taffy.set_container_query(card, Some(container_query)).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    size: Size { width: length(250.0), height: auto() },
+                    padding: Rect { left: length(10.0), right: length(10.0), top: zero(), bottom: zero() },
+                    flex_direction: FlexDirection::Column,
+                    ..Default::default()
+                },
+                &[card],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.style(card).unwrap().flex_direction, FlexDirection::Column);
+        assert_eq!(taffy.layout(b).unwrap().location, Point { x: 0.0, y: 10.0 });
+
+        let mut root_style = taffy.style(root).unwrap().clone();
+        root_style.size.width = length(320.0);
+        taffy.set_style(root, root_style).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.style(card).unwrap().flex_direction, FlexDirection::Row);
+        assert_eq!(taffy.layout(b).unwrap().location, Point { x: 100.0, y: 0.0 });
+    }
+
+    #[test]
+    fn container_queries_apply_on_top_of_responsive_styles() {
+        use crate::style::{PartialStyle, ResponsiveStyle};
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let card = taffy.new_leaf(Style::default()).unwrap();
+        let column = PartialStyle { flex_direction: Some(FlexDirection::Column), ..Default::default() };
+        let responsive_style = ResponsiveStyle::new(Style::default()).with_breakpoint(None, Some(300.0), column);
+        taffy.set_responsive_style(card, Some(responsive_style)).unwrap();
+        let sized = PartialStyle { size: Some(Size::from_lengths(10.0, 10.0)), ..Default::default() };
+        let container_query = ResponsiveStyle::new(Style::default()).with_breakpoint(None, Some(100.0), sized);
+        taffy.set_container_query(card, Some(container_query)).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_lengths(50.0, 50.0), ..Default::default() }, &[card])
+            .unwrap();
+
+        let available_space = Size { width: AvailableSpace::Definite(200.0), height: AvailableSpace::MaxContent };
+        taffy.compute_layout(root, available_space).unwrap();
+        assert_eq!(taffy.style(card).unwrap().flex_direction, FlexDirection::Column);
+        assert_eq!(taffy.style(card).unwrap().size, Size::from_lengths(10.0, 10.0));
+
+        // Resolving the styles again for the same layout changes nothing, so the node stays clean
+        taffy.resolve_responsive_styles(Some(root), available_space.width).unwrap();
+        assert!(!taffy.resolve_container_queries(root, available_space.width).unwrap());
+        assert_eq!(taffy.dirty(card), Ok(false));
+    }
+
+    #[test]
+    fn container_queries_are_resolved_when_laying_out_a_subtree() {
+        use crate::style::{PartialStyle, ResponsiveStyle};
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let card = taffy.new_leaf(Style::default()).unwrap();
+        let sized = PartialStyle { size: Some(Size::from_lengths(10.0, 10.0)), ..Default::default() };
+        let container_query = ResponsiveStyle::new(Style::default()).with_breakpoint(None, None, sized);
+        taffy.set_container_query(card, Some(container_query)).unwrap();
+        let subtree = taffy.new_with_children(Style::default(), &[card]).unwrap();
+        let _root = taffy.new_with_children(Style::default(), &[subtree]).unwrap();
+
+        taffy.compute_layout(subtree, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(card).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn hit_test_uses_hit_insets_and_paint_order() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let icon_style = Style {
+            position: Position::Absolute,
+            size: Size::from_lengths(10.0, 10.0),
+            hit_inset: Rect { left: -10.0, right: -10.0, top: -10.0, bottom: -10.0 },
+            ..Default::default()
+        };
+        let icon = taffy.new_leaf(icon_style).unwrap();
+        let panel_style = Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(50.0), right: auto(), top: length(50.0), bottom: auto() },
+            size: Size::from_lengths(40.0, 40.0),
+            hit_inset: Rect { left: 5.0, right: 5.0, top: 5.0, bottom: 5.0 },
+            ..Default::default()
+        };
+        let panel = taffy.new_leaf(panel_style).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_lengths(100.0, 100.0), ..Default::default() }, &[icon, panel])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.hit_test(root, Point { x: 15.0, y: 5.0 }), Some(icon));
+        assert_eq!(taffy.hit_test(root, Point { x: 25.0, y: 5.0 }), Some(root));
+        assert_eq!(taffy.hit_test(root, Point { x: 52.0, y: 60.0 }), Some(root));
+        assert_eq!(taffy.hit_test(root, Point { x: 60.0, y: 60.0 }), Some(panel));
+        assert_eq!(taffy.hit_test(root, Point { x: 150.0, y: 60.0 }), None);
+
+        taffy.set_style(panel, Style { display: Display::None, ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.hit_test(root, Point { x: 60.0, y: 60.0 }), Some(root));
+    }
+
+    #[test]
+    fn hit_test_handles_very_deep_trees() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let mut root = leaf;
+        for _ in 0..100_000 {
+            root = taffy.new_with_children(Style::default(), &[root]).unwrap();
+        }
+        let size = Size { width: 10.0, height: 10.0 };
+        taffy.set_layout(leaf, Layout { size, ..Layout::new() }).unwrap();
+
+        assert_eq!(taffy.hit_test(root, Point { x: 5.0, y: 5.0 }), Some(leaf));
+    }
+
+    #[test]
+    fn node_keys_are_unique_and_removed_with_their_node() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let node = taffy.new_leaf(Style::default()).unwrap();
+        let other = taffy.new_leaf(Style::default()).unwrap();
+
+        taffy.set_node_key(node, Some(NodeKey(1))).unwrap();
+        assert_eq!(taffy.node_key(node), Ok(Some(NodeKey(1))));
+        assert_eq!(taffy.node_by_key(NodeKey(1)), Some(node));
+        assert_eq!(taffy.set_node_key(other, Some(NodeKey(1))), Err(TaffyError::DuplicateNodeKey(NodeKey(1))));
+
+        taffy.set_node_key(node, Some(NodeKey(2))).unwrap();
+        assert_eq!(taffy.node_by_key(NodeKey(1)), None);
+        taffy.remove(node).unwrap();
+        assert_eq!(taffy.node_by_key(NodeKey(2)), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "block_layout", feature = "grid"))]
+    fn measure_read_only_computes_sizes_through_a_shared_reference() {
+        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let text = taffy.new_leaf_with_context(Style::default(), Size { width: 30.0, height: 10.0 }).unwrap();
+        let contents =
+            taffy.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[text]).unwrap();
+        let block = Style { display: Display::Block, padding: length(3.0), ..Default::default() };
+        let child = taffy.new_with_children(block, &[contents]).unwrap();
+        let node = taffy.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[child]).unwrap();
+
+        let shared = &taffy;
+        let measure = |_, _, _, context: Option<&Size<f32>>, _: &Style| context.copied().unwrap_or(Size::ZERO);
+        let size = shared.measure_read_only_with_measure_function(node, Size::MAX_CONTENT, measure);
+        assert_eq!(size, Ok(Size { width: 36.0, height: 16.0 }));
+        assert_eq!(shared.measure_read_only(node, Size::MAX_CONTENT), Ok(Size { width: 6.0, height: 6.0 }));
+        for node in [node, child, contents, text] {
+            assert_eq!(*shared.unrounded_layout(node), Layout::new());
+            assert_eq!(shared.dirty(node), Ok(true));
+            assert_eq!(shared.nodes[node.into()].cache.get(Size::NONE, Size::MAX_CONTENT, RunMode::ComputeSize), None);
+        }
+
+        taffy
+            .compute_layout_with_measure(node, Size::MAX_CONTENT, |_, _, _, context, _| {
+                context.copied().unwrap_or(Size::ZERO)
+            })
+            .unwrap();
+        let shared = &taffy;
+        let size = shared.measure_read_only_with_measure_function(node, Size::MAX_CONTENT, |_, _, _, _, _| Size::ZERO);
+        assert_eq!(size, Ok(Size { width: 36.0, height: 16.0 }));
+    }
+
+    #[test]
+    fn measure_does_not_change_layout() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(30.0, 20.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(Style { padding: length(5.0), ..Default::default() }, &[child]).unwrap();
+
+        assert_eq!(taffy.measure(node, Size::MAX_CONTENT), Ok(Size { width: 40.0, height: 30.0 }));
+        assert_eq!(*taffy.layout(node).unwrap(), Layout::new());
+        assert_eq!(*taffy.layout(child).unwrap(), Layout::new());
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 40.0, height: 30.0 });
+    }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn measure_does_not_change_layout_of_block_children() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(30.0, 20.0), ..Default::default() }).unwrap();
+        let block = Style { display: Display::Block, padding: length(3.0), ..Default::default() };
+        let child = taffy.new_with_children(block.clone(), &[leaf]).unwrap();
+        let node = taffy.new_with_children(Style { padding: length(5.0), ..block }, &[child]).unwrap();
+
+        assert_eq!(taffy.measure(node, Size::MAX_CONTENT), Ok(Size { width: 46.0, height: 36.0 }));
+        for node in [node, child, leaf] {
+            assert_eq!(*taffy.unrounded_layout(node), Layout::new());
+            assert_eq!(taffy.dirty(node), Ok(true));
+        }
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 5.0, y: 5.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 36.0, height: 26.0 });
+        assert_eq!(taffy.layout(leaf).unwrap().location, Point { x: 3.0, y: 3.0 });
+        assert_eq!(taffy.dirty(leaf), Ok(false));
+    }
+
+    #[test]
+    fn measure_resolves_responsive_styles_and_container_queries() {
+        use crate::style::{PartialStyle, ResponsiveStyle};
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let sized = |width| PartialStyle { size: Some(Size::from_lengths(width, 10.0)), ..Default::default() };
+        let container_query = ResponsiveStyle::new(Style::default()).with_breakpoint(None, Some(100.0), sized(20.0));
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        taffy.set_container_query(leaf, Some(container_query)).unwrap();
+        let responsive_style = ResponsiveStyle::new(Style::default()).with_breakpoint(None, Some(300.0), sized(50.0));
+        let node = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        taffy.set_responsive_style(node, Some(responsive_style)).unwrap();
+
+        let narrow = Size { width: AvailableSpace::Definite(80.0), height: AvailableSpace::MaxContent };
+        assert_eq!(taffy.measure(node, narrow), Ok(Size { width: 50.0, height: 10.0 }));
+        assert_eq!(taffy.style(leaf).unwrap().size, Size::from_lengths(20.0, 10.0));
+
+        let wide = Size { width: AvailableSpace::Definite(400.0), height: AvailableSpace::MaxContent };
+        // The container has never been laid out, so the leaf's container query still matches
+        assert_eq!(taffy.measure(node, wide), Ok(Size { width: 20.0, height: 10.0 }));
+    }
+
+    #[test]
+    fn measure_rejects_removed_nodes() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let node = taffy.new_leaf(Style::default()).unwrap();
+        taffy.remove(node).unwrap();
+
+        assert_eq!(taffy.measure(node, Size::MAX_CONTENT), Err(TaffyError::InvalidInputNode(node)));
+    }
+
+    #[test]
+    fn measured_roots_are_still_laid_out_by_compute_layout_all_roots() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(30.0, 20.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        taffy.measure(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.dirty(root), Ok(true));
+
+        taffy.compute_layout_all_roots(Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.dirty(root), Ok(false));
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 30.0, height: 20.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 30.0, height: 20.0 });
+    }
+
+    #[test]
+    fn mark_dirty_propagates_through_display_contents() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let contents =
+            taffy.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[child]).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[contents]).unwrap();
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 10.0, height: 10.0 });
+        assert_eq!(taffy.layout(contents).unwrap().size, Size::ZERO);
+
+        taffy.set_style(child, Style { size: Size::from_lengths(20.0, 20.0), ..Default::default() }).unwrap();
+        assert_eq!(taffy.dirty(node), Ok(true));
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 20.0, height: 20.0 });
+    }
+
+    #[test]
+    fn display_contents_children_are_hidden_with_parent() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let contents =
+            taffy.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[child]).unwrap();
+        let hidden = taffy.new_with_children(Style::default(), &[contents]).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[hidden]).unwrap();
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 10.0 });
+
+        taffy.set_style(hidden, Style { display: Display::None, ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size::ZERO);
+    }
+
     #[test]
     fn compute_layout_should_produce_valid_result() {
         let mut taffy: TaffyTree<()> = TaffyTree::new();
@@ -1399,4 +3775,55 @@ mod tests {
 
         assert!(taffy.children(old_parent).unwrap().is_empty());
     }
+
+    #[test]
+    fn warnings_are_collected_during_layout() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let wide_style = Style { size: Size::from_lengths(80.0, 10.0), flex_shrink: 0.0, ..Default::default() };
+        let wide = [taffy.new_leaf(wide_style.clone()).unwrap(), taffy.new_leaf(wide_style).unwrap()];
+        let percent = taffy.new_leaf(Style { size: Size::from_percent(0.5, 0.5), ..Default::default() }).unwrap();
+        let row = taffy
+            .new_with_children(Style { size: Size::from_lengths(100.0, 50.0), ..Default::default() }, &wide)
+            .unwrap();
+        let column = taffy
+            .new_with_children(Style { flex_direction: FlexDirection::Column, ..Default::default() }, &[percent])
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[row, column]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert!(taffy.warnings().contains(&(row, LayoutWarning::FlexLineOverflow { overflow: 60.0 })));
+        // The percentage is resolved each time the leaf is sized, but is only reported once
+        let percent_warnings = taffy.warnings().iter().filter(|&&(node, _)| node == percent).count();
+        assert_eq!(percent_warnings, 1);
+        assert!(taffy.warnings().contains(&(percent, LayoutWarning::PercentageOfIndefiniteSize)));
+
+        // Nothing is laid out again, so no warnings are reported
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert!(taffy.warnings().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn flex_line_overflow_is_reported_once_per_node() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let wide_style = Style { size: Size::from_lengths(80.0, 10.0), flex_shrink: 0.0, ..Default::default() };
+        let wide = [taffy.new_leaf(wide_style.clone()).unwrap(), taffy.new_leaf(wide_style).unwrap()];
+        let row = taffy
+            .new_with_children(
+                Style { max_size: Size { width: length(100.0), height: auto() }, ..Default::default() },
+                &wide,
+            )
+            .unwrap();
+        // Sizing the block container performs the layout of the row, which is then laid out again with the final size
+        let block = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[row]).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: length(300.0), height: auto() }, ..Default::default() },
+                &[block],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.warnings(), &[(row, LayoutWarning::FlexLineOverflow { overflow: 60.0 })]);
+    }
 }
@@ -106,6 +106,10 @@
 //!     fn get_unrounded_layout(&self, node_id: NodeId) -> Layout;
 //!     /// Get a reference to the node's final layout
 //!     fn set_final_layout(&mut self, node_id: NodeId, layout: &Layout);
+//!     /// Get the origin against which the node's descendants are rounded
+//!     fn get_rounding_origin(&self, node_id: NodeId) -> RoundingOrigin {
+//!         RoundingOrigin::Inherit
+//!     }
 //! }
 //! ```
 //!
@@ -126,19 +130,21 @@
 //! }
 //! ```
 //!
-use super::{Layout, LayoutInput, LayoutOutput, NodeId, RequestedAxis, RunMode, SizingMode};
-#[cfg(feature = "detailed_layout_info")]
-use crate::debug::debug_log;
+use super::{Layout, LayoutInput, LayoutOutput, LayoutWarning, NodeId, RequestedAxis, RunMode, SizingMode};
 use crate::geometry::{AbsoluteAxis, Line, Size};
-use crate::style::{AvailableSpace, CoreStyle};
+use crate::style::{AvailableSpace, CoreStyle, RoundingOrigin};
 #[cfg(feature = "flexbox")]
 use crate::style::{FlexboxContainerStyle, FlexboxItemStyle};
 #[cfg(feature = "grid")]
 use crate::style::{GridContainerStyle, GridItemStyle};
+#[cfg(feature = "detailed_layout_info")]
+use crate::util::debug::debug_log;
 use crate::CheapCloneStr;
 #[cfg(feature = "block_layout")]
 use crate::{BlockContainerStyle, BlockItemStyle};
 
+#[cfg(all(feature = "flexbox", feature = "detailed_layout_info"))]
+use crate::compute::flexbox::DetailedFlexboxInfo;
 #[cfg(all(feature = "grid", feature = "detailed_layout_info"))]
 use crate::compute::grid::DetailedGridInfo;
 
@@ -198,6 +204,28 @@ pub trait LayoutPartialTree: TraversePartialTree {
 
     /// Compute the specified node's size or full layout given the specified constraints
     fn compute_child_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput;
+
+    /// Report a non-fatal condition detected while laying out the node
+    ///
+    /// Implementing this method is optional. The default implementation ignores the warning.
+    #[inline(always)]
+    fn report_warning(&mut self, node_id: NodeId, warning: LayoutWarning) {
+        let _ = node_id;
+        let _ = warning;
+    }
+
+    /// Whether block, flex and grid containers resolve the percentage padding and border of their items against the
+    /// inline size (width) of the container in both axes, as CSS does, rather than against the size in the same axis
+    ///
+    /// This only affects the padding and border which a container resolves for an item while sizing it. A node
+    /// resolves its own percentage padding and border against the width of its containing block either way, so
+    /// returning `false` can size an item differently from how it lays itself out.
+    ///
+    /// Implementing this method is optional. The default implementation resolves them against the inline size.
+    #[inline(always)]
+    fn resolve_percentage_padding_against_inline_size(&self) -> bool {
+        true
+    }
 }
 
 /// Trait used by the `compute_cached_layout` method which allows cached layout results to be stored and retrieved.
@@ -236,6 +264,13 @@ pub trait RoundTree: TraverseTree {
     fn get_unrounded_layout(&self, node_id: NodeId) -> Layout;
     /// Get a reference to the node's final layout
     fn set_final_layout(&mut self, node_id: NodeId, layout: &Layout);
+    /// Get the origin against which the node's descendants are rounded
+    ///
+    /// Defaults to [`RoundingOrigin::Inherit`], which rounds every node in the tree against the root.
+    fn get_rounding_origin(&self, node_id: NodeId) -> RoundingOrigin {
+        let _ = node_id;
+        RoundingOrigin::Inherit
+    }
 }
 
 /// Trait used by the `print_tree` method which prints a debug representation
@@ -265,6 +300,15 @@ pub trait LayoutFlexboxContainer: LayoutPartialTree {
 
     /// Get the child's styles
     fn get_flexbox_child_style(&self, child_node_id: NodeId) -> Self::FlexboxItemStyle<'_>;
+
+    /// Set the node's detailed flexbox information
+    ///
+    /// Implementing this method is optional. Doing so allows you to access details about the flex container such as
+    /// which items were placed in each flex line and the computed size and position of each line.
+    #[cfg(feature = "detailed_layout_info")]
+    fn set_detailed_flexbox_info(&mut self, _node_id: NodeId, _detailed_flexbox_info: DetailedFlexboxInfo) {
+        debug_log!("LayoutFlexboxContainer::set_detailed_flexbox_info called");
+    }
 }
 
 #[cfg(feature = "grid")]
@@ -401,11 +445,22 @@ pub(crate) trait LayoutPartialTreeExt: LayoutPartialTree {
         )
     }
 
+    /// The size which the percentage padding and border of an item resolve against, given the size of its
+    /// containing block
+    #[inline(always)]
+    fn percentage_padding_basis(&self, containing_block_size: Size<Option<f32>>) -> Size<Option<f32>> {
+        if self.resolve_percentage_padding_against_inline_size() {
+            Size { width: containing_block_size.width, height: containing_block_size.width }
+        } else {
+            containing_block_size
+        }
+    }
+
     /// Alias to `resolve_calc_value` with a shorter function name
     #[inline(always)]
     #[cfg(feature = "calc")]
     fn calc(&self, val: *const (), basis: f32) -> f32 {
-        self.resolve_calc_value(val, basis)
+        crate::util::sanitize_f32(self.resolve_calc_value(val, basis), "resolve_calc_value")
     }
 
     /// Alias to `resolve_calc_value` with a shorter function name
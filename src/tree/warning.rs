@@ -0,0 +1,58 @@
+//! Non-fatal conditions which can be reported while computing layout
+use crate::tree::LayoutInput;
+
+/// A non-fatal condition detected while computing layout
+///
+/// Warnings do not change the computed layout. They point at styles or measured sizes which are likely to produce
+/// a layout that isn't what was intended. They are passed to [`LayoutPartialTree::report_warning`](crate::LayoutPartialTree::report_warning)
+/// and are collected by [`TaffyTree`](crate::TaffyTree) (see `TaffyTree::warnings`).
+///
+/// New variants may be added in minor releases, so matches on this enum must include a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum LayoutWarning {
+    /// The computed size of the node is NaN or infinite
+    NonFiniteSize,
+    /// The node has a percentage size in an axis in which the size of its parent is indefinite,
+    /// so the percentage behaves as `auto`
+    PercentageOfIndefiniteSize,
+    /// The in-flow items of a line of the flex container are larger than the container in the main axis. The container
+    /// may be laid out several times during a layout computation, so this is reported at most once for each node.
+    FlexLineOverflow {
+        /// The amount by which the items overflow the container
+        overflow: f32,
+    },
+    /// The grid item is larger than its grid area. The negative free space is treated as zero when aligning the item.
+    GridItemOverflow,
+    /// The node has no parent and isn't a registered root, so the layout computation didn't reach it. It may have
+    /// been leaked. Only reported if enabled with `TaffyTree::enable_orphan_warnings`.
+    Orphan,
+    /// The layout of the node panicked. Once this is reported the panic is raised again with a message describing the
+    /// node and its inputs followed by the original message, so it is only seen by callers which catch the panic. Only
+    /// reported when debug assertions are enabled, and only for the innermost node which was being laid out.
+    LayoutPanicked {
+        /// The label of the node's layout algorithm, as returned by [`PrintTree::get_debug_label`](crate::PrintTree::get_debug_label)
+        label: &'static str,
+        /// The constraints the node was being laid out with. The run mode says which phase of layout panicked.
+        inputs: LayoutInput,
+    },
+}
+
+impl LayoutWarning {
+    /// Identifies the kind of the warning, ignoring any data it carries
+    pub(crate) fn kind(&self) -> u8 {
+        match self {
+            Self::NonFiniteSize => 0,
+            Self::PercentageOfIndefiniteSize => 1,
+            Self::FlexLineOverflow { .. } => 2,
+            Self::GridItemOverflow => 3,
+            Self::Orphan => 4,
+            Self::LayoutPanicked { .. } => 5,
+        }
+    }
+
+    /// Whether the warning can be detected each time the node is laid out, so it is only reported the first time
+    pub(crate) fn is_reported_once(&self) -> bool {
+        matches!(self, Self::PercentageOfIndefiniteSize | Self::FlexLineOverflow { .. })
+    }
+}
@@ -0,0 +1,106 @@
+//! Storage for the temporary vectors allocated by layout algorithms during a layout pass
+//!
+//! With the `arena` feature these are allocated from a per-thread bump allocator which is reset whenever the outermost
+//! flexbox or grid layout on the thread returns, so the arena doesn't grow across passes even if the algorithms are
+//! called directly rather than through [`compute_root_layout`](crate::compute_root_layout). Otherwise they are ordinary
+//! heap-allocated vectors.
+//!
+//! The `arena` feature depends on `bumpalo`, whose current releases require Rust 1.71 or higher.
+
+/// The allocator that temporary vectors are allocated from
+#[cfg(feature = "arena")]
+pub(crate) type Scratch<'bump> = &'bump bumpalo::Bump;
+/// The allocator that temporary vectors are allocated from
+#[cfg(not(feature = "arena"))]
+pub(crate) type Scratch<'bump> = core::marker::PhantomData<&'bump ()>;
+
+/// A vector which lives no longer than the layout pass it was allocated in
+#[cfg(feature = "arena")]
+pub(crate) type ScratchVec<'bump, T> = bumpalo::collections::Vec<'bump, T>;
+/// A vector which lives no longer than the layout pass it was allocated in
+#[cfg(not(feature = "arena"))]
+pub(crate) type ScratchVec<'bump, T> = super::sys::Vec<T>;
+
+#[cfg(feature = "arena")]
+std::thread_local! {
+    /// The bump allocator of the current thread
+    static ARENA: core::cell::RefCell<bumpalo::Bump> = core::cell::RefCell::new(bumpalo::Bump::new());
+}
+
+/// Calls `f` with the allocator for temporary vectors, and frees them once the outermost call on this thread returns
+#[inline]
+pub(crate) fn with_scratch<R>(f: impl FnOnce(Scratch) -> R) -> R {
+    #[cfg(feature = "arena")]
+    return {
+        let output = ARENA.with(|arena| f(&arena.borrow()));
+        reset_scratch();
+        output
+    };
+    #[cfg(not(feature = "arena"))]
+    return f(core::marker::PhantomData);
+}
+
+/// Frees all of the temporary vectors allocated by the current thread, unless some of them are still in use
+///
+/// They are still in use while an enclosing call to [`with_scratch`] is running, in which case the outermost call frees
+/// them when it returns.
+#[inline]
+#[cfg_attr(not(feature = "arena"), allow(dead_code))]
+fn reset_scratch() {
+    #[cfg(feature = "arena")]
+    ARENA.with(|arena| {
+        if let Ok(mut arena) = arena.try_borrow_mut() {
+            arena.reset();
+        }
+    });
+}
+
+/// Creates an empty temporary vector with space for `capacity` items
+#[inline]
+pub(crate) fn scratch_vec_with_capacity<T>(capacity: usize, scratch: Scratch) -> ScratchVec<T> {
+    #[cfg(feature = "arena")]
+    return bumpalo::collections::Vec::with_capacity_in(capacity, scratch);
+    #[cfg(not(feature = "arena"))]
+    return {
+        let _ = scratch;
+        super::sys::new_vec_with_capacity(capacity)
+    };
+}
+
+/// Collects the items of `iter` into a temporary vector
+#[inline]
+pub(crate) fn collect_scratch_vec<T>(iter: impl Iterator<Item = T>, scratch: Scratch) -> ScratchVec<T> {
+    #[cfg(feature = "arena")]
+    return bumpalo::collections::Vec::from_iter_in(iter, scratch);
+    #[cfg(not(feature = "arena"))]
+    return {
+        let _ = scratch;
+        iter.collect()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_scratch_vec, reset_scratch, with_scratch};
+
+    #[test]
+    #[cfg(feature = "arena")]
+    fn scratch_vectors_are_freed_when_the_outermost_call_returns() {
+        let remaining_capacity = || super::ARENA.with(|arena| arena.borrow().chunk_capacity());
+        let remaining_capacity_during_layout = with_scratch(|scratch| {
+            let _items = super::scratch_vec_with_capacity::<u32>(64, scratch);
+            with_scratch(|_| {});
+            remaining_capacity()
+        });
+        assert!(remaining_capacity() >= remaining_capacity_during_layout + 256);
+    }
+
+    #[test]
+    fn reset_is_deferred_while_scratch_vectors_are_in_use() {
+        with_scratch(|scratch| {
+            let items = collect_scratch_vec(0..4, scratch);
+            reset_scratch();
+            assert_eq!(&items[..], [0, 1, 2, 3]);
+        });
+    }
+}
@@ -120,6 +120,63 @@ macro_rules! debug_pop_node {
     };
 }
 
+/// Runs `layout` on `tree` and, if it panics, passes the tree to `on_panic`, which describes the node being laid out
+///
+/// The panic is then raised again with a message made of that description followed by the message of the original
+/// panic, so that an uncaught layout panic says which node it came from. Only the innermost node is described: the
+/// panic passes through the layouts of its ancestors unchanged.
+#[cfg(all(debug_assertions, feature = "std"))]
+pub(crate) fn with_panic_context<T, R>(
+    tree: &mut T,
+    layout: impl FnOnce(&mut T) -> R,
+    on_panic: impl FnOnce(&mut T) -> String,
+) -> R {
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+    std::thread_local! {
+        /// The number of nested calls to `with_panic_context` on this thread
+        static DEPTH: Cell<usize> = const { Cell::new(0) };
+        /// Whether the panic which is unwinding has already been given its context
+        static HAS_CONTEXT: Cell<bool> = const { Cell::new(false) };
+    }
+
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = catch_unwind(AssertUnwindSafe(|| layout(&mut *tree)));
+    let depth = DEPTH.with(|depth| {
+        depth.set(depth.get() - 1);
+        depth.get()
+    });
+
+    match result {
+        Ok(output) => output,
+        Err(payload) => {
+            // Describe the node unless a descendant was already described, and reset once the outermost call is reached
+            if HAS_CONTEXT.with(|has_context| has_context.replace(depth > 0)) {
+                resume_unwind(payload);
+            }
+            let context = on_panic(tree);
+            let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+                (Some(message), _) => *message,
+                (_, Some(message)) => message.as_str(),
+                (None, None) => "Box<dyn Any>",
+            };
+            panic!("{context}: {message}");
+        }
+    }
+}
+
+/// Runs `layout` on `tree`. Panics are only given context by `on_panic` when debug assertions are enabled.
+#[cfg(not(all(debug_assertions, feature = "std")))]
+#[inline(always)]
+pub(crate) fn with_panic_context<T, R>(
+    tree: &mut T,
+    layout: impl FnOnce(&mut T) -> R,
+    _on_panic: impl FnOnce(&mut T) -> super::sys::String,
+) -> R {
+    layout(tree)
+}
+
 #[cfg(feature = "profile")]
 #[allow(unused_macros)]
 macro_rules! time {
@@ -3,6 +3,23 @@
 
 use crate::geometry::Size;
 use crate::style::AvailableSpace;
+use crate::util::debug::debug_log;
+
+/// Replaces a NaN or infinite value provided from outside of Taffy (such as a length or `flex_grow` in a style, the
+/// result of a measure function or of resolving a `calc()` value) with zero, so that it cannot spread into the layout
+/// of every node that depends on it.
+///
+/// With the `debug` feature, each replaced value is logged along with `source`.
+#[inline(always)]
+pub(crate) fn sanitize_f32(value: f32, source: &str) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        debug_log!("NON-FINITE VALUE REPLACED WITH ZERO, FROM", source);
+        let _ = source;
+        0.0
+    }
+}
 
 /// A trait to conveniently calculate minimums and maximums when some data may not be defined
 ///
@@ -248,8 +265,77 @@ impl<In, Out, T: MaybeMath<In, Out>> MaybeMath<Size<In>, Size<Out>> for Size<T>
     }
 }
 
+/// A trait to override values with known values where they are defined
+///
+/// If `value` is [`None`] then `self` is returned unchanged, otherwise `self` is replaced by the defined value.
+/// This is typically used to replace the space available to a node with its known size.
+pub trait MaybeSet<In> {
+    /// Returns `value` if it is defined, or else `self`
+    fn maybe_set(self, value: In) -> Self;
+}
+
+impl MaybeSet<Option<f32>> for Option<f32> {
+    #[inline(always)]
+    fn maybe_set(self, value: Option<f32>) -> Self {
+        value.or(self)
+    }
+}
+
+impl MaybeSet<Option<f32>> for AvailableSpace {
+    #[inline(always)]
+    fn maybe_set(self, value: Option<f32>) -> Self {
+        match value {
+            Some(value) => AvailableSpace::Definite(value),
+            None => self,
+        }
+    }
+}
+
+impl<In, T: MaybeSet<In>> MaybeSet<Size<In>> for Size<T> {
+    #[inline(always)]
+    fn maybe_set(self, value: Size<In>) -> Self {
+        Size { width: self.width.maybe_set(value.width), height: self.height.maybe_set(value.height) }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    mod maybe_set {
+        use crate::geometry::Size;
+        use crate::style::AvailableSpace;
+        use crate::util::MaybeSet;
+
+        #[test]
+        fn test_option_f32() {
+            assert_eq!(Some(3.0).maybe_set(Some(5.0)), Some(5.0));
+            assert_eq!(Some(3.0).maybe_set(None), Some(3.0));
+            assert_eq!(None.maybe_set(Some(5.0)), Some(5.0));
+            assert_eq!(None::<f32>.maybe_set(None), None);
+        }
+
+        #[test]
+        fn test_available_space() {
+            assert_eq!(AvailableSpace::MinContent.maybe_set(Some(5.0)), AvailableSpace::Definite(5.0));
+            assert_eq!(AvailableSpace::Definite(3.0).maybe_set(Some(5.0)), AvailableSpace::Definite(5.0));
+            assert_eq!(AvailableSpace::MaxContent.maybe_set(None), AvailableSpace::MaxContent);
+        }
+
+        #[test]
+        fn test_size() {
+            let available_space = Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MinContent };
+            assert_eq!(
+                available_space.maybe_set(Size { width: Some(5.0), height: None }),
+                Size { width: AvailableSpace::Definite(5.0), height: AvailableSpace::MinContent }
+            );
+
+            let known_dimensions = Size { width: Some(3.0), height: None };
+            assert_eq!(
+                known_dimensions.maybe_set(Size { width: None, height: Some(5.0) }),
+                Size { width: Some(3.0), height: Some(5.0) }
+            );
+        }
+    }
+
     mod lhs_option_f32_rhs_option_f32 {
         use crate::util::MaybeMath;
 
@@ -0,0 +1,198 @@
+//! Helpers for implementing measure functions for common kinds of leaf content
+use crate::geometry::Size;
+use crate::style::{AvailableSpace, Style};
+use crate::tree::NodeId;
+use crate::util::sys::{f32_max, f32_min};
+
+/// A trait for integrating text shaping/layout engines (cosmic-text, rusttype, an engine-specific shaper, etc) with Taffy
+///
+/// Implementors only need to describe how their content wraps. The [`measure_text`] function then implements the
+/// logic for converting Taffy's sizing constraints into a size, and [`text_measure_function`] adapts any implementor
+/// into a measure function that can be passed to [`TaffyTree::compute_layout_with_measure`](crate::TaffyTree::compute_layout_with_measure).
+pub trait TextMeasurer {
+    /// The width of the content when every soft wrap opportunity is taken (typically the width of the longest word)
+    fn min_content_width(&self) -> f32;
+
+    /// The width of the content when no soft wrap opportunities are taken (typically the width of the longest paragraph)
+    fn max_content_width(&self) -> f32;
+
+    /// The height of the content when it is wrapped to fit within the specified `width`
+    fn measure_height(&self, width: f32) -> f32;
+
+    /// The distance from the top of the content to the baseline of its first line (if it has one)
+    ///
+    /// Note that leaf nodes laid out by Taffy's built-in algorithms do not currently propagate baselines, so this
+    /// value is only used by callers that construct their own [`LayoutOutput`](crate::LayoutOutput).
+    fn first_baseline(&self, width: f32) -> Option<f32> {
+        let _ = width;
+        None
+    }
+}
+
+impl<T: TextMeasurer + ?Sized> TextMeasurer for &'_ T {
+    fn min_content_width(&self) -> f32 {
+        (**self).min_content_width()
+    }
+    fn max_content_width(&self) -> f32 {
+        (**self).max_content_width()
+    }
+    fn measure_height(&self, width: f32) -> f32 {
+        (**self).measure_height(width)
+    }
+    fn first_baseline(&self, width: f32) -> Option<f32> {
+        (**self).first_baseline(width)
+    }
+}
+
+/// Compute the size of some text content given the known dimensions and available space passed to a measure function
+///
+/// If the width is not known then the content is sized to its min-content or max-content width under min-content or
+/// max-content constraints, and to the available space clamped between the two under a definite constraint.
+pub fn measure_text(
+    text: &impl TextMeasurer,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+) -> Size<f32> {
+    if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+        return Size { width, height };
+    }
+
+    let width = known_dimensions.width.unwrap_or_else(|| match available_space.width {
+        AvailableSpace::MinContent => text.min_content_width(),
+        AvailableSpace::MaxContent => text.max_content_width(),
+        AvailableSpace::Definite(width) => f32_max(f32_min(width, text.max_content_width()), text.min_content_width()),
+    });
+    let height = known_dimensions.height.unwrap_or_else(|| text.measure_height(width));
+
+    Size { width, height }
+}
+
+/// Compute the size of a replaced element (such as an image) with the specified natural size
+///
+/// This implements the CSS sizing rules for replaced elements: known dimensions are always respected, an unknown
+/// dimension is computed from the other using the natural aspect ratio, and if neither dimension is known then the
+/// natural size is used. If the natural size is zero in either axis then it has no aspect ratio, and the natural size
+/// is used for any unknown dimension.
+pub fn measure_aspect(known_dimensions: Size<Option<f32>>, natural_size: Size<f32>) -> Size<f32> {
+    let has_aspect_ratio = natural_size.width > 0.0 && natural_size.height > 0.0;
+    match (known_dimensions.width, known_dimensions.height) {
+        (Some(width), Some(height)) => Size { width, height },
+        (Some(width), None) if has_aspect_ratio => {
+            Size { width, height: width * natural_size.height / natural_size.width }
+        }
+        (None, Some(height)) if has_aspect_ratio => {
+            Size { width: height * natural_size.width / natural_size.height, height }
+        }
+        _ => known_dimensions.unwrap_or(natural_size),
+    }
+}
+
+/// A measure function for [`TaffyTree`](crate::TaffyTree)s whose node context implements [`TextMeasurer`]
+///
+/// Nodes without a context are measured as zero-sized.
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// # use taffy::{text_measure_function, TextMeasurer};
+/// struct Monospace(&'static str);
+/// impl TextMeasurer for Monospace {
+///     fn min_content_width(&self) -> f32 {
+///         self.0.split_whitespace().map(|word| word.len()).max().unwrap_or(0) as f32 * 10.0
+///     }
+///     fn max_content_width(&self) -> f32 {
+///         self.0.len() as f32 * 10.0
+///     }
+///     fn measure_height(&self, width: f32) -> f32 {
+///         (self.max_content_width() / width).ceil() * 20.0
+///     }
+/// }
+///
+/// let mut taffy: TaffyTree<Monospace> = TaffyTree::new();
+/// let text = taffy.new_leaf_with_context(Style::default(), Monospace("hello world")).unwrap();
+/// taffy.compute_layout_with_measure(text, Size::MAX_CONTENT, text_measure_function).unwrap();
+/// assert_eq!(taffy.layout(text).unwrap().size, Size { width: 110.0, height: 20.0 });
+/// ```
+pub fn text_measure_function<NodeContext: TextMeasurer>(
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    _node_id: NodeId,
+    node_context: Option<&mut NodeContext>,
+    _style: &Style,
+) -> Size<f32> {
+    match node_context {
+        Some(text) => measure_text(&*text, known_dimensions, available_space),
+        None => known_dimensions.unwrap_or(Size::ZERO),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{measure_aspect, measure_text, TextMeasurer};
+    use crate::geometry::Size;
+    use crate::style::AvailableSpace;
+    use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
+
+    /// Text with 10px wide words that wraps into 20px high lines
+    struct Words(usize);
+    impl TextMeasurer for Words {
+        fn min_content_width(&self) -> f32 {
+            10.0
+        }
+        fn max_content_width(&self) -> f32 {
+            self.0 as f32 * 10.0
+        }
+        fn measure_height(&self, width: f32) -> f32 {
+            let words_per_line = (width / 10.0).floor().max(1.0);
+            (self.0 as f32 / words_per_line).ceil() * 20.0
+        }
+    }
+
+    #[test]
+    fn text_sizes_under_intrinsic_constraints() {
+        let text = Words(6);
+        assert_eq!(measure_text(&text, Size::NONE, Size::MIN_CONTENT), Size { width: 10.0, height: 120.0 });
+        assert_eq!(measure_text(&text, Size::NONE, Size::MAX_CONTENT), Size { width: 60.0, height: 20.0 });
+    }
+
+    #[test]
+    fn text_clamps_definite_available_width() {
+        let text = Words(6);
+        let available_space =
+            |width| Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent };
+        assert_eq!(measure_text(&text, Size::NONE, available_space(30.0)), Size { width: 30.0, height: 40.0 });
+        assert_eq!(measure_text(&text, Size::NONE, available_space(5.0)), Size { width: 10.0, height: 120.0 });
+        assert_eq!(measure_text(&text, Size::NONE, available_space(500.0)), Size { width: 60.0, height: 20.0 });
+    }
+
+    #[test]
+    fn text_respects_known_dimensions() {
+        let text = Words(6);
+        let known_width = Size { width: Some(20.0), height: None };
+        assert_eq!(measure_text(&text, known_width, Size::MAX_CONTENT), Size { width: 20.0, height: 60.0 });
+        assert_eq!(measure_text(&text, Size::new(1.0, 2.0), Size::MAX_CONTENT), Size { width: 1.0, height: 2.0 });
+    }
+
+    #[test]
+    fn aspect_uses_natural_size_and_ratio() {
+        let natural_size = Size { width: 400.0, height: 300.0 };
+        assert_eq!(measure_aspect(Size::NONE, natural_size), natural_size);
+        assert_eq!(
+            measure_aspect(Size { width: Some(200.0), height: None }, natural_size),
+            Size { width: 200.0, height: 150.0 }
+        );
+        assert_eq!(
+            measure_aspect(Size { width: None, height: Some(60.0) }, natural_size),
+            Size { width: 80.0, height: 60.0 }
+        );
+        assert_eq!(measure_aspect(Size::new(10.0, 10.0), natural_size), Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn aspect_without_ratio_falls_back_to_natural_size() {
+        let natural_size = Size { width: 0.0, height: 30.0 };
+        assert_eq!(
+            measure_aspect(Size { width: Some(50.0), height: None }, natural_size),
+            Size { width: 50.0, height: 30.0 }
+        );
+    }
+}
@@ -1,9 +1,13 @@
 //! Helpful misc. utilities such as a function to debug print a tree
+pub(crate) mod arena;
 mod math;
+mod measure;
 mod resolve;
 pub(crate) mod sys;
 
-pub use math::MaybeMath;
+pub(crate) use math::sanitize_f32;
+pub use math::{MaybeMath, MaybeSet};
+pub use measure::{measure_aspect, measure_text, text_measure_function, TextMeasurer};
 pub use resolve::{MaybeResolve, ResolveOrZero};
 
 #[doc(hidden)]
@@ -11,7 +15,7 @@ pub use resolve::{MaybeResolve, ResolveOrZero};
 pub(crate) mod debug;
 
 #[cfg(feature = "std")]
-mod print;
+pub(crate) mod print;
 #[cfg(feature = "std")]
 pub use print::print_tree;
 
@@ -1,4 +1,5 @@
 //! Contains the print_tree function for printing a debug representation of the tree
+use crate::geometry::Point;
 use crate::tree::{NodeId, PrintTree};
 
 /// Prints a debug representation of the computed layout for a tree of nodes, starting with the passed root node.
@@ -57,3 +58,131 @@ pub fn print_tree(tree: &impl PrintTree, root: NodeId) {
         }
     }
 }
+
+/// Renders the computed layout of a tree of nodes as a grid of characters, starting with the passed root node.
+///
+/// Each node is drawn as a box outline using `+`, `-` and `|`, with children drawn over their parents. The root
+/// node (which may be any node of the tree) is scaled to fill a grid of `cols` by `rows` characters. Nodes which are smaller than a single character
+/// in either axis are not drawn. The rows of the grid are separated by newlines.
+pub fn render_ascii(tree: &impl PrintTree, root: NodeId, cols: usize, rows: usize) -> String {
+    /// Recursive function that draws each node in the tree
+    fn draw_node(
+        tree: &impl PrintTree,
+        node_id: NodeId,
+        offset: Point<f32>,
+        scale: Point<f32>,
+        grid: &mut [Vec<char>],
+    ) {
+        let layout = tree.get_final_layout(node_id);
+        let location = Point { x: offset.x + layout.location.x, y: offset.y + layout.location.y };
+
+        // Convert the box into inclusive ranges of character cells, clamped to the grid
+        let to_cell = |value: f32, scale: f32, len: usize| ((value * scale).round().max(0.0) as usize).min(len);
+        let (cols, rows) = (grid[0].len(), grid.len());
+        let left = to_cell(location.x, scale.x, cols);
+        let right = to_cell(location.x + layout.size.width, scale.x, cols);
+        let top = to_cell(location.y, scale.y, rows);
+        let bottom = to_cell(location.y + layout.size.height, scale.y, rows);
+
+        if right > left && bottom > top {
+            let (right, bottom) = (right - 1, bottom - 1);
+            for (y, row) in grid.iter_mut().enumerate().take(bottom + 1).skip(top) {
+                for (x, cell) in row.iter_mut().enumerate().take(right + 1).skip(left) {
+                    let on_vertical_edge = x == left || x == right;
+                    let on_horizontal_edge = y == top || y == bottom;
+                    *cell = match (on_vertical_edge, on_horizontal_edge) {
+                        (true, true) => '+',
+                        (false, true) => '-',
+                        (true, false) => '|',
+                        (false, false) => ' ',
+                    };
+                }
+            }
+        }
+
+        for child in tree.child_ids(node_id) {
+            draw_node(tree, child, location, scale, grid);
+        }
+    }
+
+    let mut grid = vec![vec![' '; cols]; rows];
+    let root_layout = tree.get_final_layout(root);
+    let root_size = root_layout.size;
+    if cols > 0 && rows > 0 && root_size.width > 0.0 && root_size.height > 0.0 {
+        let scale = Point { x: cols as f32 / root_size.width, y: rows as f32 / root_size.height };
+        // The root is drawn at the origin of the grid, wherever it is placed within its parent
+        let offset = Point { x: -root_layout.location.x, y: -root_layout.location.y };
+        draw_node(tree, root, offset, scale, &mut grid);
+    }
+
+    let mut output = String::with_capacity((cols + 1) * rows);
+    for row in grid {
+        output.extend(row);
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_ascii;
+    use crate::prelude::*;
+
+    #[test]
+    fn render_ascii_draws_a_nested_node_at_the_origin() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(20.0, 10.0), ..Default::default() }).unwrap();
+        let nested = taffy
+            .new_with_children(
+                Style {
+                    size: Size::from_lengths(40.0, 20.0),
+                    justify_content: Some(JustifyContent::End),
+                    ..Default::default()
+                },
+                &[leaf],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size::from_lengths(100.0, 100.0), padding: length(30.0), ..Default::default() },
+                &[nested],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let expected = ["+----+---+", "|    +---+", "|        |", "+--------+"];
+        assert_eq!(render_ascii(&taffy, nested, 10, 4), expected.join("\n") + "\n");
+    }
+
+    #[test]
+    fn render_ascii_draws_children_over_parent() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style { flex_grow: 1.0, size: Size::from_lengths(0.0, 40.0), ..Default::default() })
+            .unwrap();
+        let small = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 20.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    size: Size::from_lengths(100.0, 40.0),
+                    align_items: Some(AlignItems::Start),
+                    ..Default::default()
+                },
+                &[child, small],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let expected = [
+            "+--------++--------+",
+            "|        ||        |",
+            "|        ||        |",
+            "|        |+--------+",
+            "|        |         |",
+            "|        |         |",
+            "|        |         |",
+            "+--------+---------+",
+        ];
+        assert_eq!(render_ascii(&taffy, root, 20, 8), expected.join("\n") + "\n");
+    }
+}
@@ -1,8 +1,9 @@
 //! Helper trait to calculate dimensions during layout resolution
 
 use crate::geometry::{Rect, Size};
-use crate::style::{Dimension, LengthPercentage, LengthPercentageAuto};
+use crate::style::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto};
 use crate::style_helpers::TaffyZero;
+use crate::util::sanitize_f32;
 use crate::CompactLength;
 
 /// Trait to encapsulate behaviour where we need to resolve from a
@@ -30,8 +31,10 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentage {
     /// Can return `None`
     fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(*const (), f32) -> f32) -> Option<f32> {
         match self.0.tag() {
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            CompactLength::LENGTH_TAG => Some(sanitize_f32(self.0.value(), "a length in a style")),
+            CompactLength::PERCENT_TAG => {
+                context.map(|dim| dim * sanitize_f32(self.0.value(), "a percentage in a style"))
+            }
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
@@ -45,8 +48,10 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
     fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(*const (), f32) -> f32) -> Option<f32> {
         match self.0.tag() {
             CompactLength::AUTO_TAG => None,
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            CompactLength::LENGTH_TAG => Some(sanitize_f32(self.0.value(), "a length in a style")),
+            CompactLength::PERCENT_TAG => {
+                context.map(|dim| dim * sanitize_f32(self.0.value(), "a percentage in a style"))
+            }
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
@@ -61,8 +66,10 @@ impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
     fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(*const (), f32) -> f32) -> Option<f32> {
         match self.0.tag() {
             CompactLength::AUTO_TAG => None,
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            CompactLength::LENGTH_TAG => Some(sanitize_f32(self.0.value(), "a length in a style")),
+            CompactLength::PERCENT_TAG => {
+                context.map(|dim| dim * sanitize_f32(self.0.value(), "a percentage in a style"))
+            }
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
@@ -80,6 +87,16 @@ impl<T: MaybeResolve<Option<f32>, Option<f32>>> MaybeResolve<f32, Option<f32>> f
     }
 }
 
+// Generic implementation of MaybeResolve for AvailableSpace context where MaybeResolve is implemented
+// for Option<f32> context
+impl<T: MaybeResolve<Option<f32>, Option<f32>>> MaybeResolve<AvailableSpace, Option<f32>> for T {
+    /// Converts the given MaybeResolve value into an absolute length
+    /// Percentages only resolve against definite available space, as min-content and max-content are indefinite
+    fn maybe_resolve(self, context: AvailableSpace, calc: impl Fn(*const (), f32) -> f32) -> Option<f32> {
+        self.maybe_resolve(context.into_option(), calc)
+    }
+}
+
 // Generic MaybeResolve for Size
 impl<In, Out, T: MaybeResolve<In, Out>> MaybeResolve<Size<In>, Size<Out>> for Size<T> {
     /// Converts any `parent`-relative values for size into an absolute size
@@ -149,6 +166,16 @@ impl<Out: TaffyZero, T: ResolveOrZero<Option<f32>, Out>> ResolveOrZero<Option<f3
     }
 }
 
+// Generic implementation of ResolveOrZero for AvailableSpace context where ResolveOrZero is implemented
+// for Option<f32> context
+impl<Out: TaffyZero, T: ResolveOrZero<Option<f32>, Out>> ResolveOrZero<AvailableSpace, Out> for T {
+    /// Converts the given ResolveOrZero value into an absolute length, resolving percentages against a definite
+    /// available space and treating min-content and max-content available space as indefinite
+    fn resolve_or_zero(self, context: AvailableSpace, calc: impl Fn(*const (), f32) -> f32) -> Out {
+        self.resolve_or_zero(context.into_option(), calc)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{MaybeResolve, ResolveOrZero};
@@ -348,4 +375,38 @@ mod tests {
             roz_case(Rect::from_percent(5.0, 5.0, 5.0, 5.0), Some(0.0), Rect::zero());
         }
     }
+
+    mod resolve_against_available_space {
+        use super::{mr_case, roz_case};
+        use crate::geometry::{Rect, Size};
+        use crate::style::{AvailableSpace, Dimension};
+        use crate::style_helpers::*;
+
+        #[test]
+        fn maybe_resolve_percent() {
+            mr_case(Dimension::from_percent(0.5), AvailableSpace::Definite(10.0), Some(5.0));
+            mr_case(Dimension::from_percent(0.5), AvailableSpace::MinContent, None);
+            mr_case(Dimension::from_percent(0.5), AvailableSpace::MaxContent, None);
+            mr_case(Dimension::from_length(3.0), AvailableSpace::MaxContent, Some(3.0));
+        }
+
+        #[test]
+        fn maybe_resolve_size() {
+            mr_case(
+                Size::<Dimension>::from_percent(0.5, 0.5),
+                Size { width: AvailableSpace::Definite(10.0), height: AvailableSpace::MaxContent },
+                Size { width: Some(5.0), height: None },
+            );
+        }
+
+        #[test]
+        fn resolve_or_zero_rect() {
+            roz_case(
+                Rect::from_percent(0.5, 0.5, 0.5, 0.5),
+                AvailableSpace::Definite(10.0),
+                Rect::new(5.0, 5.0, 5.0, 5.0),
+            );
+            roz_case(Rect::from_percent(0.5, 0.5, 0.5, 0.5), AvailableSpace::MinContent, Rect::zero());
+        }
+    }
 }
@@ -33,8 +33,12 @@ mod std {
     pub(crate) type DefaultCheapStr = String;
     /// A map
     pub(crate) type Map<K, V> = std::collections::HashMap<K, V, std::collections::hash_map::RandomState>;
+    /// A set
+    pub(crate) type Set<K> = std::collections::HashSet<K, std::collections::hash_map::RandomState>;
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = std::vec::Vec<A>;
+    /// A pointer to a heap allocation
+    pub(crate) type Box<A> = std::boxed::Box<A>;
     /// A vector of child nodes
     pub(crate) type ChildrenVec<A> = std::vec::Vec<A>;
     #[cfg(feature = "grid")]
@@ -119,8 +123,12 @@ mod alloc {
     /// A map
     // TODO: consider using hashbrown
     pub(crate) type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+    /// A set
+    pub(crate) type Set<K> = alloc::collections::BTreeSet<K>;
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = alloc::vec::Vec<A>;
+    /// A pointer to a heap allocation
+    pub(crate) type Box<A> = alloc::boxed::Box<A>;
     /// A vector of child nodes
     pub(crate) type ChildrenVec<A> = alloc::vec::Vec<A>;
     #[cfg(feature = "grid")]
@@ -186,6 +194,32 @@ mod core {
 
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = arrayvec::ArrayVec<A, MAX_NODE_COUNT>;
+
+    /// A stand-in for a heap allocation, which stores its contents inline as there is no allocator
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    #[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
+    pub(crate) struct Box<A>(A);
+
+    impl<A> Box<A> {
+        /// Stores the value inline
+        pub(crate) fn new(value: A) -> Self {
+            Self(value)
+        }
+    }
+
+    impl<A> core::ops::Deref for Box<A> {
+        type Target = A;
+
+        fn deref(&self) -> &A {
+            &self.0
+        }
+    }
+
+    impl<A> core::ops::DerefMut for Box<A> {
+        fn deref_mut(&mut self) -> &mut A {
+            &mut self.0
+        }
+    }
     /// A vector of child nodes, whose length cannot exceed [`MAX_CHILD_COUNT`]
     pub(crate) type ChildrenVec<A> = arrayvec::ArrayVec<A, MAX_CHILD_COUNT>;
     #[cfg(feature = "grid")]
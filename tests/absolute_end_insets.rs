@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod absolute_end_insets {
+    use taffy::prelude::*;
+    use taffy::style::Overflow;
+    use taffy::Point;
+    use taffy_test_helpers::new_test_tree;
+
+    const CONTAINER: Size<f32> = Size { width: 200.0, height: 100.0 };
+    const BORDER: f32 = 5.0;
+    const SCROLLBAR_WIDTH: f32 = 15.0;
+    const ITEM: Size<f32> = Size { width: 40.0, height: 20.0 };
+
+    /// Which insets are set in an axis
+    #[derive(Debug, Clone, Copy)]
+    enum Insets {
+        Start,
+        End,
+        Both,
+    }
+
+    impl Insets {
+        const ALL: [Insets; 3] = [Insets::Start, Insets::End, Insets::Both];
+
+        fn start(self) -> LengthPercentageAuto {
+            if matches!(self, Insets::End) {
+                auto()
+            } else {
+                length(10.0)
+            }
+        }
+
+        fn end(self) -> LengthPercentageAuto {
+            if matches!(self, Insets::Start) {
+                auto()
+            } else {
+                length(10.0)
+            }
+        }
+
+        /// The expected offset of the item in an axis where the container and item have the given sizes. The start
+        /// inset wins when both are set, as the item's size is definite.
+        fn expected_offset(self, container: f32, item: f32) -> f32 {
+            match self {
+                Insets::Start | Insets::Both => BORDER + 10.0,
+                Insets::End => container - BORDER - SCROLLBAR_WIDTH - item - 10.0,
+            }
+        }
+    }
+
+    /// The location of a definitely sized absolutely positioned item with the given insets, in a scroll container
+    /// with a border
+    fn layout_item(display: Display, horizontal: Insets, vertical: Insets) -> Point<f32> {
+        let mut taffy = new_test_tree();
+        let item = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                size: Size::from_lengths(ITEM.width, ITEM.height),
+                inset: Rect {
+                    left: horizontal.start(),
+                    right: horizontal.end(),
+                    top: vertical.start(),
+                    bottom: vertical.end(),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display,
+                    size: Size::from_lengths(CONTAINER.width, CONTAINER.height),
+                    border: Rect::length(BORDER),
+                    overflow: Point { x: Overflow::Scroll, y: Overflow::Scroll },
+                    scrollbar_width: SCROLLBAR_WIDTH,
+                    ..Default::default()
+                },
+                &[item],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        taffy.layout(item).unwrap().location
+    }
+
+    fn assert_all_inset_combinations(display: Display) {
+        for horizontal in Insets::ALL {
+            for vertical in Insets::ALL {
+                let expected = Point {
+                    x: horizontal.expected_offset(CONTAINER.width, ITEM.width),
+                    y: vertical.expected_offset(CONTAINER.height, ITEM.height),
+                };
+                assert_eq!(
+                    layout_item(display, horizontal, vertical),
+                    expected,
+                    "{display:?} container with {horizontal:?} horizontal and {vertical:?} vertical insets"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn flex_items_are_positioned_from_the_end_inside_the_scrollbar_gutter() {
+        assert_all_inset_combinations(Display::Flex);
+    }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn block_items_are_positioned_from_the_end_inside_the_scrollbar_gutter() {
+        assert_all_inset_combinations(Display::Block);
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn grid_items_are_positioned_from_the_end_inside_the_scrollbar_gutter() {
+        assert_all_inset_combinations(Display::Grid);
+    }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn block_auto_margins_only_subtract_the_bottom_margin_when_bottom_is_set() {
+        let mut taffy = new_test_tree();
+        let item = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                size: Size::from_lengths(ITEM.width, ITEM.height),
+                inset: Rect { left: auto(), right: auto(), top: length(10.0), bottom: length(10.0) },
+                margin: Rect { left: length(20.0), right: zero(), top: auto(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size::from_lengths(CONTAINER.width, CONTAINER.height),
+                    ..Default::default()
+                },
+                &[item],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The auto top margin takes all of the space between the insets that the item doesn't fill
+        let layout = taffy.layout(item).unwrap();
+        assert_eq!(layout.margin.top, 60.0);
+        assert_eq!(layout.location.y, 70.0);
+    }
+}
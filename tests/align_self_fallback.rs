@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod align_self_fallback {
+    use taffy::prelude::*;
+    use taffy_test_helpers::{new_test_tree, TestNodeContext};
+
+    /// A 20x20 leaf, which has no baseline of its own
+    fn icon(taffy: &mut TaffyTree<TestNodeContext>, align_self_fallback: Option<AlignSelf>) -> NodeId {
+        taffy
+            .new_leaf(Style { size: Size::from_lengths(20.0, 20.0), align_self_fallback, ..Default::default() })
+            .unwrap()
+    }
+
+    /// A flex row containing a 30px tall leaf, whose baseline is 30px from its top
+    fn text(taffy: &mut TaffyTree<TestNodeContext>) -> NodeId {
+        let line = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 30.0), ..Default::default() }).unwrap();
+        taffy.new_with_children(Style::default(), &[line]).unwrap()
+    }
+
+    /// Lays out `children` in a 100x100 container with `style` applied
+    fn layout(taffy: &mut TaffyTree<TestNodeContext>, style: Style, children: &[NodeId]) {
+        let style = Style { size: Size::from_lengths(100.0, 100.0), align_items: Some(AlignItems::Baseline), ..style };
+        let root = taffy.new_with_children(style, children).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    }
+
+    #[test]
+    fn items_without_a_baseline_use_their_fallback() {
+        let mut taffy = new_test_tree();
+        let text = text(&mut taffy);
+        let centered = icon(&mut taffy, Some(AlignSelf::Center));
+        let synthesized = icon(&mut taffy, None);
+        layout(&mut taffy, Style::default(), &[text, centered, synthesized]);
+
+        assert_eq!(taffy.layout(text).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(centered).unwrap().location.y, 40.0);
+        // Without a fallback the baseline is synthesized from the bottom edge
+        assert_eq!(taffy.layout(synthesized).unwrap().location.y, 10.0);
+    }
+
+    #[test]
+    fn a_lone_baseline_item_without_a_baseline_uses_its_fallback() {
+        let mut taffy = new_test_tree();
+        let icon = icon(&mut taffy, Some(AlignSelf::FlexEnd));
+        layout(&mut taffy, Style::default(), &[icon]);
+        assert_eq!(taffy.layout(icon).unwrap().location.y, 80.0);
+    }
+
+    #[test]
+    fn fallback_can_stretch() {
+        let mut taffy = new_test_tree();
+        let text = text(&mut taffy);
+        let icon = taffy
+            .new_leaf(Style {
+                size: Size { width: length(20.0), height: auto() },
+                align_self_fallback: Some(AlignSelf::Stretch),
+                ..Default::default()
+            })
+            .unwrap();
+        layout(&mut taffy, Style::default(), &[text, icon]);
+        assert_eq!(taffy.layout(icon).unwrap().size.height, 100.0);
+    }
+
+    #[test]
+    fn columns_always_use_the_fallback() {
+        let mut taffy = new_test_tree();
+        let text = text(&mut taffy);
+        let centered = icon(&mut taffy, Some(AlignSelf::Center));
+        layout(&mut taffy, Style { flex_direction: FlexDirection::Column, ..Default::default() }, &[text, centered]);
+        assert_eq!(taffy.layout(text).unwrap().location.x, 0.0);
+        assert_eq!(taffy.layout(centered).unwrap().location.x, 40.0);
+    }
+}
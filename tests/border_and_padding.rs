@@ -109,3 +109,61 @@ fn vertical_border_and_padding_percentage_values_use_available_space_correctly()
     assert_eq!(layout.size.width, 200.0);
     assert_eq!(layout.size.height, 200.0);
 }
+
+/// Lays out a tall container with a child with vertical percentage padding and border followed by a sibling, and
+/// returns the layouts of the child and the sibling
+fn layout_child_with_vertical_percentage_padding(
+    display: Display,
+    inline_percentage_padding: bool,
+) -> (Layout, Layout) {
+    let mut taffy = new_test_tree();
+    if !inline_percentage_padding {
+        taffy.disable_inline_percentage_padding();
+    }
+
+    let child = taffy
+        .new_leaf(Style {
+            padding: Rect { top: percent(0.1), bottom: percent(0.1), ..Rect::zero() },
+            border: Rect { top: percent(0.05), bottom: percent(0.05), ..Rect::zero() },
+            box_sizing: BoxSizing::ContentBox,
+            min_size: Size { width: auto(), height: length(0.0) },
+            max_size: Size { width: auto(), height: length(0.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let sibling = taffy.new_leaf(Style::default()).unwrap();
+    let node = taffy
+        .new_with_children(
+            Style {
+                display,
+                flex_direction: FlexDirection::Column,
+                size: Size::from_lengths(100.0, 200.0),
+                grid_template_rows: vec![auto(), fr(1.0)],
+                ..Default::default()
+            },
+            &[child, sibling],
+        )
+        .unwrap();
+
+    taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+    (*taffy.layout(child).unwrap(), *taffy.layout(sibling).unwrap())
+}
+
+#[test]
+fn vertical_percentage_padding_of_children_resolves_against_container_width() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let (child, sibling) = layout_child_with_vertical_percentage_padding(display, true);
+        assert_eq!(child.padding.top, 10.0, "{display}");
+        assert_eq!(child.border.bottom, 5.0, "{display}");
+        assert_eq!(child.size.height, 30.0, "{display}");
+        assert_eq!(sibling.location.y, 30.0, "{display}");
+    }
+}
+
+#[test]
+fn vertical_percentage_padding_of_block_children_resolves_against_container_height_when_disabled() {
+    let (child, _) = layout_child_with_vertical_percentage_padding(Display::Block, false);
+    assert_eq!(child.padding.top, 20.0);
+    assert_eq!(child.border.bottom, 10.0);
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod caching {
     use taffy::prelude::*;
-    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext};
+    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
 
     const NODE_CONTEXT: TestNodeContext = TestNodeContext::fixed(50.0, 50.0);
 
@@ -37,4 +37,64 @@ mod caching {
         taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, test_measure_function).unwrap();
         assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, 4);
     }
+
+    #[test]
+    fn measure_count_nested_orthogonal_flexbox() {
+        let mut taffy = new_test_tree();
+        let text = || TestNodeContext::ahem_text("HH\u{200b}HHHH\u{200b}HH", WritingMode::Horizontal);
+
+        // Columns nested in rows nested in columns, each with a wrapping text sibling. Each container is asked for
+        // its height at both the hypothetical and the final width of its row parent (and vice versa), so it needs
+        // more than one cache entry per slot to avoid laying out its subtree again on every query.
+        let first_leaf = taffy.new_leaf_with_context(Style::default(), text()).unwrap();
+        let mut leaves = vec![first_leaf];
+        let mut node = first_leaf;
+        for depth in 0..12 {
+            let flex_direction = if depth % 2 == 0 { FlexDirection::Column } else { FlexDirection::Row };
+            let leaf = taffy.new_leaf_with_context(Style::default(), text()).unwrap();
+            leaves.push(leaf);
+            node = taffy
+                .new_with_children(Style { flex_direction, flex_grow: 1.0, ..Default::default() }, &[node, leaf])
+                .unwrap();
+        }
+
+        let available_space = Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::Definite(300.0) };
+        taffy.compute_layout_with_measure(node, available_space, test_measure_function).unwrap();
+
+        let count: usize = leaves.iter().map(|&leaf| taffy.get_node_context_mut(leaf).unwrap().count).sum();
+        assert_eq!(count, 222);
+    }
+
+    #[test]
+    fn changing_only_inset_of_absolute_node_keeps_its_cache() {
+        let mut taffy = new_test_tree();
+
+        let leaf = taffy.new_leaf_with_context(Style::default(), NODE_CONTEXT).unwrap();
+        let absolute_style = |left: f32| Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(left), right: auto(), top: length(0.0), bottom: auto() },
+            ..Default::default()
+        };
+        let absolute = taffy.new_with_children(absolute_style(10.0), &[leaf]).unwrap();
+        let sibling = taffy.new_leaf_with_context(Style::default(), NODE_CONTEXT).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size::from_lengths(200.0, 200.0), ..Default::default() },
+                &[sibling, absolute],
+            )
+            .unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+        let leaf_count = taffy.get_node_context_mut(leaf).unwrap().count;
+        let sibling_count = taffy.get_node_context_mut(sibling).unwrap().count;
+
+        taffy.set_style(absolute, absolute_style(30.0)).unwrap();
+        assert!(!taffy.dirty(absolute).unwrap());
+        assert!(taffy.dirty(root).unwrap());
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+        assert_eq!(taffy.layout(absolute).unwrap().location.x, 30.0);
+        assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, leaf_count);
+        assert_eq!(taffy.get_node_context_mut(sibling).unwrap().count, sibling_count);
+    }
 }
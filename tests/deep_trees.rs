@@ -0,0 +1,87 @@
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy_test_helpers::{new_test_tree, TestNodeContext};
+
+/// Deep enough to overflow the call stack of a test thread if layout recursed once per level
+const DEPTH: usize = 10_000;
+
+/// Builds a chain of `DEPTH` containers with the given display mode around a 10x10 leaf, returning the root and leaf
+fn deep_chain(taffy: &mut TaffyTree<TestNodeContext>, display: Display) -> (NodeId, NodeId) {
+    let leaf = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+    let style = Style { display, padding: Rect::length(1.0), ..Default::default() };
+    let mut root = leaf;
+    for _ in 0..DEPTH {
+        root = taffy.new_with_children(style.clone(), &[root]).unwrap();
+    }
+    (root, leaf)
+}
+
+fn assert_deep_chain_layout(display: Display) {
+    let mut taffy = new_test_tree();
+    let (root, leaf) = deep_chain(&mut taffy, display);
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // Each container wraps its child in 1px of padding on every side
+    let size = 10.0 + 2.0 * DEPTH as f32;
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: size, height: size });
+    assert_eq!(taffy.layout(leaf).unwrap().location, Point { x: 1.0, y: 1.0 });
+    assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 10.0, height: 10.0 });
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn deep_flexbox_tree() {
+    assert_deep_chain_layout(Display::Flex);
+}
+
+#[test]
+#[cfg(feature = "grid")]
+fn deep_grid_tree() {
+    assert_deep_chain_layout(Display::Grid);
+}
+
+#[test]
+#[cfg(feature = "block_layout")]
+fn deep_block_tree() {
+    assert_deep_chain_layout(Display::Block);
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn deep_hidden_tree() {
+    let mut taffy = new_test_tree();
+    let (child, leaf) = deep_chain(&mut taffy, Display::Flex);
+    let root = taffy.new_with_children(Style { display: Display::None, ..Default::default() }, &[child]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(leaf).unwrap().size, Size::ZERO);
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn deep_tree_relayout_after_change() {
+    let mut taffy = new_test_tree();
+    let (root, leaf) = deep_chain(&mut taffy, Display::Flex);
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    taffy.set_style(leaf, Style { size: Size::from_lengths(20.0, 5.0), ..Default::default() }).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let width = 20.0 + 2.0 * DEPTH as f32;
+    let height = 5.0 + 2.0 * DEPTH as f32;
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width, height });
+    assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 20.0, height: 5.0 });
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn deep_tree_measure() {
+    let mut taffy = new_test_tree();
+    let (root, _) = deep_chain(&mut taffy, Display::Flex);
+
+    let size = 10.0 + 2.0 * DEPTH as f32;
+    assert_eq!(taffy.measure(root, Size::MAX_CONTENT).unwrap(), Size { width: size, height: size });
+    assert_eq!(taffy.measure_read_only(root, Size::MAX_CONTENT).unwrap(), Size { width: size, height: size });
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod flex_gap_grow {
+    use taffy::prelude::*;
+    use taffy_test_helpers::new_test_tree;
+
+    /// The x positions and widths of `items` laid out in a 400px wide row with `style` applied
+    fn layout_row(style: Style, items: &[Style]) -> Vec<(f32, f32)> {
+        let mut taffy = new_test_tree();
+        let children: Vec<NodeId> = items.iter().map(|item| taffy.new_leaf(item.clone()).unwrap()).collect();
+        let style = Style { size: Size::from_lengths(400.0, 10.0), ..style };
+        let root = taffy.new_with_children(style, &children).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        children
+            .iter()
+            .map(|&child| {
+                let layout = taffy.layout(child).unwrap();
+                (layout.location.x, layout.size.width)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gaps_absorb_the_free_space_left_by_fixed_size_items() {
+        let item = Style { size: Size::from_lengths(100.0, 10.0), ..Default::default() };
+        let style = Style {
+            gap: Size::from_length(10.0),
+            gap_grow: 1.0,
+            justify_content: Some(JustifyContent::Center),
+            ..Default::default()
+        };
+        assert_eq!(
+            layout_row(style, &[item.clone(), item.clone(), item]),
+            [(0.0, 100.0), (150.0, 100.0), (300.0, 100.0)]
+        );
+    }
+
+    #[test]
+    fn gaps_share_free_space_with_growing_items() {
+        let item = Style { flex_grow: 1.0, flex_basis: length(0.0), ..Default::default() };
+        let style = Style { gap_grow: 2.0, ..Default::default() };
+        assert_eq!(layout_row(style, &[item.clone(), item]), [(0.0, 100.0), (300.0, 100.0)]);
+    }
+
+    #[test]
+    fn gaps_take_the_space_that_capped_items_cannot() {
+        let item = Style {
+            flex_grow: 1.0,
+            flex_basis: length(0.0),
+            max_size: Size { width: length(50.0), height: auto() },
+            ..Default::default()
+        };
+        let style = Style { gap_grow: 1.0, ..Default::default() };
+        assert_eq!(layout_row(style, &[item.clone(), item]), [(0.0, 50.0), (350.0, 50.0)]);
+    }
+}
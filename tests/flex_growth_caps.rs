@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod flex_growth_caps {
+    use taffy::prelude::*;
+    use taffy_test_helpers::new_test_tree;
+
+    /// The widths of two items with a 100px basis in a row of `width`, the first of which has the given caps
+    fn item_widths(width: f32, flex_grow_max: Option<f32>, flex_shrink_min: Option<f32>) -> (f32, f32) {
+        let mut taffy = new_test_tree();
+        let item = Style {
+            flex_grow: 1.0,
+            flex_basis: length(100.0),
+            min_size: Size::from_lengths(0.0, 0.0),
+            ..Default::default()
+        };
+        let capped = taffy.new_leaf(Style { flex_grow_max, flex_shrink_min, ..item.clone() }).unwrap();
+        let other = taffy.new_leaf(item).unwrap();
+        let style = Style { size: Size::from_lengths(width, 10.0), ..Default::default() };
+        let root = taffy.new_with_children(style, &[capped, other]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        (taffy.layout(capped).unwrap().size.width, taffy.layout(other).unwrap().size.width)
+    }
+
+    #[test]
+    fn uncapped_items_share_free_space_equally() {
+        assert_eq!(item_widths(400.0, None, None), (200.0, 200.0));
+        assert_eq!(item_widths(100.0, None, None), (50.0, 50.0));
+    }
+
+    #[test]
+    fn flex_grow_max_caps_growth_beyond_the_basis() {
+        assert_eq!(item_widths(400.0, Some(20.0), None), (120.0, 280.0));
+        // The cap doesn't apply when shrinking
+        assert_eq!(item_widths(100.0, Some(20.0), None), (50.0, 50.0));
+    }
+
+    #[test]
+    fn flex_shrink_min_caps_shrinkage_below_the_basis() {
+        assert_eq!(item_widths(100.0, None, Some(10.0)), (90.0, 10.0));
+        // The cap doesn't apply when growing
+        assert_eq!(item_widths(400.0, None, Some(10.0)), (200.0, 200.0));
+    }
+}
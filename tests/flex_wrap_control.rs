@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod flex_wrap_control {
+    use taffy::prelude::*;
+    use taffy_test_helpers::new_test_tree;
+
+    /// The positions of six 50x10 items in a container with `style` applied, with `break_before` set on the items at
+    /// the indexes in `breaks`, along with the size of the container
+    fn layout_items(style: Style, breaks: &[usize]) -> (Vec<(f32, f32)>, Size<f32>) {
+        let mut taffy = new_test_tree();
+        let children: Vec<NodeId> = (0..6)
+            .map(|idx| {
+                let style = Style {
+                    size: Size::from_lengths(50.0, 10.0),
+                    break_before: breaks.contains(&idx),
+                    ..Default::default()
+                };
+                taffy.new_leaf(style).unwrap()
+            })
+            .collect();
+        let root = taffy.new_with_children(style, &children).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let locations = children
+            .iter()
+            .map(|&child| {
+                let location = taffy.layout(child).unwrap().location;
+                (location.x, location.y)
+            })
+            .collect();
+        (locations, taffy.layout(root).unwrap().size)
+    }
+
+    fn wrapping_container() -> Style {
+        Style { flex_wrap: FlexWrap::Wrap, size: Size { width: length(400.0), height: auto() }, ..Default::default() }
+    }
+
+    #[test]
+    fn max_items_per_line_starts_a_new_line() {
+        let style = Style { max_items_per_line: Some(4), ..wrapping_container() };
+        let (locations, size) = layout_items(style, &[]);
+        assert_eq!(locations, [(0.0, 0.0), (50.0, 0.0), (100.0, 0.0), (150.0, 0.0), (0.0, 10.0), (50.0, 10.0)]);
+        assert_eq!(size.height, 20.0);
+    }
+
+    #[test]
+    fn break_before_starts_a_new_line() {
+        let (locations, _) = layout_items(wrapping_container(), &[0, 2]);
+        assert_eq!(locations, [(0.0, 0.0), (50.0, 0.0), (0.0, 10.0), (50.0, 10.0), (100.0, 10.0), (150.0, 10.0)]);
+    }
+
+    #[test]
+    fn max_items_per_line_and_break_before_combine() {
+        let style = Style { max_items_per_line: Some(3), ..wrapping_container() };
+        let (locations, _) = layout_items(style, &[1]);
+        assert_eq!(locations, [(0.0, 0.0), (0.0, 10.0), (50.0, 10.0), (100.0, 10.0), (0.0, 20.0), (50.0, 20.0)]);
+    }
+
+    #[test]
+    fn forced_breaks_apply_under_a_max_content_constraint() {
+        let style = Style { flex_wrap: FlexWrap::Wrap, max_items_per_line: Some(2), ..Default::default() };
+        let (locations, size) = layout_items(style, &[]);
+        assert_eq!(locations, [(0.0, 0.0), (50.0, 0.0), (0.0, 10.0), (50.0, 10.0), (0.0, 20.0), (50.0, 20.0)]);
+        assert_eq!(size, Size { width: 100.0, height: 30.0 });
+    }
+
+    #[test]
+    fn forced_breaks_are_ignored_without_wrapping() {
+        let style = Style { max_items_per_line: Some(2), ..Default::default() };
+        let (locations, _) = layout_items(style, &[3]);
+        assert!(locations.iter().all(|&(_, y)| y == 0.0));
+    }
+}
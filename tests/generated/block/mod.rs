@@ -10,6 +10,8 @@ mod block_absolute_aspect_ratio_fill_width;
 mod block_absolute_aspect_ratio_fill_width_from_inset;
 mod block_absolute_aspect_ratio_height_overrides_inset;
 mod block_absolute_aspect_ratio_width_overrides_inset;
+mod block_absolute_auto_size_shrinks_to_fit_after_inset_and_margin;
+mod block_absolute_both_insets_auto_after_static_position;
 mod block_absolute_child_with_margin_x;
 mod block_absolute_child_with_margin_y;
 mod block_absolute_child_with_max_height;
@@ -72,6 +74,7 @@ mod block_border_fixed_size;
 mod block_border_intrinsic_size;
 mod block_border_percentage_fixed_size;
 mod block_border_percentage_intrinsic_size;
+mod block_display_contents;
 mod block_display_none;
 mod block_display_none_with_child;
 mod block_display_none_with_inset;
@@ -9,6 +9,13 @@ mod absolute_aspect_ratio_fill_width;
 mod absolute_aspect_ratio_fill_width_from_inset;
 mod absolute_aspect_ratio_height_overrides_inset;
 mod absolute_aspect_ratio_width_overrides_inset;
+mod absolute_auto_size_shrinks_to_fit_after_inset_and_margin;
+mod absolute_auto_size_shrinks_to_fit_after_left_inset;
+mod absolute_both_insets_auto_column_align_items_flex_end;
+mod absolute_both_insets_auto_justify_content_center;
+mod absolute_both_insets_auto_justify_content_flex_end;
+mod absolute_both_insets_auto_row_reverse;
+mod absolute_both_insets_auto_shrinks_to_fit_after_padding;
 mod absolute_child_with_cross_margin;
 mod absolute_child_with_main_margin;
 mod absolute_child_with_max_height;
@@ -23,6 +30,12 @@ mod absolute_layout_align_items_and_justify_content_flex_end;
 mod absolute_layout_align_items_center;
 mod absolute_layout_align_items_center_on_child_only;
 mod absolute_layout_child_order;
+mod absolute_layout_in_column_reverse_container;
+mod absolute_layout_in_column_reverse_container_flex_end;
+mod absolute_layout_in_column_reverse_container_space_between;
+mod absolute_layout_in_column_reverse_container_start;
+mod absolute_layout_in_row_reverse_container;
+mod absolute_layout_in_row_reverse_container_space_between;
 mod absolute_layout_in_wrap_reverse_column_container;
 mod absolute_layout_in_wrap_reverse_column_container_flex_end;
 mod absolute_layout_in_wrap_reverse_row_container;
@@ -65,6 +78,7 @@ mod align_baseline_multiline_row_and_column;
 mod align_baseline_nested_child;
 mod align_baseline_nested_column;
 mod align_center_should_size_based_on_content;
+mod align_content_center_overflow_hidden;
 mod align_content_center_single_line;
 mod align_content_center_single_line_negative_space;
 mod align_content_center_single_line_negative_space_gap;
@@ -122,6 +136,8 @@ mod align_content_stretch_row_with_min_height;
 mod align_content_stretch_row_with_padding;
 mod align_content_stretch_row_with_single_row;
 mod align_content_stretch_row_wrap;
+mod align_content_stretch_wrapped_mixed_cross_sizes;
+mod align_content_stretch_wrapped_mixed_cross_sizes_column;
 mod align_flex_start_with_shrinking_children;
 mod align_flex_start_with_shrinking_children_with_stretch;
 mod align_flex_start_with_stretching_children;
@@ -205,6 +221,7 @@ mod child_min_max_width_flexing;
 mod child_with_padding_align_end;
 mod container_with_unsized_child;
 mod content_size;
+mod display_contents;
 mod display_none;
 mod display_none_absolute_child;
 mod display_none_fixed_size;
@@ -299,6 +316,7 @@ mod gap_row_gap_align_items_end;
 mod gap_row_gap_align_items_stretch;
 mod gap_row_gap_column_child_margins;
 mod gap_row_gap_determines_parent_height;
+mod gap_row_gap_percentage_column_definite;
 mod gap_row_gap_percentage_wrapping;
 mod gap_row_gap_row_wrap_child_margins;
 mod intrinsic_sizing_cross_size_column;
@@ -309,6 +327,7 @@ mod intrinsic_sizing_main_size_min_size;
 mod intrinsic_sizing_main_size_row;
 mod intrinsic_sizing_main_size_row_nested;
 mod intrinsic_sizing_main_size_row_wrap;
+mod justify_content_center_overflow_hidden;
 mod justify_content_column_center;
 mod justify_content_column_center_negative_space;
 mod justify_content_column_center_negative_space_gap;
@@ -349,6 +368,10 @@ mod justify_content_row_min_width_and_margin;
 mod justify_content_row_space_around;
 mod justify_content_row_space_between;
 mod justify_content_row_space_evenly;
+mod justify_content_space_around_flex_grow_max_gap;
+mod justify_content_space_between_flex_grow_max_gap;
+mod justify_content_space_between_flex_shrink_min_gap;
+mod justify_content_space_evenly_flex_grow_max_gap;
 mod margin_and_flex_column;
 mod margin_and_flex_row;
 mod margin_and_stretch_column;
@@ -521,11 +544,13 @@ mod wrap_nodes_with_content_sizing_margin_cross;
 mod wrap_nodes_with_content_sizing_overflowing_margin;
 mod wrap_reverse_column;
 mod wrap_reverse_column_fixed_size;
+mod wrap_reverse_column_reverse;
 mod wrap_reverse_row;
 mod wrap_reverse_row_align_content_center;
 mod wrap_reverse_row_align_content_flex_start;
 mod wrap_reverse_row_align_content_space_around;
 mod wrap_reverse_row_align_content_stretch;
+mod wrap_reverse_row_reverse;
 mod wrap_reverse_row_single_line_different_size;
 mod wrap_row;
 mod wrap_row_align_items_center;
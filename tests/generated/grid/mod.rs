@@ -3,6 +3,14 @@ mod chrome_issue_325928327;
 #[cfg(feature = "grid")]
 mod grid_absolute_align_self_sized_all;
 #[cfg(feature = "grid")]
+mod grid_absolute_auto_size_shrinks_to_fit_after_left_inset;
+#[cfg(feature = "grid")]
+mod grid_absolute_both_insets_auto_center;
+#[cfg(feature = "grid")]
+mod grid_absolute_both_insets_auto_end;
+#[cfg(feature = "grid")]
+mod grid_absolute_both_insets_auto_start;
+#[cfg(feature = "grid")]
 mod grid_absolute_column_end;
 #[cfg(feature = "grid")]
 mod grid_absolute_column_start;
@@ -157,10 +165,14 @@ mod grid_auto_columns_fixed_width;
 #[cfg(feature = "grid")]
 mod grid_auto_fill_fixed_size;
 #[cfg(feature = "grid")]
+mod grid_auto_fill_minmax_fr;
+#[cfg(feature = "grid")]
 mod grid_auto_fill_with_empty_auto_track;
 #[cfg(feature = "grid")]
 mod grid_auto_fit_definite_percentage;
 #[cfg(feature = "grid")]
+mod grid_auto_fit_minmax_fr;
+#[cfg(feature = "grid")]
 mod grid_auto_fit_with_empty_auto_track;
 #[cfg(feature = "grid")]
 mod grid_auto_rows;
@@ -187,6 +199,10 @@ mod grid_basic_with_overflow;
 #[cfg(feature = "grid")]
 mod grid_basic_with_padding;
 #[cfg(feature = "grid")]
+mod grid_content_alignment_overflow_hidden;
+#[cfg(feature = "grid")]
+mod grid_display_contents;
+#[cfg(feature = "grid")]
 mod grid_display_none_fixed_size;
 #[cfg(feature = "grid")]
 mod grid_fit_content_percent_definite_argument;
@@ -235,6 +251,8 @@ mod grid_fr_span_2_proportion_zero_sum_with_non_spanned_track;
 #[cfg(feature = "grid")]
 mod grid_gap;
 #[cfg(feature = "grid")]
+mod grid_gap_percent_definite;
+#[cfg(feature = "grid")]
 mod grid_hidden;
 #[cfg(feature = "grid")]
 mod grid_justify_content_center;
@@ -287,6 +305,8 @@ mod grid_margins_auto_margins;
 #[cfg(feature = "grid")]
 mod grid_margins_auto_margins_override_stretch;
 #[cfg(feature = "grid")]
+mod grid_margins_auto_single_side;
+#[cfg(feature = "grid")]
 mod grid_margins_fixed_center;
 #[cfg(feature = "grid")]
 mod grid_margins_fixed_end;
@@ -0,0 +1,94 @@
+#[cfg(feature = "grid")]
+mod grid_determinism {
+    use taffy::prelude::*;
+    use taffy::{GridTemplateArea, Layout};
+    use taffy_test_helpers::new_test_tree;
+
+    /// Lay out a grid which uses named areas, named lines, spans and dense auto-placement in a new tree
+    fn layout_grid() -> Vec<Layout> {
+        let mut taffy = new_test_tree();
+        let area = |name: &str, row: u16, column: u16| GridTemplateArea {
+            name: name.to_string(),
+            row_start: row,
+            row_end: row + 1,
+            column_start: column,
+            column_end: column + 1,
+        };
+
+        let mut children = Vec::new();
+        for i in 0..24 {
+            let style = match i % 6 {
+                0 => Style { grid_column: span(2), ..Default::default() },
+                1 => Style { grid_row: span(2), ..Default::default() },
+                2 => Style {
+                    grid_row: Line { start: GridPlacement::NamedLine("header".to_string(), 1), end: auto() },
+                    ..Default::default()
+                },
+                3 => Style {
+                    grid_column: Line { start: GridPlacement::NamedLine("middle".to_string(), 1), end: span(1) },
+                    ..Default::default()
+                },
+                _ => Style { size: Size::from_lengths(10.0 + i as f32, 5.0), ..Default::default() },
+            };
+            children.push(taffy.new_leaf(style).unwrap());
+        }
+
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_auto_flow: GridAutoFlow::RowDense,
+                    grid_template_columns: vec![length(40.0), fr(1.0), fr(2.0), auto()],
+                    grid_template_column_names: vec![
+                        vec![],
+                        vec!["middle".to_string()],
+                        vec!["middle".to_string()],
+                        vec![],
+                        vec![],
+                    ],
+                    grid_template_areas: vec![area("header", 1, 1), area("side", 2, 1), area("main", 2, 2)],
+                    size: Size { width: length(400.0), height: auto() },
+                    ..Default::default()
+                },
+                &children,
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        children.iter().map(|&child| *taffy.layout(child).unwrap()).collect()
+    }
+
+    #[test]
+    fn grid_placement_is_the_same_in_every_tree() {
+        // Each tree has its own (randomly seeded) hash maps of line and area names
+        let expected = layout_grid();
+        for _ in 0..20 {
+            assert_eq!(layout_grid(), expected);
+        }
+    }
+    #[test]
+    #[cfg(feature = "detailed_layout_info")]
+    fn explicitly_placed_items_are_placed_before_auto_placed_items() {
+        let mut taffy = new_test_tree();
+        let auto_placed = taffy.new_leaf(Style::default()).unwrap();
+        let row_locked = taffy.new_leaf(Style { grid_row: line(1), ..Default::default() }).unwrap();
+        let definite = taffy.new_leaf(Style { grid_row: line(1), grid_column: line(1), ..Default::default() }).unwrap();
+        let column_locked = taffy.new_leaf(Style { grid_column: line(2), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![fr(1.0); 3], ..Default::default() },
+                &[auto_placed, row_locked, definite, column_locked],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Items are reported in child order, with the order in which they were placed and their final areas
+        let items = &taffy.grid_info(root).unwrap().items;
+        let placements: Vec<_> =
+            items.iter().map(|item| (item.node, item.placement_order, item.row_start, item.column_start)).collect();
+        assert_eq!(
+            placements,
+            vec![(auto_placed, 2, 1, 3), (row_locked, 1, 1, 2), (definite, 0, 1, 1), (column_locked, 3, 2, 2)]
+        );
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(feature = "grid")]
+mod grid_named_areas {
+    use taffy::prelude::*;
+    use taffy::style_helpers::area;
+    use taffy_test_helpers::new_test_tree;
+
+    #[test]
+    fn items_can_be_placed_in_named_areas() {
+        let mut taffy = new_test_tree();
+        let header = taffy
+            .new_leaf(Style { grid_row: area("header"), grid_column: area("header"), ..Default::default() })
+            .unwrap();
+        let main =
+            taffy.new_leaf(Style { grid_row: area("main"), grid_column: area("main"), ..Default::default() }).unwrap();
+        let nav =
+            taffy.new_leaf(Style { grid_row: area("nav"), grid_column: area("nav"), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size::from_lengths(300.0, 200.0),
+                    grid_template_columns: vec![length(100.0), fr(1.0)],
+                    grid_template_rows: vec![length(50.0), fr(1.0)],
+                    grid_template_areas: taffy::grid_areas!["header header", "nav main"],
+                    ..Default::default()
+                },
+                &[main, nav, header],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let bounds = |node| {
+            let layout = taffy.layout(node).unwrap();
+            (layout.location.x, layout.location.y, layout.size.width, layout.size.height)
+        };
+        assert_eq!(bounds(header), (0.0, 0.0, 300.0, 50.0));
+        assert_eq!(bounds(nav), (0.0, 50.0, 100.0, 150.0));
+        assert_eq!(bounds(main), (100.0, 50.0, 200.0, 150.0));
+    }
+
+    #[test]
+    fn area_prefers_a_line_with_the_same_name() {
+        let mut taffy = new_test_tree();
+        let item =
+            taffy.new_leaf(Style { grid_row: line(1), grid_column: area("side"), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size::from_lengths(300.0, 100.0),
+                    grid_template_columns: vec![length(100.0), length(100.0), length(100.0)],
+                    grid_template_column_names: vec![vec![], vec![], vec!["side".into()], vec![]],
+                    ..Default::default()
+                },
+                &[item],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Both ends resolve to the line named `side`, so the item spans the single track after it
+        let layout = taffy.layout(item).unwrap();
+        assert_eq!((layout.location.x, layout.size.width), (200.0, 100.0));
+    }
+}
@@ -368,4 +368,21 @@ mod measure {
         assert_eq!(taffy.layout(child).unwrap().size.width, 100.0);
         assert_eq!(taffy.layout(child).unwrap().size.height, 100.0);
     }
+
+    #[test]
+    fn measure_nan_is_treated_as_zero() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        taffy
+            .compute_layout_with_measure(node, Size::MAX_CONTENT, |_, _, _, _, _| Size {
+                width: f32::NAN,
+                height: 10.0,
+            })
+            .unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size.width, 0.0);
+        assert_eq!(taffy.layout(node).unwrap().size.height, 10.0);
+    }
 }
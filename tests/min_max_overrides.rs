@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod min_max_overrides {
     use taffy::prelude::*;
-    use taffy_test_helpers::new_test_tree;
+    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext};
 
     #[test]
     fn min_overrides_max() {
@@ -69,4 +69,28 @@ mod min_max_overrides {
 
         assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 100.0 });
     }
+
+    #[test]
+    fn flex_auto_min_size_opt_out() {
+        for (flex_auto_min_size, expected_width) in [(true, 80.0), (false, 50.0)] {
+            let mut taffy = new_test_tree();
+
+            let child = taffy
+                .new_leaf_with_context(
+                    Style { flex_auto_min_size, ..Default::default() },
+                    TestNodeContext::fixed(80.0, 10.0),
+                )
+                .unwrap();
+            let node = taffy
+                .new_with_children(
+                    Style { size: Size { width: Dimension::from_length(50.0), height: auto() }, ..Default::default() },
+                    &[child],
+                )
+                .unwrap();
+
+            taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+            assert_eq!(taffy.layout(child).unwrap().size.width, expected_width);
+        }
+    }
 }
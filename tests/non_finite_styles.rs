@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod non_finite_styles {
+    use taffy::prelude::*;
+
+    #[test]
+    fn infinite_length_is_treated_as_zero() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style { size: Size { width: length(f32::INFINITY), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 0.0, height: 10.0 });
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 0.0, height: 10.0 });
+    }
+
+    #[test]
+    fn nan_flex_grow_is_treated_as_zero() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style { flex_grow: f32::NAN, size: Size::from_lengths(10.0, 10.0), ..Default::default() })
+            .unwrap();
+        let node = taffy
+            .new_with_children(Style { size: Size::from_lengths(100.0, 10.0), ..Default::default() }, &[child])
+            .unwrap();
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn infinite_aspect_ratio_is_ignored() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style {
+                aspect_ratio: Some(f32::INFINITY),
+                size: Size { width: length(10.0), height: auto() },
+                ..Default::default()
+            })
+            .unwrap();
+        let node = taffy
+            .new_with_children(Style { align_items: Some(AlignItems::Start), ..Default::default() }, &[child])
+            .unwrap();
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 0.0 });
+    }
+}
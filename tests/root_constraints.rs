@@ -107,4 +107,22 @@ mod root_constraints {
         assert_eq!(layout.size.width, 40.0);
         assert_eq!(layout.size.height, 40.0);
     }
+
+    #[test]
+    fn root_with_infinite_definite_space_is_max_content() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 30.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        taffy
+            .compute_layout(
+                node,
+                Size { width: AvailableSpace::Definite(f32::INFINITY), height: AvailableSpace::MAX_CONTENT },
+            )
+            .unwrap();
+        let layout = taffy.layout(node).unwrap();
+
+        assert_eq!(layout.size.width, 50.0);
+        assert_eq!(layout.size.height, 30.0);
+    }
 }
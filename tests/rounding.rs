@@ -28,3 +28,59 @@ fn rounding_doesnt_leave_gaps() {
     let layout_b = taffy.layout(child_b).unwrap();
     assert_eq!(layout_a.location.x + layout_a.size.width, layout_b.location.x);
 }
+
+#[test]
+fn rounding_origin_node_rounds_descendants_against_the_node() {
+    let mut taffy = new_test_tree();
+
+    let spacer = taffy
+        .new_leaf(Style { size: Size { width: length(10.4), height: length(10.0) }, ..Default::default() })
+        .unwrap();
+    let child_style = Style {
+        margin: Rect { left: length(10.25), right: zero(), top: zero(), bottom: zero() },
+        size: Size { width: length(20.5), height: length(10.0) },
+        ..Default::default()
+    };
+    let child = taffy.new_leaf(child_style).unwrap();
+    let panel = taffy.new_with_children(Style { flex_shrink: 0.0, ..Default::default() }, &[child]).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[spacer, panel]).unwrap();
+
+    // Rounded against the root, the child spans 20.65..41.15
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location.x, 10.0);
+    assert_eq!(taffy.layout(child).unwrap().size.width, 20.0);
+
+    // Rounded against the panel, the child spans 10.25..30.75
+    taffy
+        .set_style(panel, Style { flex_shrink: 0.0, rounding_origin: RoundingOrigin::Node, ..Default::default() })
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location.x, 10.0);
+    assert_eq!(taffy.layout(child).unwrap().size.width, 21.0);
+}
+
+/// The rounded right edge of the last of 1000 items which share the free space of a 99999px wide line
+fn right_edge_of_last_growing_item(flex_grow_precision: FlexGrowPrecision) -> f32 {
+    let mut taffy = new_test_tree();
+    let children: Vec<NodeId> =
+        (0..1000).map(|_| taffy.new_leaf(Style { flex_grow: 0.1, ..Default::default() }).unwrap()).collect();
+    let root = taffy
+        .new_with_children(
+            Style {
+                size: Size { width: length(99999.0), height: length(10.0) },
+                flex_grow_precision,
+                ..Default::default()
+            },
+            &children,
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let last = taffy.layout(*children.last().unwrap()).unwrap();
+    last.location.x + last.size.width
+}
+
+#[test]
+fn exact_flex_grow_precision_fills_the_line_after_rounding() {
+    assert_eq!(right_edge_of_last_growing_item(FlexGrowPrecision::Exact), 99999.0);
+}
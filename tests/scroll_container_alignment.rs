@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod scroll_container_alignment {
+    use taffy::prelude::*;
+    use taffy::{Overflow, Point};
+    use taffy_test_helpers::{new_test_tree, TestNodeContext};
+
+    /// A 100x100 container with `overflow` and `style` applied, containing a single 150x150 item
+    fn overflowing_container(overflow: Overflow, style: Style) -> (TaffyTree<TestNodeContext>, NodeId) {
+        let mut taffy = new_test_tree();
+        let child = taffy
+            .new_leaf(Style { flex_shrink: 0.0, size: Size::from_lengths(150.0, 150.0), ..Default::default() })
+            .unwrap();
+        let style = Style {
+            size: Size::from_lengths(100.0, 100.0),
+            overflow: Point { x: overflow, y: overflow },
+            scrollbar_width: 0.0,
+            ..style
+        };
+        let root = taffy.new_with_children(style, &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        (taffy, child)
+    }
+
+    #[test]
+    fn flexbox_justify_content_center_overflows_both_sides_when_visible() {
+        let style = Style { justify_content: Some(JustifyContent::Center), ..Default::default() };
+        let (taffy, child) = overflowing_container(Overflow::Visible, style);
+        assert_eq!(taffy.layout(child).unwrap().location.x, -25.0);
+    }
+
+    #[test]
+    fn flexbox_justify_content_center_falls_back_to_start_in_scroll_container() {
+        let style = Style { justify_content: Some(JustifyContent::Center), ..Default::default() };
+        let (taffy, child) = overflowing_container(Overflow::Scroll, style);
+        assert_eq!(taffy.layout(child).unwrap().location.x, 0.0);
+    }
+
+    #[test]
+    fn flexbox_justify_content_end_falls_back_to_start_in_scroll_container() {
+        let style = Style { justify_content: Some(JustifyContent::FlexEnd), ..Default::default() };
+        let (taffy, child) = overflowing_container(Overflow::Hidden, style);
+        assert_eq!(taffy.layout(child).unwrap().location.x, 0.0);
+    }
+
+    #[test]
+    fn flexbox_justify_content_center_applies_in_scroll_container_without_overflow() {
+        let mut taffy = new_test_tree();
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 50.0), ..Default::default() }).unwrap();
+        let style = Style {
+            size: Size::from_lengths(100.0, 100.0),
+            overflow: Point { x: Overflow::Scroll, y: Overflow::Scroll },
+            scrollbar_width: 0.0,
+            justify_content: Some(JustifyContent::Center),
+            ..Default::default()
+        };
+        let root = taffy.new_with_children(style, &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location.x, 25.0);
+    }
+
+    #[test]
+    fn flexbox_align_content_center_falls_back_to_start_in_scroll_container() {
+        let style =
+            Style { flex_wrap: FlexWrap::Wrap, align_content: Some(AlignContent::Center), ..Default::default() };
+        let (taffy, child) = overflowing_container(Overflow::Scroll, style.clone());
+        assert_eq!(taffy.layout(child).unwrap().location.y, 0.0);
+
+        let (taffy, child) = overflowing_container(Overflow::Visible, style);
+        assert_eq!(taffy.layout(child).unwrap().location.y, -25.0);
+    }
+
+    #[test]
+    fn flexbox_safe_alignment_only_applies_in_the_scrolling_axis() {
+        let mut taffy = new_test_tree();
+        let child = taffy
+            .new_leaf(Style { flex_shrink: 0.0, size: Size::from_lengths(150.0, 150.0), ..Default::default() })
+            .unwrap();
+        let style = Style {
+            size: Size::from_lengths(100.0, 100.0),
+            overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+            scrollbar_width: 0.0,
+            justify_content: Some(JustifyContent::Center),
+            ..Default::default()
+        };
+        let root = taffy.new_with_children(style, &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location.x, -25.0);
+    }
+
+    #[test]
+    fn grid_content_alignment_falls_back_to_start_in_scroll_container() {
+        let style = Style {
+            display: Display::Grid,
+            justify_content: Some(JustifyContent::Center),
+            align_content: Some(AlignContent::End),
+            ..Default::default()
+        };
+        let (taffy, child) = overflowing_container(Overflow::Scroll, style.clone());
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 0.0, y: 0.0 });
+
+        let (taffy, child) = overflowing_container(Overflow::Visible, style);
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: -25.0, y: -50.0 });
+    }
+}
@@ -51,4 +51,56 @@ mod serde {
         }"###;
         let _: Value = serde_json::from_str(&json).unwrap();
     }
+
+    #[test]
+    fn serde_partial_style_only_serializes_set_fields() {
+        use taffy::prelude::*;
+        use taffy::PartialStyle;
+
+        let patch: PartialStyle<String> =
+            PartialStyle { padding: Some(length(8.0)), flex_grow: Some(1.0), ..Default::default() };
+        let json: Value = serde_json::to_value(&patch).unwrap();
+        let fields = json.as_object().unwrap();
+        assert_eq!(fields.len(), 2);
+        assert!(fields.contains_key("padding"));
+        assert!(fields.contains_key("flex_grow"));
+
+        let roundtrip: PartialStyle<String> = serde_json::from_value(json).unwrap();
+        assert_eq!(roundtrip, patch);
+    }
+
+    #[test]
+    fn serde_can_deserialize_partial_style_patch() {
+        use taffy::prelude::*;
+        use taffy::PartialStyle;
+
+        let json = r###"{ "display": "None", "flex_grow": 2.0 }"###;
+        let patch: PartialStyle<String> = serde_json::from_str(json).unwrap();
+
+        let mut style: Style<String> = Style::DEFAULT;
+        style.apply(&patch);
+        assert_eq!(style.display, Display::None);
+        assert_eq!(style.flex_grow, 2.0);
+        assert_eq!(style.size, Style::<String>::DEFAULT.size);
+    }
+
+    #[test]
+    #[cfg(feature = "capture")]
+    fn captured_fixture_roundtrip() {
+        use taffy::prelude::*;
+        use taffy::CapturedTree;
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap();
+        taffy.set_intrinsic_size(child, Size { width: Some(10.0), height: None }).unwrap();
+        let root = taffy.new_with_children(Style { padding: length(4.0), ..Default::default() }, &[child]).unwrap();
+
+        let available_space = Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::MinContent };
+        let fixture = taffy.capture(root, available_space).unwrap();
+        let captured = CapturedTree::parse(&fixture).unwrap();
+        assert_eq!(captured.available_space, available_space);
+        let json: Value = serde_json::from_str(&fixture).unwrap();
+        assert_eq!(json["nodes"][0]["children"], serde_json::json!([1]));
+        assert_eq!(CapturedTree::parse(&serde_json::to_string(&captured).unwrap()).unwrap(), captured);
+    }
 }
@@ -0,0 +1,22 @@
+//! Guards against accidental growth of the types which are stored once per node
+
+use std::mem::size_of;
+use taffy::{Cache, Layout, Style};
+
+// Each bound is the size of the type on 64-bit targets plus a few bytes of slack, so any field added to these types
+// is noticed. Raise them if the growth is intentional, and lower them when a type shrinks.
+
+#[test]
+fn style_size() {
+    assert!(size_of::<Style>() <= 592, "Style is {} bytes", size_of::<Style>());
+}
+
+#[test]
+fn layout_size() {
+    assert!(size_of::<Layout>() <= 88, "Layout is {} bytes", size_of::<Layout>());
+}
+
+#[test]
+fn cache_size() {
+    assert!(size_of::<Cache>() <= 88, "Cache is {} bytes", size_of::<Cache>());
+}